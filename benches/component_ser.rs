@@ -0,0 +1,65 @@
+//! Compares writing replica components straight to the output through a `dyn Write`, as [`ComponentConstruction::ser`] does, with writing them to an in-memory buffer which is then copied to the output.
+#![feature(test)]
+extern crate test;
+
+use std::io::Write;
+
+use endio::{Deserialize, LE, LEWrite, Serialize};
+use endio_bit::{BEBitReader, BEBitWriter};
+use lu_packets::common::{Versioned, NETWORK_VERSION_LIVE};
+use lu_packets::raknet::client::replica::ComponentConstruction;
+use lu_packets::raknet::client::replica::character::CharacterConstruction;
+use lu_packets::raknet::client::replica::controllable_physics::ControllablePhysicsConstruction;
+use lu_packets::raknet::client::replica::destroyable::DestroyableConstruction;
+use test::{black_box, Bencher};
+
+fn read<T: Deserialize<LE, BEBitReader<&'static [u8]>>>(bin: &'static [u8]) -> T {
+	T::deserialize(&mut BEBitReader::new(bin)).unwrap()
+}
+
+struct Components {
+	physics: ControllablePhysicsConstruction,
+	destroyable: DestroyableConstruction,
+	character: CharacterConstruction,
+}
+
+fn components() -> Components {
+	Components {
+		physics: read(include_bytes!("../src/raknet/client/replica/tests/ControllablePhysicsConstruction.bin")),
+		destroyable: read(include_bytes!("../src/raknet/client/replica/tests/DestroyableConstruction.bin")),
+		character: read(include_bytes!("../src/raknet/client/replica/tests/CharacterConstruction.bin")),
+	}
+}
+
+#[bench]
+fn direct(b: &mut Bencher) {
+	let comps = components();
+	let boxed: [&dyn ComponentConstruction; 3] = [&comps.physics, &comps.destroyable, &comps.character];
+	let mut out = Vec::with_capacity(1024);
+	b.iter(|| {
+		out.clear();
+		let mut writer = BEBitWriter::new(Versioned::new(&mut out as &mut dyn Write, NETWORK_VERSION_LIVE));
+		for comp in &boxed {
+			comp.ser(&mut writer).unwrap();
+		}
+		writer.flush().unwrap();
+		drop(writer);
+		black_box(&out);
+	});
+}
+
+#[bench]
+fn buffer(b: &mut Bencher) {
+	let comps = components();
+	let mut out = Vec::with_capacity(1024);
+	b.iter(|| {
+		out.clear();
+		let mut writer = BEBitWriter::new(Versioned::new(vec![], NETWORK_VERSION_LIVE));
+		(&comps.physics).serialize(&mut writer).unwrap();
+		(&comps.destroyable).serialize(&mut writer).unwrap();
+		(&comps.character).serialize(&mut writer).unwrap();
+		writer.flush().unwrap();
+		LEWrite::write(&mut out, writer.get_ref().get_ref()).unwrap();
+		black_box(&out);
+	});
+}
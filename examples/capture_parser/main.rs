@@ -159,7 +159,16 @@ fn parse(path: &Path, cdclient: &mut Cdclient) -> Res<usize> {
 				|| file.name().contains("[27]"))
 		{
 			let mut ctx = ZipContext { zip: file, comps: &mut comps, cdclient, assert_fully_read: true };
-			let msg: Message = ctx.read().expect(&format!("Zip: {}, Filename: {}, {} bytes", path.to_str().unwrap(), ctx.zip.name(), ctx.zip.size()));
+			let res: Res<Message> = ctx.read();
+			let msg = match res {
+				Ok(x) => x,
+				Err(e) => {
+					let info = format!("Zip: {}, Filename: {}, {} bytes", path.to_str().unwrap(), ctx.zip.name(), ctx.zip.size());
+					drop(ctx);
+					print_trace(&mut zip, i, &mut comps, cdclient);
+					panic!("{}: {:?}", info, e);
+				}
+			};
 			file = ctx.zip;
 			if unsafe { PRINT_PACKETS } {
 				dbg!(&msg);
@@ -170,7 +179,12 @@ fn parse(path: &Path, cdclient: &mut Cdclient) -> Res<usize> {
 				// assert fully read
 				let mut rest = vec![];
 				std::io::Read::read_to_end(&mut file, &mut rest).unwrap();
-				assert_eq!(rest, vec![], "Zip: {}, Filename: {}, {} bytes", path.to_str().unwrap(), file.name(), file.size());
+				if !rest.is_empty() {
+					let info = format!("Zip: {}, Filename: {}, {} bytes", path.to_str().unwrap(), file.name(), file.size());
+					drop(file);
+					print_trace(&mut zip, i, &mut comps, cdclient);
+					panic!("{}: {} bytes left unread", info, rest.len());
+				}
 			}
 			i += 1;
 			continue;
@@ -182,6 +196,25 @@ fn parse(path: &Path, cdclient: &mut Cdclient) -> Res<usize> {
 	Ok(packet_count)
 }
 
+/// Prints an annotated decode trace of a packet that failed to parse.
+#[cfg(feature = "trace")]
+fn print_trace(zip: &mut ZipArchive<BufReader<File>>, i: usize, comps: &mut HashMap<u16, Vec<u32>>, cdclient: &mut Cdclient) {
+	use endio::LERead;
+
+	let mut data = vec![];
+	std::io::Read::read_to_end(&mut zip.by_index(i).unwrap(), &mut data).unwrap();
+	let trace = lu_packets::trace::trace_with(&data, |reader| {
+		let mut ctx = ZipContext { zip: reader, comps, cdclient, assert_fully_read: true };
+		LERead::read::<Message>(&mut ctx)
+	});
+	println!("{}", trace);
+}
+
+#[cfg(not(feature = "trace"))]
+fn print_trace(_zip: &mut ZipArchive<BufReader<File>>, _i: usize, _comps: &mut HashMap<u16, Vec<u32>>, _cdclient: &mut Cdclient) {
+	println!("Rerun with `--features trace` for an annotated decode trace.");
+}
+
 fn main() {
 	let args: Vec<String> = env::args().collect();
 	if args.len() < 3 {
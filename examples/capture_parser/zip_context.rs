@@ -49,14 +49,15 @@ use super::Cdclient;
 
 const COMP_ORDER: [u32; 35] = [108, 61, 1, 30, 20, 3, 40, 98, 7, 110, 109, 106, 4, 26, 17, 5, 9, 60, 11, 48, 25, 16, 100, 102, 19, 39, 23, 75, 42, 6, 49, 2, 44, 71, 107];
 
-pub struct ZipContext<'a> {
-	pub zip: ZipFile<'a>,
+/// Context for reading replica packets, from a zip file or from another reader like the one of a trace.
+pub struct ZipContext<'a, R = ZipFile<'a>> {
+	pub zip: R,
 	pub comps: &'a mut HashMap<u16, Vec<u32>>,
 	pub cdclient: &'a mut Cdclient,
 	pub assert_fully_read: bool,
 }
 
-impl ZipContext<'_> {
+impl<R> ZipContext<'_, R> {
 	fn apply_whitelist(comps: &mut Vec<u32>, config: &Option<LuNameValue>) {
 		if let Some(conf) = config {
 			if let Some(LnvValue::I32(1)) = conf.get(&lu!("componentWhitelist")) {
@@ -121,10 +122,10 @@ impl ZipContext<'_> {
 	}
 
 	#[rustfmt::skip]
-	fn map_constrs<R: std::io::Read>(comps: &Vec<u32>) -> Vec<fn(&mut BEBitReader<R>) -> Res<Box<dyn ComponentConstruction>>> {
+	fn map_constrs<T: std::io::Read>(comps: &Vec<u32>) -> Vec<fn(&mut BEBitReader<T>) -> Res<Box<dyn ComponentConstruction>>> {
 		use endio::Deserialize;
 
-		let mut constrs: Vec<fn(&mut BEBitReader<R>) -> Res<Box<dyn ComponentConstruction>>> = vec![];
+		let mut constrs: Vec<fn(&mut BEBitReader<T>) -> Res<Box<dyn ComponentConstruction>>> = vec![];
 		for comp in comps {
 			match comp {
 				1  =>  { constrs.push(|x| Ok(Box::new(ControllablePhysicsConstruction::deserialize(x)?))); }
@@ -169,15 +170,15 @@ impl ZipContext<'_> {
 	}
 }
 
-impl std::io::Read for ZipContext<'_> {
+impl<R: std::io::Read> std::io::Read for ZipContext<'_, R> {
 	fn read(&mut self, buf: &mut [u8]) -> Res<usize> {
 		self.zip.read(buf)
 	}
 }
 
 // hacky hardcoded components to be able to read player replicas without DB lookup
-impl ReplicaContext for ZipContext<'_> {
-	fn get_comp_constructions<R: std::io::Read>(&mut self, network_id: u16, lot: Lot, config: &Option<LuNameValue>) -> Vec<fn(&mut BEBitReader<R>) -> Res<Box<dyn ComponentConstruction>>> {
+impl<R> ReplicaContext for ZipContext<'_, R> {
+	fn get_comp_constructions<T: std::io::Read>(&mut self, network_id: u16, lot: Lot, config: &Option<LuNameValue>) -> Vec<fn(&mut BEBitReader<T>) -> Res<Box<dyn ComponentConstruction>>> {
 		let mut comps = self.cdclient.get_comps(lot).clone();
 
 		Self::apply_whitelist(&mut comps, config);
@@ -194,11 +195,11 @@ impl ReplicaContext for ZipContext<'_> {
 	}
 
 	#[rustfmt::skip]
-	fn get_comp_serializations<R: std::io::Read>(&mut self, network_id: u16) -> Vec<fn(&mut BEBitReader<R>) -> Res<Box<dyn ComponentSerialization>>> {
+	fn get_comp_serializations<T: std::io::Read>(&mut self, network_id: u16) -> Vec<fn(&mut BEBitReader<T>) -> Res<Box<dyn ComponentSerialization>>> {
 		use endio::Deserialize;
 
 		if let Some(comps) = self.comps.get(&network_id) {
-			let mut sers: Vec<fn(&mut BEBitReader<R>) -> Res<Box<dyn ComponentSerialization>>> = vec![];
+			let mut sers: Vec<fn(&mut BEBitReader<T>) -> Res<Box<dyn ComponentSerialization>>> = vec![];
 			for comp in comps {
 				match comp {
 					1   => { sers.push(|x| Ok(Box::new(ControllablePhysicsSerialization::deserialize(x)?))); }
@@ -0,0 +1,76 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Error, Fields};
+
+pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	gen_arbitrary(&input).into()
+}
+
+/**
+	Generates an `arbitrary::Arbitrary` impl, shared with the `GameMessage` and `ReplicaSerde` derives.
+
+	Fields are generated independently. Enums pick one of their variants, so the discriminant is always valid. Protocol constraints like string capacities are upheld by the impls of the field types.
+*/
+pub(crate) fn gen_arbitrary(input: &DeriveInput) -> TokenStream {
+	let name = &input.ident;
+	let mut generics = input.generics.clone();
+	for param in generics.type_params_mut() {
+		param.bounds.push(parse_quote!(::arbitrary::Arbitrary<'__arbitrary>));
+	}
+	let (_, ty_generics, _) = input.generics.split_for_impl();
+	let (_, _, where_clause) = generics.split_for_impl();
+	let mut impl_generics = generics.clone();
+	impl_generics.params.insert(0, parse_quote!('__arbitrary));
+	let (impl_generics, _, _) = impl_generics.split_for_impl();
+
+	let body = match &input.data {
+		Data::Struct(data) => {
+			let construct = gen_construct(&data.fields);
+			quote! { Ok(Self #construct) }
+		}
+		Data::Enum(data) => {
+			if data.variants.is_empty() {
+				return Error::new_spanned(&input.ident, "Arbitrary can't be derived for enums without variants").to_compile_error();
+			}
+			let last = data.variants.len() as u32 - 1;
+			let arms = data.variants.iter().enumerate().map(|(i, v)| {
+				let i = i as u32;
+				let ident = &v.ident;
+				let construct = gen_construct(&v.fields);
+				quote! { #i => Self::#ident #construct, }
+			});
+			quote! {
+				Ok(match u.int_in_range(0..=#last)? {
+					#(#arms)*
+					_ => unreachable!(),
+				})
+			}
+		}
+		Data::Union(data) => return Error::new_spanned(data.union_token, "Arbitrary can't be derived for unions").to_compile_error(),
+	};
+
+	quote! {
+		#[cfg(feature = "arbitrary")]
+		impl #impl_generics ::arbitrary::Arbitrary<'__arbitrary> for #name #ty_generics #where_clause {
+			#[allow(unused_variables)]
+			fn arbitrary(u: &mut ::arbitrary::Unstructured<'__arbitrary>) -> ::arbitrary::Result<Self> {
+				#body
+			}
+		}
+	}
+}
+
+fn gen_construct(fields: &Fields) -> TokenStream {
+	match fields {
+		Fields::Named(fields) => {
+			let idents = fields.named.iter().map(|f| &f.ident);
+			quote! { { #(#idents: ::arbitrary::Arbitrary::arbitrary(u)?,)* } }
+		}
+		Fields::Unnamed(fields) => {
+			let values = fields.unnamed.iter().map(|_| quote! { ::arbitrary::Arbitrary::arbitrary(u)? });
+			quote! { ( #(#values),* ) }
+		}
+		Fields::Unit => quote! {},
+	}
+}
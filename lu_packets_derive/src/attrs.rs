@@ -0,0 +1,59 @@
+use syn::{punctuated::Punctuated, token::Comma, Attribute, Data, DeriveInput, Error, Lit, LitInt, Meta, NestedMeta, Result};
+
+/// Parses an attribute with exactly one argument, like `#[since(version)]` or `#[default(value)]`.
+pub(crate) fn parse_single_arg(attr: &Attribute, name: &str) -> Result<NestedMeta> {
+	let mut list = parse_list(attr, name)?;
+	if list.len() != 1 {
+		return Err(Error::new_spanned(attr, format!("{} attribute should have exactly one argument", name)));
+	}
+	Ok(list.pop().unwrap().into_value())
+}
+
+/// Parses an attribute with a list of arguments, like `#[test_params(a, b)]`.
+pub(crate) fn parse_list(attr: &Attribute, name: &str) -> Result<Punctuated<NestedMeta, Comma>> {
+	match attr.parse_meta()? {
+		Meta::List(x) => Ok(x.nested),
+		_ => Err(Error::new_spanned(attr, format!("{} needs to be a list", name))),
+	}
+}
+
+/// Parses an attribute of the form `#[name = integer]`, like `#[padding = 4]`.
+pub(crate) fn parse_int(attr: &Attribute, name: &str) -> Result<LitInt> {
+	let lit = match attr.parse_meta()? {
+		Meta::NameValue(x) => x.lit,
+		_ => return Err(Error::new_spanned(attr, format!("{} needs to be name=value", name))),
+	};
+	match lit {
+		Lit::Int(x) => Ok(x),
+		lit => Err(Error::new_spanned(lit, format!("{} needs to be an integer", name))),
+	}
+}
+
+/// Checks the helper attributes named in `names` on the input, its variants and its fields.
+///
+/// The getters for these attributes are called all over code generation, so the derives run this first and report malformed attributes as spanned compile errors, after which the getters can't fail.
+pub(crate) fn check(input: &DeriveInput, names: &[&str]) -> Result<()> {
+	let mut attrs: Vec<&Attribute> = input.attrs.iter().collect();
+	match &input.data {
+		Data::Struct(data) => attrs.extend(data.fields.iter().flat_map(|f| &f.attrs)),
+		Data::Enum(data) => {
+			for v in &data.variants {
+				attrs.extend(&v.attrs);
+				attrs.extend(v.fields.iter().flat_map(|f| &f.attrs));
+			}
+		}
+		Data::Union(_) => {}
+	}
+	for attr in attrs {
+		for name in names {
+			if !attr.path.is_ident(name) {
+				continue;
+			}
+			match *name {
+				"padding" | "trailing_padding" | "pre_disc_padding" | "post_disc_padding" => { parse_int(attr, name)?; }
+				_ => { parse_single_arg(attr, name)?; }
+			}
+		}
+	}
+	Ok(())
+}
@@ -0,0 +1,118 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{DeriveInput, Fields};
+
+/**
+	Generates a builder for a struct with named fields, and a `Default` impl if all fields have defaults.
+
+	`defaults` contains the default value for each field, if it has one. Fields without a default are required: the builder tracks them with one type parameter each, starting out as `()` and changing to the field type once set, so that `build` is only available once all of them have been set.
+*/
+pub(crate) fn gen_builder(input: &DeriveInput, fields: &Fields, defaults: &[Option<TokenStream>]) -> TokenStream {
+	let fields = match fields {
+		Fields::Named(fields) => fields,
+		_ => return quote! {},
+	};
+	// not needed so far
+	if !input.generics.params.is_empty() {
+		return quote! {};
+	}
+	let name = &input.ident;
+	let vis = &input.vis;
+	let builder = Ident::new(&format!("{}Builder", name), Span::call_site());
+
+	let mut params = vec![];
+	let mut req_tys = vec![];
+	let mut units = vec![];
+	let mut init = vec![];
+	let mut moves = vec![];
+	for (f, default) in fields.named.iter().zip(defaults) {
+		let ident = &f.ident;
+		moves.push(quote! { #ident: self.#ident, });
+		match default {
+			Some(x) => init.push(quote! { #ident: #x, }),
+			None => {
+				params.push(Ident::new(&format!("__{}", params.len()), Span::call_site()));
+				req_tys.push(f.ty.clone());
+				units.push(quote! { () });
+				init.push(quote! { #ident: (), });
+			}
+		}
+	}
+
+	let mut builder_fields = vec![];
+	let mut setters = vec![];
+	let mut req_index = 0;
+	for (f, default) in fields.named.iter().zip(defaults) {
+		let ident = &f.ident;
+		let ty = &f.ty;
+		let field_vis = &f.vis;
+		let doc = format!("Sets [`{}`]({}::{}).", ident.as_ref().unwrap(), name, ident.as_ref().unwrap());
+		if default.is_some() {
+			builder_fields.push(quote! { #ident: #ty, });
+			setters.push(quote! {
+				impl<#(#params),*> #builder<#(#params),*> {
+					#[doc = #doc]
+					#field_vis fn #ident(mut self, #ident: #ty) -> Self {
+						self.#ident = #ident;
+						self
+					}
+				}
+			});
+		} else {
+			let param = &params[req_index];
+			builder_fields.push(quote! { #ident: #param, });
+			let mut set_params = params.iter().map(|x| quote! { #x }).collect::<Vec<_>>();
+			set_params[req_index] = quote! { #ty };
+			let others: Vec<_> = moves.iter().zip(fields.named.iter()).filter(|(_, x)| x.ident != *ident).map(|(m, _)| m).collect();
+			setters.push(quote! {
+				impl<#(#params),*> #builder<#(#params),*> {
+					#[doc = #doc]
+					#field_vis fn #ident(self, #ident: #ty) -> #builder<#(#set_params),*> {
+						#builder { #(#others)* #ident }
+					}
+				}
+			});
+			req_index += 1;
+		}
+	}
+
+	let builder_doc = format!("Builder for [`{}`], created with [`{}::builder`].", name, name);
+	let default_impl = if params.is_empty() {
+		let init = init.iter();
+		quote! {
+			impl ::std::default::Default for #name {
+				fn default() -> Self {
+					Self { #(#init)* }
+				}
+			}
+		}
+	} else {
+		quote! {}
+	};
+
+	quote! {
+		impl #name {
+			/// Creates a builder. Fields with a default value can be left out, all other fields have to be set before calling `build`.
+			#vis fn builder() -> #builder<#(#units),*> {
+				#builder { #(#init)* }
+			}
+		}
+
+		#[doc = #builder_doc]
+		#[must_use]
+		#vis struct #builder<#(#params),*> {
+			#(#builder_fields)*
+		}
+
+		#(#setters)*
+
+		impl #builder<#(#req_tys),*> {
+			/// Creates the struct.
+			#vis fn build(self) -> #name {
+				#name { #(#moves)* }
+			}
+		}
+
+		#default_impl
+	}
+}
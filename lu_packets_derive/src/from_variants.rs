@@ -1,12 +1,12 @@
 use quote::quote;
-use syn::{parse_macro_input, Data, DeriveInput, Fields, Generics, Ident};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Generics, Ident};
 
 pub fn derive(input: proc_macro::TokenStream, opt_dest: Option<&Ident>) -> proc_macro::TokenStream {
 	let input = parse_macro_input!(input as DeriveInput);
 
 	let data = match &input.data {
 		Data::Enum(data) => data,
-		_ => panic!("only enums are supported"),
+		_ => return Error::new_spanned(&input.ident, "only enums are supported").to_compile_error().into(),
 	};
 
 	let name = &input.ident;
@@ -25,13 +25,13 @@ pub fn derive(input: proc_macro::TokenStream, opt_dest: Option<&Ident>) -> proc_
 	for v in &data.variants {
 		let variant = &v.ident;
 		let fields = match &v.fields {
-			Fields::Named(_) => panic!("use a tuple or unit variant"),
+			Fields::Named(fields) => return Error::new_spanned(fields, "use a tuple or unit variant").to_compile_error().into(),
 			Fields::Unit => { continue }
 			Fields::Unnamed(fields) => fields,
 		};
 
 		if fields.unnamed.len() != 1 {
-			panic!("use exactly one tuple argument");
+			return Error::new_spanned(fields, "use exactly one tuple argument").to_compile_error().into();
 		}
 		let first = fields.unnamed.first().unwrap();
 		let variant_ty = &first.ty;
@@ -1,9 +1,20 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Field, Fields, Meta, NestedMeta, Type};
+use syn::{parse_macro_input, parse_quote, Data, DataEnum, DeriveInput, Error, Field, Fields, NestedMeta, Type};
+
+use crate::arbitrary::gen_arbitrary;
+use crate::attrs::{self, parse_single_arg};
+use crate::builder::gen_builder;
+use crate::reflect::gen_reflect;
+use crate::replica_serde::{gen_check_variant_version, get_enum_type};
+use crate::schema::{self, Encoding};
+use crate::version::{gen_get_version, get_since};
 
 pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 	let input = parse_macro_input!(input as DeriveInput);
+	if let Err(e) = attrs::check(&input, &["default", "since"]) {
+		return e.to_compile_error().into();
+	}
 
 	let name = &input.ident;
 	let des_impl_generics = &mut input.generics.clone();
@@ -15,15 +26,48 @@ pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 	ser_impl_generics.params.push(parse_quote!(__WRITER: ::std::io::Write));
 	let (ser_impl_generics, _, _) = ser_impl_generics.split_for_impl();
 
-	let (_, ty_generics, where_clause) = input.generics.split_for_impl();
-
-	let data = match &input.data {
-		Data::Struct(data) => data,
-		_ => unimplemented!(),
-	};
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
-	let deser_code = gen_deser_code(&data.fields);
-	let ser_code = gen_ser_code(&data.fields);
+	let deser_code;
+	let ser_code;
+	let size_code;
+	let schema_body;
+	let mut builder = quote! {};
+	let mut byte_offsets = quote! {};
+	match &input.data {
+		Data::Struct(data) => {
+			deser_code = gen_deser_code_struct(&data.fields);
+			ser_code = gen_ser_code_struct(&data.fields, name);
+			size_code = gen_size_code_struct(&data.fields, name);
+			schema_body = schema::gen_struct(&input, &data.fields, Encoding::GameMessage);
+			let defaults: Vec<_> = data.fields.iter().map(|f| get_gm_default(f).map(|x| quote! { #x })).collect();
+			builder = gen_builder(&input, &data.fields, &defaults);
+			let offsets = gen_byte_offsets(&data.fields);
+			byte_offsets = quote! {
+				impl #impl_generics #name #ty_generics #where_clause {
+					/// Byte offset of a field in the encoded message after the message ID, if the fields before it are byte-aligned and have a fixed size.
+					#[allow(dead_code)]
+					pub(crate) fn byte_offset(field: &str) -> ::std::option::Option<usize> {
+						#offsets
+					}
+				}
+			};
+		}
+		Data::Enum(data) => {
+			let ty = match get_enum_type(&input) {
+				Ok(x) => x,
+				Err(e) => return e.to_compile_error().into(),
+			};
+			deser_code = gen_deser_code_enum(data, name, &ty);
+			ser_code = gen_ser_code_enum(data, name, &ty);
+			size_code = gen_size_code_enum(data, name, &ty);
+			schema_body = schema::gen_enum(&input, data, &ty, Encoding::GameMessage);
+		}
+		Data::Union(data) => return Error::new_spanned(data.union_token, "GameMessage can't be derived for unions").to_compile_error().into(),
+	}
+	let schema_impl = schema::gen_impl(&input, schema_body);
+	let reflect_impl = gen_reflect(&input);
+	let arbitrary_impl = gen_arbitrary(&input);
 	(quote! {
 		impl #des_impl_generics ::endio::Deserialize<::endio::LE, __READER> for #name #ty_generics #where_clause {
 			fn deserialize(reader: &mut __READER) -> ::std::io::Result<Self> {
@@ -36,41 +80,115 @@ pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 				#ser_code
 			}
 		}
+
+		impl #impl_generics crate::common::ByteSize for #name #ty_generics #where_clause {
+			// bools only take up a bit regardless of their value
+			#[allow(unused_variables)]
+			fn byte_size(&self) -> ::std::io::Result<usize> {
+				#size_code
+			}
+		}
+
+		#schema_impl
+
+		#reflect_impl
+
+		#arbitrary_impl
+
+		#builder
+
+		#byte_offsets
 	}).into()
 }
 
-fn gen_deser_code(fields: &Fields) -> TokenStream {
-	let fields = match fields {
-		Fields::Named(fields) => fields,
-		_ => unimplemented!(),
-	};
+/// Fields are byte-aligned up to the first bool or defaulted field, where the message switches to bit level. Offsets after a `#[since]` field depend on the network version, so they aren't known either.
+fn gen_byte_offsets(fields: &Fields) -> TokenStream {
+	let mut checks = vec![];
+	for (i, f) in fields.iter().enumerate() {
+		if is_bool(f) || get_gm_default(f).is_some() || get_since(&f.attrs).is_some() {
+			break;
+		}
+		let name = match &f.ident {
+			Some(x) => x.to_string(),
+			None => i.to_string(),
+		};
+		let ty = &f.ty;
+		checks.push(quote! {
+			if field == #name {
+				return ::std::option::Option::Some(__offset);
+			}
+			__offset += <#ty as crate::world::gm::GmParam>::FIXED_SIZE?;
+		});
+	}
+	quote! {
+		#[allow(unused_mut, unused_variables)]
+		let mut __offset = 0;
+		#(#checks)*
+		::std::option::Option::None
+	}
+}
+
+/// Names the fields are bound to: their own name for named fields, `__0`, `__1`, ... for tuple fields.
+fn bindings(fields: &Fields) -> Vec<Ident> {
+	fields.iter().enumerate().map(|(i, f)| match &f.ident {
+		Some(x) => x.clone(),
+		None => Ident::new(&format!("__{}", i), Span::call_site()),
+	}).collect()
+}
+
+/// Generates a pattern or constructor for the fields from their bindings.
+fn gen_fields_pat(fields: &Fields) -> TokenStream {
+	let bindings = bindings(fields);
+	match fields {
+		Fields::Named(_) => quote! { { #(#bindings),* } },
+		Fields::Unnamed(_) => quote! { ( #(#bindings),* ) },
+		Fields::Unit => quote! {},
+	}
+}
+
+fn uses_since<'a>(mut fields: impl Iterator<Item=&'a Field>) -> bool {
+	fields.any(|f| get_since(&f.attrs).is_some())
+}
+
+/// Variants can be `#[since]` a network version too, like in [`crate::replica_serde`].
+fn enum_uses_since(data: &DataEnum) -> bool {
+	data.variants.iter().any(|v| get_since(&v.attrs).is_some()) || uses_since(data.variants.iter().flat_map(|v| v.fields.iter()))
+}
+
+fn is_bool(f: &Field) -> bool {
+	match &f.ty {
+		Type::Path(path) => path.path.is_ident("bool"),
+		_ => false,
+	}
+}
+
+/// Reads the fields into their bindings. The reader switches to bit level at the first bool or defaulted field, for the rest of the fields.
+fn gen_deser_fields(fields: &Fields) -> TokenStream {
 	let mut msg_needs_bitreader = false;
-	let mut idents = vec![];
 	let mut deser = vec![];
-	for f in &fields.named {
-		let ident = &f.ident;
-		idents.push(quote! { #ident, });
-
-		let is_bool = match &f.ty {
-			Type::Path(path) => path.path.is_ident("bool"),
-			_ => false,
+	for (i, (f, binding)) in fields.iter().zip(bindings(fields)).enumerate() {
+		let is_bool = is_bool(f);
+		let default = get_gm_default(f);
+		let since = get_since(&f.attrs);
+		let name = match &f.ident {
+			Some(x) => x.to_string(),
+			None => i.to_string(),
 		};
-		let default = get_gm_default(&f);
 		let field_needs_bitreader = is_bool || default.is_some();
 		let create_bitreader = if !msg_needs_bitreader && field_needs_bitreader {
 			msg_needs_bitreader = true;
-			quote! { let mut reader = &mut ::endio_bit::BEBitReader::new(reader); }
+			quote! { let mut reader = &mut crate::common::bit_reader(reader); }
 		} else {
 			quote! { }
 		};
 		let val = if is_bool {
-			quote! { reader.read_bit()? }
+			quote! { crate::common::read_bit(reader)? }
 		} else {
 			let parse = quote! { crate::world::gm::GmParam::deserialize(reader)? };
-			match default {
+			match &default {
 				None => quote! { #parse },
 				Some(default) => quote! {
-					if reader.read_bit()? {
+					if crate::common::read_bit(reader)? {
 						#parse
 					} else {
 						#default
@@ -78,47 +196,108 @@ fn gen_deser_code(fields: &Fields) -> TokenStream {
 				},
 			}
 		};
+		let val = match since {
+			None => val,
+			Some(since) => {
+				let fallback = match default {
+					None => quote! { ::std::default::Default::default() },
+					Some(default) => quote! { #default },
+				};
+				quote! {
+					if __version < #since {
+						#fallback
+					} else {
+						#val
+					}
+				}
+			}
+		};
 		deser.push(quote! {
 			#create_bitreader
-			let #ident = #val;
+			#[cfg(feature = "trace")]
+			let __trace = crate::trace::enter_field(#name);
+			let #binding = #val;
+			#[cfg(feature = "trace")]
+			__trace.exit(&#binding);
+		});
+	}
+	if msg_needs_bitreader {
+		// the bits left in the bit reader's last byte are skipped
+		deser.push(quote! {
+			#[cfg(feature = "trace")]
+			crate::trace::align();
 		});
 	}
+	quote! { #(#deser)* }
+}
+
+fn gen_deser_code_struct(fields: &Fields) -> TokenStream {
+	let get_version = gen_get_version(uses_since(fields.iter()), quote! { reader });
+	let deser = gen_deser_fields(fields);
+	let construct = gen_fields_pat(fields);
 	quote! {
-		#(#deser)*
-		Ok(Self {#(#idents)* })
+		#get_version
+		#deser
+		Ok(Self #construct)
 	}
 }
 
-fn gen_ser_code(fields: &Fields) -> TokenStream {
-	let fields = match fields {
-		Fields::Named(fields) => fields,
-		_ => unimplemented!(),
-	};
+fn gen_deser_code_enum(data: &DataEnum, name: &Ident, ty: &Ident) -> TokenStream {
+	let get_version = gen_get_version(enum_uses_since(data), quote! { reader });
+	let last_disc: syn::ExprLit = parse_quote! { 0 };
+	let mut last_disc = &last_disc.into();
+	let mut disc_offset = 0;
+	let mut arms = vec![];
+	for v in &data.variants {
+		let ident = &v.ident;
+		if let Some((_, x)) = &v.discriminant {
+			last_disc = x;
+			disc_offset = 0;
+		}
+		let check_version = gen_check_variant_version(v);
+		let deser = gen_deser_fields(&v.fields);
+		let construct = gen_fields_pat(&v.fields);
+		arms.push(quote! { disc if disc == (#last_disc + (#disc_offset as #ty)) => {
+			#check_version
+			#[cfg(feature = "trace")]
+			let __trace_variant = crate::trace::enter_variant(stringify!(#ident));
+			#deser
+			Ok(Self::#ident #construct)
+		} });
+		disc_offset += 1;
+	}
+	quote! {
+		#get_version
+		let disc: #ty = ::endio::LERead::read(reader)?;
+		match disc {
+			#(#arms)*
+			_ => Err(::std::io::Error::new(::std::io::ErrorKind::InvalidData, format!("invalid discriminant value for {}: {}", stringify!(#name), disc))),
+		}
+	}
+}
+
+/// Writes the fields from their bindings, which are references. The writer switches to bit level like the reader does.
+fn gen_ser_fields(fields: &Fields) -> TokenStream {
 	let mut msg_needs_bitwriter = false;
 	let mut ser = vec![];
-	for f in &fields.named {
-		let ident = &f.ident;
-
-		let is_bool = match &f.ty {
-			Type::Path(path) => path.path.is_ident("bool"),
-			_ => false,
-		};
-		let default = get_gm_default(&f);
+	for (f, binding) in fields.iter().zip(bindings(fields)) {
+		let is_bool = is_bool(f);
+		let default = get_gm_default(f);
 		let field_needs_bitwriter = is_bool || default.is_some();
 		let create_bitwriter = if !msg_needs_bitwriter && field_needs_bitwriter {
 			msg_needs_bitwriter = true;
-			quote! { let mut writer = &mut ::endio_bit::BEBitWriter::new(writer); }
+			quote! { let mut writer = &mut crate::common::bit_writer(writer); }
 		} else {
 			quote! { }
 		};
 		let write = if is_bool {
-			quote! { writer.write_bit(self.#ident)?; }
+			quote! { writer.write_bit(*#binding)?; }
 		} else {
-			let write_field = quote! { crate::world::gm::GmParam::serialize(&self.#ident, writer)? };
+			let write_field = quote! { crate::world::gm::GmParam::serialize(#binding, writer)? };
 			match default {
 				None => quote! { #write_field; },
 				Some(default) => quote! {
-					let is_not_default = self.#ident != #default;
+					let is_not_default = *#binding != #default;
 					writer.write_bit(is_not_default)?;
 					if is_not_default {
 						#write_field;
@@ -126,32 +305,141 @@ fn gen_ser_code(fields: &Fields) -> TokenStream {
 				},
 			}
 		};
+		let write = match get_since(&f.attrs) {
+			None => write,
+			Some(since) => quote! {
+				if __version >= #since {
+					#write
+				}
+			},
+		};
 		ser.push(quote! {
 			#create_bitwriter
 			#write
 		});
 	}
+	quote! { #(#ser)* }
+}
+
+/// `self` is a reference to the message in the `Serialize` impl, so the patterns name the type instead of using `Self`.
+fn gen_ser_code_struct(fields: &Fields, name: &Ident) -> TokenStream {
+	let get_version = gen_get_version(uses_since(fields.iter()), quote! { writer });
+	let pat = gen_fields_pat(fields);
+	let ser = gen_ser_fields(fields);
 	quote! {
-		#(#ser)*
+		#get_version
+		let #name #pat = self;
+		#ser
 		Ok(())
 	}
 }
 
-fn get_gm_default(input: &Field) -> Option<NestedMeta> {
-	for attr in &input.attrs {
-		if !attr.path.is_ident("default") {
-			continue;
+fn gen_ser_code_enum(data: &DataEnum, name: &Ident, ty: &Ident) -> TokenStream {
+	let get_version = gen_get_version(enum_uses_since(data), quote! { writer });
+	let mut checks = vec![];
+	let mut arms = vec![];
+	for v in &data.variants {
+		let ident = &v.ident;
+		let pat = gen_fields_pat(&v.fields);
+		let check_version = gen_check_variant_version(v);
+		let ser = gen_ser_fields(&v.fields);
+		checks.push(quote! { #name::#ident { .. } => { #check_version } });
+		arms.push(quote! { #name::#ident #pat => { #ser } });
+	}
+	let check_version = if enum_uses_since(data) {
+		quote! {
+			match self {
+				#(#checks)*
+			}
 		}
-		let meta = match attr.parse_meta() {
-			Err(_) => panic!("encountered unparseable default attribute"),
-			Ok(x) => x,
-		};
-		let list = match meta {
-			Meta::List(x) => x,
-			_ => panic!("default attribute has wrong format"),
+	} else {
+		quote! {}
+	};
+	quote! {
+		#get_version
+		#check_version
+		let disc = unsafe { *(self as *const #name as *const #ty) };
+		::endio::LEWrite::write(writer, disc)?;
+		match self {
+			#(#arms)*
+		}
+		Ok(())
+	}
+}
+
+/// Adds the bits of the fields to `__size`, like the writer switching to bit level.
+fn gen_size_fields(fields: &Fields) -> TokenStream {
+	let mut size = vec![];
+	for (f, binding) in fields.iter().zip(bindings(fields)) {
+		let param_size = quote! { __size += crate::world::gm::GmParam::size(#binding)? * 8; };
+		let field_size = if is_bool(f) {
+			quote! { __size += 1; }
+		} else {
+			match get_gm_default(f) {
+				None => param_size,
+				Some(default) => quote! {
+					__size += 1;
+					if *#binding != #default {
+						#param_size
+					}
+				},
+			}
 		};
-		let nested_meta = list.nested.first().expect("default attribute should have exactly one argument");
-		return Some(nested_meta.clone());
+		size.push(match get_since(&f.attrs) {
+			None => field_size,
+			Some(since) => quote! {
+				if __version >= #since {
+					#field_size
+				}
+			},
+		});
+	}
+	quote! { #(#size)* }
+}
+
+/// Sizes are for the live network version.
+fn gen_live_version(needed: bool) -> TokenStream {
+	if needed {
+		quote! { let __version = crate::common::NETWORK_VERSION_LIVE; }
+	} else {
+		quote! {}
 	}
-	None
+}
+
+/// The bit writer is flushed at the end, so the bits are rounded up to bytes.
+fn gen_size_code_struct(fields: &Fields, name: &Ident) -> TokenStream {
+	let live_version = gen_live_version(uses_since(fields.iter()));
+	let pat = gen_fields_pat(fields);
+	let size = gen_size_fields(fields);
+	quote! {
+		#live_version
+		let #name #pat = self;
+		let mut __size = 0;
+		#size
+		Ok((__size + 7) / 8)
+	}
+}
+
+fn gen_size_code_enum(data: &DataEnum, name: &Ident, ty: &Ident) -> TokenStream {
+	let live_version = gen_live_version(uses_since(data.variants.iter().flat_map(|v| v.fields.iter())));
+	let mut arms = vec![];
+	for v in &data.variants {
+		let ident = &v.ident;
+		let pat = gen_fields_pat(&v.fields);
+		let size = gen_size_fields(&v.fields);
+		arms.push(quote! { #name::#ident #pat => { #size } });
+	}
+	quote! {
+		#live_version
+		let mut __size = ::std::mem::size_of::<#ty>() * 8;
+		match self {
+			#(#arms)*
+		}
+		Ok((__size + 7) / 8)
+	}
+}
+
+/// Parses `#[default(value)]`. Malformed attributes are reported by [`crate::attrs::check`] before this is called.
+pub(crate) fn get_gm_default(input: &Field) -> Option<NestedMeta> {
+	input.attrs.iter().find(|x| x.path.is_ident("default")).and_then(|x| parse_single_arg(x, "default").ok())
 }
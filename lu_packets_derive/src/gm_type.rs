@@ -1,13 +1,20 @@
+use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
+
+use crate::replica_serde::get_enum_type;
 
 pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 	let input = parse_macro_input!(input as DeriveInput);
 	let name = &input.ident;
 	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+	let size_code = gen_size_code(&input);
+	let fixed_size = gen_fixed_size(&input);
 
 	(quote! {
 		impl #impl_generics crate::world::gm::GmParam for #name #ty_generics #where_clause {
+			const FIXED_SIZE: ::std::option::Option<usize> = #fixed_size;
+
 			fn deserialize<R: ::std::io::Read>(reader: &mut R) -> ::std::io::Result<Self> {
 				::endio::LERead::read(reader)
 			}
@@ -15,6 +22,43 @@ pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 			fn serialize<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
 				::endio::LEWrite::write(writer, self)
 			}
+
+			fn size(&self) -> ::std::io::Result<usize> {
+				#size_code
+			}
 		}
 	}).into()
 }
+
+/// Newtypes have the size of their field and unit-only enums the size of their discriminant, other types count the bytes by serializing.
+fn gen_size_code(input: &DeriveInput) -> TokenStream {
+	match &input.data {
+		Data::Struct(data) => match &data.fields {
+			Fields::Unnamed(fields) if fields.unnamed.len() == 1 => quote! { crate::common::ByteSize::byte_size(&self.0) },
+			_ => quote! { crate::common::ByteSize::byte_size(self) },
+		},
+		Data::Enum(data) if data.variants.iter().all(|v| matches!(v.fields, Fields::Unit)) => match get_enum_type(input) {
+			Ok(ty) => quote! { Ok(::std::mem::size_of::<#ty>()) },
+			Err(e) => e.to_compile_error(),
+		},
+		_ => quote! { crate::common::ByteSize::byte_size(self) },
+	}
+}
+
+/// Like [`gen_size_code`], but only for sizes that don't depend on the value.
+fn gen_fixed_size(input: &DeriveInput) -> TokenStream {
+	match &input.data {
+		Data::Struct(data) => match &data.fields {
+			Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+				let ty = &fields.unnamed[0].ty;
+				quote! { <#ty as crate::world::gm::GmParam>::FIXED_SIZE }
+			}
+			_ => quote! { None },
+		},
+		Data::Enum(data) if data.variants.iter().all(|v| matches!(v.fields, Fields::Unit)) => match get_enum_type(input) {
+			Ok(ty) => quote! { Some(::std::mem::size_of::<#ty>()) },
+			Err(e) => e.to_compile_error(),
+		},
+		_ => quote! { None },
+	}
+}
@@ -1,14 +1,27 @@
+mod arbitrary;
+mod attrs;
+mod builder;
 mod from_variants;
 mod game_message;
 mod gm_type;
+mod message_deserialize;
+mod message_handler;
+mod reflect;
 mod replica_serde;
+mod schema;
 mod variant_tests;
+mod version;
 
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::quote;
 use syn::Ident;
 
+#[proc_macro_derive(Arbitrary)]
+pub fn derive_arbitrary(input: TokenStream) -> TokenStream {
+	arbitrary::derive(input)
+}
+
 #[proc_macro_derive(BitVariantTests, attributes(test_params))]
 pub fn derive_bit_variant_tests(input: TokenStream) -> TokenStream {
 	variant_tests::derive(input, quote!(::endio_bit::BEBitReader::new(&mut bin)), quote!(::endio_bit::BEBitWriter::new(&mut out)))
@@ -19,7 +32,7 @@ pub fn derive_from_variants(input: TokenStream) -> TokenStream {
 	from_variants::derive(input, None)
 }
 
-#[proc_macro_derive(GameMessage, attributes(default))]
+#[proc_macro_derive(GameMessage, attributes(default, since))]
 pub fn derive_game_message_deserialize(input: TokenStream) -> TokenStream {
 	game_message::derive(input)
 }
@@ -29,12 +42,27 @@ pub fn derive_gm_type(input: TokenStream) -> TokenStream {
 	gm_type::derive(input)
 }
 
+#[proc_macro_derive(MessageDeserialize, attributes(padding, pre_disc_padding, post_disc_padding))]
+pub fn derive_message_deserialize(input: TokenStream) -> TokenStream {
+	message_deserialize::derive(input)
+}
+
 #[proc_macro_derive(MessageFromVariants)]
 pub fn derive_message_from_variants(input: TokenStream) -> TokenStream {
 	from_variants::derive(input, Some(&Ident::new("Message", Span::call_site())))
 }
 
-#[proc_macro_derive(ReplicaSerde, attributes(padding, trailing_padding))]
+#[proc_macro_derive(MessageHandler, attributes(handler_args))]
+pub fn derive_message_handler(input: TokenStream) -> TokenStream {
+	message_handler::derive(input)
+}
+
+#[proc_macro_derive(Reflect)]
+pub fn derive_reflect(input: TokenStream) -> TokenStream {
+	reflect::derive(input)
+}
+
+#[proc_macro_derive(ReplicaSerde, attributes(padding, since, trailing_padding))]
 pub fn derive_replica(input: TokenStream) -> TokenStream {
 	replica_serde::derive(input)
 }
@@ -44,6 +72,11 @@ pub fn derive_replica_variant_tests(input: TokenStream) -> TokenStream {
 	variant_tests::derive(input, quote!(crate::raknet::client::replica::DummyContext { inner: &mut bin }), quote!(&mut out))
 }
 
+#[proc_macro_derive(Schema, attributes(padding, trailing_padding, pre_disc_padding, post_disc_padding))]
+pub fn derive_schema(input: TokenStream) -> TokenStream {
+	schema::derive(input)
+}
+
 #[proc_macro_derive(VariantTests, attributes(test_params))]
 pub fn derive_variant_tests(input: TokenStream) -> TokenStream {
 	variant_tests::derive(input, quote!(bin), quote!(&mut out))
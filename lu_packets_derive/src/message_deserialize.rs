@@ -0,0 +1,102 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Error, Fields, LitInt, Type};
+
+use crate::attrs;
+use crate::replica_serde::{get_enum_type, get_padding};
+
+/// Derives `Deserialize` for message enums like endio's derive does, additionally adding the variant to the path of a trace.
+pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	if let Err(e) = attrs::check(&input, &["padding", "pre_disc_padding", "post_disc_padding"]) {
+		return e.to_compile_error().into();
+	}
+	let data = match &input.data {
+		Data::Enum(data) => data,
+		_ => return Error::new_spanned(&input.ident, "MessageDeserialize can only be derived for enums").to_compile_error().into(),
+	};
+	let ty = match get_enum_type(&input) {
+		Ok(x) => x,
+		Err(e) => return e.to_compile_error().into(),
+	};
+	let name = &input.ident;
+
+	let mut field_tys: Vec<&Type> = vec![];
+	let last_disc: syn::ExprLit = parse_quote! { 0 };
+	let mut last_disc = &last_disc.into();
+	let mut disc_offset = 0;
+	let mut arms = vec![];
+	for v in &data.variants {
+		let ident = &v.ident;
+		if let Some((_, x)) = &v.discriminant {
+			last_disc = x;
+			disc_offset = 0;
+		}
+		field_tys.extend(v.fields.iter().map(|f| &f.ty));
+		let deser_fields = gen_deser_fields(&v.fields);
+		arms.push(quote! { disc if disc == (#last_disc + (#disc_offset as #ty)) => {
+			#[cfg(feature = "trace")]
+			let __trace_variant = crate::trace::enter_variant(stringify!(#ident));
+			Ok(Self::#ident #deser_fields)
+		} });
+		disc_offset += 1;
+	}
+	let read_pre_padding = gen_read_padding(&get_padding(&input.attrs, "pre_disc_padding"));
+	let read_post_padding = gen_read_padding(&get_padding(&input.attrs, "post_disc_padding"));
+
+	let mut generics = input.generics.clone();
+	generics.params.push(parse_quote!(__READER: ::std::io::Read));
+	let where_clause = generics.make_where_clause();
+	where_clause.predicates.push(parse_quote!(#ty: ::endio::Deserialize<::endio::LE, __READER>));
+	for ty in field_tys {
+		where_clause.predicates.push(parse_quote!(#ty: ::endio::Deserialize<::endio::LE, __READER>));
+	}
+	let (impl_generics, _, where_clause) = generics.split_for_impl();
+	let (_, ty_generics, _) = input.generics.split_for_impl();
+
+	(quote! {
+		impl #impl_generics ::endio::Deserialize<::endio::LE, __READER> for #name #ty_generics #where_clause {
+			fn deserialize(reader: &mut __READER) -> ::std::io::Result<Self> {
+				#read_pre_padding
+				let disc: #ty = ::endio::LERead::read(reader)?;
+				#read_post_padding
+				match disc {
+					#(#arms)*
+					_ => Err(::std::io::Error::new(::std::io::ErrorKind::InvalidData, format!("invalid discriminant value for {}: {}", stringify!(#name), disc))),
+				}
+			}
+		}
+	}).into()
+}
+
+fn gen_deser_fields(fields: &Fields) -> TokenStream {
+	let read = |f: &syn::Field| {
+		let read_padding = gen_read_padding(&get_padding(&f.attrs, "padding"));
+		quote! { { #read_padding ::endio::LERead::read(reader)? } }
+	};
+	match fields {
+		Fields::Named(fields) => {
+			let deser = fields.named.iter().map(|f| {
+				let ident = &f.ident;
+				let read = read(f);
+				quote! { #ident: #read }
+			});
+			quote! { { #(#deser,)* } }
+		}
+		Fields::Unnamed(fields) => {
+			let deser = fields.unnamed.iter().map(read);
+			quote! { ( #(#deser,)* ) }
+		}
+		Fields::Unit => quote! {},
+	}
+}
+
+fn gen_read_padding(padding: &Option<LitInt>) -> TokenStream {
+	match padding {
+		Some(x) => quote! {
+			let mut padding = [0; #x];
+			::std::io::Read::read_exact(reader, &mut padding)?;
+		},
+		None => quote! {},
+	}
+}
@@ -0,0 +1,115 @@
+use proc_macro2::{Ident, Span};
+use quote::quote;
+use syn::{parse_macro_input, Attribute, Data, Error, DeriveInput, Fields, Token, Type};
+use syn::parse::{Parse, ParseStream, Result};
+use syn::punctuated::Punctuated;
+
+/// Argument passed through to every handler method, specified with `#[handler_args(name: Type, ...)]`.
+struct HandlerArg {
+	name: Ident,
+	ty: Type,
+}
+
+impl Parse for HandlerArg {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let name = input.parse()?;
+		input.parse::<Token![:]>()?;
+		let ty = input.parse()?;
+		Ok(Self { name, ty })
+	}
+}
+
+pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+
+	let data = match &input.data {
+		Data::Enum(data) => data,
+		_ => return Error::new_spanned(&input.ident, "MessageHandler can only be derived for enums").to_compile_error().into(),
+	};
+
+	let name = &input.ident;
+	let vis = &input.vis;
+	let trait_name = Ident::new(&format!("{}Handler", name), Span::call_site());
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+	let args = match get_handler_args(&input.attrs) {
+		Ok(x) => x,
+		Err(e) => return e.to_compile_error().into(),
+	};
+	let arg_decls: Vec<_> = args.iter().map(|HandlerArg { name, ty }| quote! { #name: #ty, }).collect();
+	let arg_names: Vec<_> = args.iter().map(|x| &x.name).map(|x| quote! { #x, }).collect();
+
+	let mut methods = vec![];
+	let mut arms = vec![];
+	for v in &data.variants {
+		let ident = &v.ident;
+		let method = Ident::new(&format!("on_{}", to_snake_case(&ident.to_string())), Span::call_site());
+		let doc = format!("Handles [`{}::{}`].", name, ident);
+		let (pat, params, values) = match &v.fields {
+			Fields::Named(fields) => {
+				let idents: Vec<_> = fields.named.iter().map(|f| &f.ident).collect();
+				let tys = fields.named.iter().map(|f| &f.ty);
+				(quote! { { #(#idents),* } }, quote! { #(#idents: &#tys,)* }, quote! { #(#idents,)* })
+			}
+			Fields::Unnamed(fields) => {
+				let idents: Vec<_> = if fields.unnamed.len() == 1 {
+					vec![Ident::new("msg", Span::call_site())]
+				} else {
+					(0..fields.unnamed.len()).map(|i| Ident::new(&format!("msg_{}", i), Span::call_site())).collect()
+				};
+				let tys = fields.unnamed.iter().map(|f| &f.ty);
+				(quote! { ( #(#idents),* ) }, quote! { #(#idents: &#tys,)* }, quote! { #(#idents,)* })
+			}
+			Fields::Unit => (quote! {}, quote! {}, quote! {}),
+		};
+		methods.push(quote! {
+			#[doc = #doc]
+			#[allow(unused_variables)]
+			fn #method(&mut self, #(#arg_decls)* #params) {}
+		});
+		arms.push(quote! {
+			#name::#ident #pat => handler.#method(#(#arg_names)* #values),
+		});
+	}
+
+	let trait_doc = format!("Handler for [`{}`]s, with one method per variant. All methods do nothing by default, so only the relevant ones need to be implemented.", name);
+	(quote! {
+		#[doc = #trait_doc]
+		#vis trait #trait_name #impl_generics #where_clause {
+			#(#methods)*
+		}
+
+		impl #impl_generics #name #ty_generics #where_clause {
+			/// Calls the handler method corresponding to the variant of this message.
+			#vis fn dispatch<__HANDLER: #trait_name #ty_generics + ?Sized>(&self, #(#arg_decls)* handler: &mut __HANDLER) {
+				match self {
+					#(#arms)*
+				}
+			}
+		}
+	}).into()
+}
+
+fn get_handler_args(attrs: &[Attribute]) -> Result<Vec<HandlerArg>> {
+	match attrs.iter().find(|x| x.path.is_ident("handler_args")) {
+		Some(attr) => Ok(attr.parse_args_with(Punctuated::<HandlerArg, Token![,]>::parse_terminated)?.into_iter().collect()),
+		None => Ok(vec![]),
+	}
+}
+
+/// Converts `UIMessageServerToAllClients` to `ui_message_server_to_all_clients`.
+fn to_snake_case(name: &str) -> String {
+	let chars: Vec<_> = name.chars().collect();
+	let mut out = String::new();
+	for (i, c) in chars.iter().enumerate() {
+		if c.is_uppercase() && i != 0 {
+			let prev = chars[i - 1];
+			let next_is_lower = chars.get(i + 1).map_or(false, |x| x.is_lowercase());
+			if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_is_lower) {
+				out.push('_');
+			}
+		}
+		out.extend(c.to_lowercase());
+	}
+	out
+}
@@ -0,0 +1,141 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Index};
+
+pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	gen_reflect(&input).into()
+}
+
+/// Generates the `Reflect` impl, shared with the `GameMessage` and `ReplicaSerde` derives.
+pub(crate) fn gen_reflect(input: &DeriveInput) -> TokenStream {
+	let name = &input.ident;
+	let name_str = name.to_string();
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+	let body = match &input.data {
+		Data::Struct(data) => {
+			let (fields, fields_mut) = gen_struct_fields(&data.fields);
+			quote! {
+				fn fields(&self) -> Vec<(&'static str, &dyn crate::reflect::DynValue)> {
+					#fields
+				}
+
+				fn fields_mut(&mut self) -> Vec<(&'static str, &mut dyn crate::reflect::DynValue)> {
+					#fields_mut
+				}
+			}
+		}
+		Data::Enum(data) => {
+			let mut names = vec![];
+			let mut variant_arms = vec![];
+			let mut set_arms = vec![];
+			let mut field_arms = vec![];
+			let mut field_mut_arms = vec![];
+			for v in &data.variants {
+				let ident = &v.ident;
+				let v_name = ident.to_string();
+				variant_arms.push(quote! { Self::#ident { .. } => #v_name, });
+				if let Fields::Unit = v.fields {
+					set_arms.push(quote! { #v_name => *self = Self::#ident, });
+				}
+				let (pat, refs, muts) = gen_variant_fields(&v.fields);
+				field_arms.push(quote! { Self::#ident #pat => vec![#refs], });
+				field_mut_arms.push(quote! { Self::#ident #pat => vec![#muts], });
+				names.push(v_name);
+			}
+			quote! {
+				fn variant(&self) -> Option<&'static str> {
+					Some(match self {
+						#(#variant_arms)*
+					})
+				}
+
+				fn variants(&self) -> &'static [&'static str] {
+					&[#(#names),*]
+				}
+
+				#[allow(unreachable_code)]
+				fn set_variant(&mut self, variant: &str) -> Result<(), crate::reflect::ReflectError> {
+					match variant {
+						#(#set_arms)*
+						_ => return Err(crate::reflect::ReflectError::NoVariant(variant.into())),
+					}
+					Ok(())
+				}
+
+				fn fields(&self) -> Vec<(&'static str, &dyn crate::reflect::DynValue)> {
+					match self {
+						#(#field_arms)*
+					}
+				}
+
+				fn fields_mut(&mut self) -> Vec<(&'static str, &mut dyn crate::reflect::DynValue)> {
+					match self {
+						#(#field_mut_arms)*
+					}
+				}
+			}
+		}
+		Data::Union(data) => return Error::new_spanned(data.union_token, "Reflect can't be derived for unions").to_compile_error(),
+	};
+
+	quote! {
+		#[cfg(feature = "reflect")]
+		impl #impl_generics crate::reflect::Reflect for #name #ty_generics #where_clause {
+			fn type_name(&self) -> &'static str {
+				#name_str
+			}
+
+			#body
+		}
+	}
+}
+
+fn field_name(ident: &Option<Ident>, i: usize) -> String {
+	match ident {
+		Some(x) => x.to_string(),
+		None => i.to_string(),
+	}
+}
+
+fn gen_struct_fields(fields: &Fields) -> (TokenStream, TokenStream) {
+	let mut refs = vec![];
+	let mut muts = vec![];
+	for (i, f) in fields.iter().enumerate() {
+		let name = field_name(&f.ident, i);
+		let access = match &f.ident {
+			Some(x) => quote! { #x },
+			None => {
+				let index = Index::from(i);
+				quote! { #index }
+			}
+		};
+		refs.push(quote! { (#name, &self.#access as &dyn crate::reflect::DynValue), });
+		muts.push(quote! { (#name, &mut self.#access as &mut dyn crate::reflect::DynValue), });
+	}
+	(quote! { vec![#(#refs)*] }, quote! { vec![#(#muts)*] })
+}
+
+/// Generates a pattern binding the fields of a variant, and the lists of bound fields for `fields` and `fields_mut`. The pattern works for both through default binding modes.
+fn gen_variant_fields(fields: &Fields) -> (TokenStream, TokenStream, TokenStream) {
+	let mut bindings = vec![];
+	let mut refs = vec![];
+	let mut muts = vec![];
+	for (i, f) in fields.iter().enumerate() {
+		let name = field_name(&f.ident, i);
+		let binding = Ident::new(&format!("__{}", i), Span::call_site());
+		refs.push(quote! { (#name, #binding as &dyn crate::reflect::DynValue), });
+		muts.push(quote! { (#name, #binding as &mut dyn crate::reflect::DynValue), });
+		bindings.push(binding);
+	}
+	let pat = match fields {
+		Fields::Named(named) => {
+			let idents = named.named.iter().map(|f| &f.ident);
+			quote! { { #(#idents: #bindings),* } }
+		}
+		Fields::Unnamed(_) => quote! { ( #(#bindings),* ) },
+		Fields::Unit => quote! {},
+	};
+	(pat, quote! { #(#refs)* }, quote! { #(#muts)* })
+}
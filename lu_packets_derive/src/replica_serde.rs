@@ -1,32 +1,55 @@
 use proc_macro2::{Ident, Span, TokenStream};
-use quote::quote;
-use syn::{parse_macro_input, parse_quote, Attribute, Data, DataEnum, DeriveInput, Field, Fields, Generics, Lit, LitInt, Meta, NestedMeta};
+use quote::{quote, ToTokens};
+use syn::{parse_macro_input, parse_quote, Attribute, Data, DataEnum, DeriveInput, Error, Field, Fields, Generics, LitInt, Meta, NestedMeta, Result, Type, Variant};
+
+use crate::arbitrary::gen_arbitrary;
+use crate::attrs::{self, parse_int};
+use crate::builder::gen_builder;
+use crate::reflect::gen_reflect;
+use crate::schema::{self, Encoding};
+use crate::version::{gen_get_version, get_since};
 
 pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 	let input = parse_macro_input!(input as DeriveInput);
+	if let Err(e) = attrs::check(&input, &["padding", "since", "trailing_padding", "pre_disc_padding", "post_disc_padding"]) {
+		return e.to_compile_error().into();
+	}
 	let name = &input.ident;
 	let deser_code;
 	let ser_code;
+	let size_code;
+	let schema_body;
+	let mut builder = quote! {};
 	match &input.data {
 		Data::Struct(data) => {
-			deser_code = gen_deser_code_struct(&data.fields);
+			deser_code = gen_deser_code_struct(&data.fields, name);
 			ser_code = gen_ser_code_struct(&data.fields, &name);
+			size_code = gen_size_code_struct(&data.fields, &name);
+			schema_body = schema::gen_struct(&input, &data.fields, Encoding::Replica);
+			let defaults: Vec<_> = data.fields.iter().map(|f| if is_option(&f.ty) { Some(quote! { None }) } else { None }).collect();
+			builder = gen_builder(&input, &data.fields, &defaults);
 		},
 		Data::Enum(data) => {
-			let ty = get_enum_type(&input);
+			let ty = match get_enum_type(&input) {
+				Ok(x) => x,
+				Err(e) => return e.to_compile_error().into(),
+			};
 			let pre_disc_padding = get_pre_disc_padding(&input);
 			let post_disc_padding = get_post_disc_padding(&input);
 			deser_code = gen_deser_code_enum(data, &name, &ty, &pre_disc_padding, &post_disc_padding);
 			ser_code = gen_ser_code_enum(data, &name, &ty, &pre_disc_padding, &post_disc_padding, &input.generics);
+			size_code = gen_size_code_enum(data, &name, &ty, &pre_disc_padding, &post_disc_padding);
+			schema_body = schema::gen_enum(&input, data, &ty, Encoding::Replica);
 		}
-		Data::Union(_) => unimplemented!(),
+		Data::Union(data) => return Error::new_spanned(data.union_token, "ReplicaSerde can't be derived for unions").to_compile_error().into(),
 	}
 
 	let trailing_padding = get_trailing_padding(&input);
 	let read_padding = gen_read_padding(&trailing_padding);
 	let write_padding = gen_write_padding(&trailing_padding);
+	let size_padding = gen_size_padding(&trailing_padding);
 
-	let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
 	let des_impl_generics = &mut input.generics.clone();
 	des_impl_generics.params.push(parse_quote!(__READER: ::std::io::Read));
@@ -37,9 +60,27 @@ pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 	ser_impl_generics.params.push(parse_quote!(__WRITER: ::std::io::Write));
 	let (ser_impl_generics, _, _) = ser_impl_generics.split_for_impl();
 
+	let uses_since = match &input.data {
+		Data::Struct(data) => data.fields.iter().any(|f| get_since(&f.attrs).is_some()),
+		Data::Enum(data) => data.variants.iter().any(|v| get_since(&v.attrs).is_some() || v.fields.iter().any(|f| get_since(&f.attrs).is_some())),
+		Data::Union(_) => unreachable!(),
+	};
+	let get_reader_version = gen_get_version(uses_since, quote! { reader });
+	let get_writer_version = gen_get_version(uses_since, quote! { writer });
+	let live_version = if uses_since {
+		quote! { let __version = crate::common::NETWORK_VERSION_LIVE; }
+	} else {
+		quote! {}
+	};
+
+	let schema_impl = schema::gen_impl(&input, schema_body);
+	let reflect_impl = gen_reflect(&input);
+	let arbitrary_impl = gen_arbitrary(&input);
+
 	(quote! {
 		impl #des_impl_generics ::endio::Deserialize<::endio::LE, ::endio_bit::BEBitReader<__READER>> for #name #ty_generics #where_clause {
 			fn deserialize(reader: &mut ::endio_bit::BEBitReader<__READER>) -> ::std::io::Result<Self> {
+				#get_reader_version
 				#deser_code
 				#read_padding
 				Ok(ret)
@@ -48,11 +89,30 @@ pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 
 		impl #ser_impl_generics ::endio::Serialize<::endio::LE, ::endio_bit::BEBitWriter<__WRITER>> for &'__LIFETIME #name #ty_generics #where_clause {
 			fn serialize(self, writer: &mut ::endio_bit::BEBitWriter<__WRITER>) -> ::std::io::Result<()> {
+				#get_writer_version
 				#ser_code
 				#write_padding
 				Ok(())
 			}
 		}
+
+		impl #impl_generics crate::common::BitSize for #name #ty_generics #where_clause {
+			fn bit_size(&self) -> ::std::io::Result<usize> {
+				#live_version
+				let mut __size = 0;
+				#size_code
+				#size_padding
+				Ok(__size)
+			}
+		}
+
+		#schema_impl
+
+		#reflect_impl
+
+		#arbitrary_impl
+
+		#builder
 	}).into()
 }
 
@@ -64,22 +124,18 @@ fn gen_deser_code_fields(fields: &Fields) -> TokenStream {
 				let ident = &f.ident;
 				let padding = get_field_padding(f);
 				let read_padding = gen_read_padding(&padding);
-				deser.push(quote! { #ident: {
-					#read_padding
-					crate::raknet::client::replica::ReplicaD::deserialize(reader)?
-				}, });
+				let read = gen_read_field(f, &read_padding, &ident.as_ref().unwrap().to_string());
+				deser.push(quote! { #ident: #read, });
 			}
 			quote! { { #(#deser)* } }
 		}
 		Fields::Unnamed(fields) => {
 			let mut deser = vec![];
-			for f in &fields.unnamed {
+			for (i, f) in fields.unnamed.iter().enumerate() {
 				let padding = get_field_padding(f);
 				let read_padding = gen_read_padding(&padding);
-				deser.push(quote! { {
-					#read_padding
-					crate::raknet::client::replica::ReplicaD::deserialize(reader)?
-				}, });
+				let read = gen_read_field(f, &read_padding, &i.to_string());
+				deser.push(quote! { #read, });
 			}
 			quote! { ( #(#deser)* ) }
 		}
@@ -89,9 +145,37 @@ fn gen_deser_code_fields(fields: &Fields) -> TokenStream {
 	}
 }
 
-fn gen_deser_code_struct(fields: &Fields) -> TokenStream {
+/// Reads a field named `name`, or uses its default if it's `#[since]` a later network version.
+fn gen_read_field(f: &Field, read_padding: &TokenStream, name: &str) -> TokenStream {
+	let read = quote! { {
+		#read_padding
+		crate::raknet::client::replica::ReplicaD::deserialize(reader)?
+	} };
+	let read = match get_since(&f.attrs) {
+		None => read,
+		Some(since) => quote! {
+			if __version < #since {
+				::std::default::Default::default()
+			} else #read
+		},
+	};
+	quote! { {
+		#[cfg(feature = "trace")]
+		let __trace = crate::trace::enter_field(#name);
+		let value = #read;
+		#[cfg(feature = "trace")]
+		__trace.exit(&value);
+		value
+	} }
+}
+
+fn gen_deser_code_struct(fields: &Fields, name: &Ident) -> TokenStream {
 	let deser_code = gen_deser_code_fields(fields);
-	quote! { let ret = Self #deser_code; }
+	quote! {
+		#[cfg(feature = "trace")]
+		let __trace_type = crate::trace::enter_type(stringify!(#name));
+		let ret = Self #deser_code;
+	}
 }
 
 fn gen_deser_code_enum(data: &DataEnum, name: &Ident, ty: &Ident, pre_disc_padding: &Option<LitInt>, post_disc_padding: &Option<LitInt>) -> TokenStream {
@@ -106,7 +190,13 @@ fn gen_deser_code_enum(data: &DataEnum, name: &Ident, ty: &Ident, pre_disc_paddi
 			disc_offset = 0;
 		}
 		let deser_fields = gen_deser_code_fields(&f.fields);
-		let arm = quote! { disc if disc == (#last_disc + (#disc_offset as #ty)) => Self::#ident #deser_fields, };
+		let check_version = gen_check_variant_version(f);
+		let arm = quote! { disc if disc == (#last_disc + (#disc_offset as #ty)) => {
+			#check_version
+			#[cfg(feature = "trace")]
+			let __trace_variant = crate::trace::enter_variant(stringify!(#ident));
+			Self::#ident #deser_fields
+		} };
 		disc_offset += 1;
 		arms.push(arm);
 	}
@@ -133,39 +223,61 @@ fn gen_read_padding(padding: &Option<LitInt>) -> TokenStream {
 	}
 }
 
-fn gen_ser_code_fields(fields: &Fields) -> TokenStream {
+/// Writes a field bound to `ident`, unless it's `#[since]` a later network version.
+fn gen_write_field(f: &Field, ident: &impl ToTokens, write_padding: &TokenStream) -> TokenStream {
+	let write = quote! {
+		#write_padding
+		crate::raknet::client::replica::ReplicaS::serialize(#ident, writer)?;
+	};
+	match get_since(&f.attrs) {
+		None => write,
+		Some(since) => quote! {
+			if __version >= #since {
+				#write
+			}
+		},
+	}
+}
+
+/// Fails if the variant is `#[since]` a later network version.
+pub(crate) fn gen_check_variant_version(v: &Variant) -> TokenStream {
+	match get_since(&v.attrs) {
+		None => quote! {},
+		Some(since) => {
+			let msg = format!("{} is not available in network version {{}}", v.ident);
+			quote! {
+				if __version < #since {
+					return ::std::result::Result::Err(::std::io::Error::new(::std::io::ErrorKind::InvalidData, format!(#msg, __version)));
+				}
+			}
+		}
+	}
+}
+
+/// Generates a match arm for the fields, with `gen_field` generating the code for each field from its binding.
+fn gen_fields_arm(fields: &Fields, gen_field: impl Fn(&Field, &Ident) -> TokenStream) -> TokenStream {
 	match fields {
 		Fields::Named(fields) => {
 			let mut pat = vec![];
-			let mut ser = vec![];
+			let mut code = vec![];
 			for f in &fields.named {
-				let ident = &f.ident;
-				let padding = get_field_padding(f);
-				let write_padding = gen_write_padding(&padding);
+				let ident = f.ident.as_ref().unwrap();
 				pat.push(quote! { #ident, });
-				ser.push(quote! {
-					#write_padding
-					crate::raknet::client::replica::ReplicaS::serialize(#ident, writer)?;
-				});
+				code.push(gen_field(f, ident));
 			}
-			quote! { { #(#pat)* } => { #(#ser)* } }
+			quote! { { #(#pat)* } => { #(#code)* } }
 		}
 		Fields::Unnamed(fields) => {
 			let mut index = String::from("a");
 			let mut pat = vec![];
-			let mut ser = vec![];
+			let mut code = vec![];
 			for f in &fields.unnamed {
 				let ident = Ident::new(&index, Span::call_site());
-				let padding = get_field_padding(f);
-				let write_padding = gen_write_padding(&padding);
 				pat.push(quote! { #ident, });
-				ser.push(quote! {
-					#write_padding
-					crate::raknet::client::replica::ReplicaS::serialize(#ident, writer)?;
-				});
+				code.push(gen_field(f, &ident));
 				index += "a";
 			}
-			quote! { ( #(#pat)* ) => { #(#ser)* } }
+			quote! { ( #(#pat)* ) => { #(#code)* } }
 		}
 		Fields::Unit => {
 			quote! { => {} }
@@ -173,6 +285,65 @@ fn gen_ser_code_fields(fields: &Fields) -> TokenStream {
 	}
 }
 
+fn gen_ser_code_fields(fields: &Fields) -> TokenStream {
+	gen_fields_arm(fields, |f, ident| {
+		let write_padding = gen_write_padding(&get_field_padding(f));
+		gen_write_field(f, ident, &write_padding)
+	})
+}
+
+/// Adds the bits of a field bound to `ident` to `__size`, unless it's `#[since]` a later network version.
+fn gen_size_field(f: &Field, ident: &Ident) -> TokenStream {
+	let padding = gen_size_padding(&get_field_padding(f));
+	let size = quote! {
+		#padding
+		__size += crate::common::BitSize::bit_size(#ident)?;
+	};
+	match get_since(&f.attrs) {
+		None => size,
+		Some(since) => quote! {
+			if __version >= #since {
+				#size
+			}
+		},
+	}
+}
+
+fn gen_size_padding(padding: &Option<LitInt>) -> TokenStream {
+	match padding {
+		Some(x) => quote! { __size += #x * 8; },
+		None => quote! { },
+	}
+}
+
+fn gen_size_code_struct(fields: &Fields, name: &Ident) -> TokenStream {
+	let size_code = gen_fields_arm(fields, gen_size_field);
+	quote! {
+		match self {
+			#name #size_code
+		}
+	}
+}
+
+fn gen_size_code_enum(data: &DataEnum, name: &Ident, ty: &Ident, pre_disc_padding: &Option<LitInt>, post_disc_padding: &Option<LitInt>) -> TokenStream {
+	let mut arms = vec![];
+	for f in &data.variants {
+		let ident = &f.ident;
+		let size_fields = gen_fields_arm(&f.fields, gen_size_field);
+		arms.push(quote! { #name::#ident #size_fields });
+	}
+	let pre_padding = gen_size_padding(pre_disc_padding);
+	let post_padding = gen_size_padding(post_disc_padding);
+	quote! {
+		#pre_padding
+		__size += ::std::mem::size_of::<#ty>() * 8;
+		#post_padding
+		match self {
+			#(#arms)*
+		}
+	}
+}
+
 fn gen_ser_code_struct(fields: &Fields, name: &Ident) -> TokenStream {
 	let ser_code = gen_ser_code_fields(fields);
 	quote! {
@@ -184,15 +355,33 @@ fn gen_ser_code_struct(fields: &Fields, name: &Ident) -> TokenStream {
 
 fn gen_ser_code_enum(data: &DataEnum, name: &Ident, ty: &Ident, pre_disc_padding: &Option<LitInt>, post_disc_padding: &Option<LitInt>, generics: &Generics) -> TokenStream {
 	let mut arms = vec![];
+	let mut check_arms = vec![];
 	for f in &data.variants {
 		let ident = &f.ident;
 		let ser_fields = gen_ser_code_fields(&f.fields);
 		let expanded = quote! { #name::#ident #ser_fields };
 		arms.push(expanded);
+		if get_since(&f.attrs).is_some() {
+			let check_version = gen_check_variant_version(f);
+			check_arms.push(quote! { #name::#ident { .. } => { #check_version } });
+		}
 	}
+	// checked before anything is written
+	let check_version = if check_arms.is_empty() {
+		quote! {}
+	} else {
+		quote! {
+			#[allow(unreachable_patterns)]
+			match self {
+				#(#check_arms)*
+				_ => {}
+			}
+		}
+	};
 	let write_pre_padding = gen_write_padding(pre_disc_padding);
 	let write_post_padding = gen_write_padding(post_disc_padding);
 	quote! {
+		#check_version
 		#write_pre_padding
 		let disc = unsafe { *(self as *const #name #generics as *const #ty) };
 		::endio::LEWrite::write(writer, disc)?;
@@ -213,23 +402,27 @@ fn gen_write_padding(padding: &Option<LitInt>) -> TokenStream {
 	}
 }
 
-fn get_enum_type(input: &DeriveInput) -> Ident {
+fn is_option(ty: &Type) -> bool {
+	match ty {
+		Type::Path(path) => path.path.segments.last().map_or(false, |x| x.ident == "Option"),
+		_ => false,
+	}
+}
+
+/// Returns the discriminant type from the `repr` attribute, which is required for enums.
+pub(crate) fn get_enum_type(input: &DeriveInput) -> Result<Ident> {
 	for attr in &input.attrs {
 		if !attr.path.is_ident("repr") {
 			continue;
 		}
-		let meta = match attr.parse_meta() {
-			Err(_) => panic!("encountered unparseable repr attribute"),
-			Ok(x) => x,
-		};
-		let list = match meta {
+		let list = match attr.parse_meta()? {
 			Meta::List(x) => x,
 			_ => continue,
 		};
 		if list.nested.is_empty() {
-			panic!("encountered repr attribute with no arguments");
+			return Err(Error::new_spanned(attr, "encountered repr attribute with no arguments"));
 		}
-		for nested_meta in list.nested {
+		for nested_meta in &list.nested {
 			let meta = match nested_meta {
 				NestedMeta::Meta(x) => x,
 				NestedMeta::Lit(_) => continue,
@@ -241,32 +434,18 @@ fn get_enum_type(input: &DeriveInput) -> Ident {
 			if path.is_ident("C") || path.is_ident("transparent") {
 				continue;
 			}
-			return (*path.get_ident().expect("invalid repr attribute argument")).clone();
+			return match path.get_ident() {
+				Some(x) => Ok(x.clone()),
+				None => Err(Error::new_spanned(path, "invalid repr attribute argument")),
+			};
 		}
 	}
-	panic!("You need to add a repr attribute to specify the discriminant type, e.g. #[repr(u16)]");
+	Err(Error::new_spanned(&input.ident, "You need to add a repr attribute to specify the discriminant type, e.g. #[repr(u16)]"))
 }
 
-fn get_padding(attrs: &Vec<Attribute>, attr_name: &str) -> Option<LitInt> {
-	for attr in attrs {
-		if !attr.path.is_ident(attr_name) {
-			continue;
-		}
-		let meta = match attr.parse_meta() {
-			Err(_) => panic!("encountered unparseable {} attribute", attr_name),
-			Ok(x) => x,
-		};
-		let lit = match meta {
-			Meta::NameValue(x) => x.lit,
-			_ => panic!("{} needs to be name=value", attr_name),
-		};
-		let int_lit = match lit {
-			Lit::Int(x) => x,
-			_ => panic!("{} needs to be an integer", attr_name),
-		};
-		return Some(int_lit);
-	}
-	None
+/// Parses `#[attr_name = bytes]`. Malformed attributes are reported by [`crate::attrs::check`] before this is called.
+pub(crate) fn get_padding(attrs: &Vec<Attribute>, attr_name: &str) -> Option<LitInt> {
+	attrs.iter().find(|x| x.path.is_ident(attr_name)).and_then(|x| parse_int(x, attr_name).ok())
 }
 
 fn get_field_padding(input: &Field) -> Option<LitInt> {
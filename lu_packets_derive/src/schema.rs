@@ -0,0 +1,161 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{parse_macro_input, Data, DataEnum, DeriveInput, Error, Fields, GenericArgument, LitInt, PathArguments, Type};
+
+use crate::attrs;
+use crate::game_message::get_gm_default;
+use crate::replica_serde::{get_enum_type, get_padding};
+
+pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	if let Err(e) = attrs::check(&input, &["padding", "trailing_padding", "pre_disc_padding", "post_disc_padding"]) {
+		return e.to_compile_error().into();
+	}
+
+	let body = match &input.data {
+		Data::Struct(data) => gen_struct(&input, &data.fields, Encoding::Plain),
+		Data::Enum(data) => match get_enum_type(&input) {
+			Ok(ty) => gen_enum(&input, data, &ty, Encoding::Plain),
+			Err(e) => return e.to_compile_error().into(),
+		},
+		Data::Union(data) => return Error::new_spanned(data.union_token, "Schema can't be derived for unions").to_compile_error().into(),
+	};
+	gen_impl(&input, body).into()
+}
+
+/// How the fields of a type are encoded, which the derives generating schemas differ in.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Encoding {
+	Plain,
+	GameMessage,
+	/// Bit level throughout, with `Option`s as a flag bit followed by the value.
+	Replica,
+}
+
+/// Generates the schema of a struct, shared with the `GameMessage` and `ReplicaSerde` derives.
+pub(crate) fn gen_struct(input: &DeriveInput, fields: &Fields, encoding: Encoding) -> TokenStream {
+	let name = input.ident.to_string();
+	let fields = gen_fields(fields, encoding);
+	let game_message = encoding == Encoding::GameMessage;
+	let replica = encoding == Encoding::Replica;
+	let trailing_padding = gen_padding(get_padding(&input.attrs, "trailing_padding"));
+	quote! {
+		crate::schema::Type::Struct(crate::schema::Struct {
+			id: ::std::any::type_name::<Self>(),
+			name: #name,
+			fields: #fields,
+			trailing_padding: #trailing_padding,
+			game_message: #game_message,
+			replica: #replica,
+		})
+	}
+}
+
+/// Generates the schema of an enum with discriminant type `ty`, shared with the `GameMessage` and `ReplicaSerde` derives.
+pub(crate) fn gen_enum(input: &DeriveInput, data: &DataEnum, ty: &Ident, encoding: Encoding) -> TokenStream {
+	let name = input.ident.to_string();
+	let game_message = encoding == Encoding::GameMessage;
+	let replica = encoding == Encoding::Replica;
+	let pre_disc_padding = gen_padding(get_padding(&input.attrs, "pre_disc_padding"));
+	let post_disc_padding = gen_padding(get_padding(&input.attrs, "post_disc_padding"));
+	let mut variants = vec![];
+	for v in &data.variants {
+		let set_disc = match &v.discriminant {
+			Some((_, x)) => quote! { disc = (#x) as u64; },
+			None => quote! { },
+		};
+		let v_name = v.ident.to_string();
+		let fields = gen_fields(&v.fields, encoding);
+		variants.push(quote! {
+			#set_disc
+			variants.push(crate::schema::Variant { name: #v_name, disc, fields: #fields });
+			disc = disc.wrapping_add(1);
+		});
+	}
+	quote! {
+		let mut variants = vec![];
+		let mut disc: u64 = 0;
+		#(#variants)*
+		crate::schema::Type::Enum(crate::schema::Enum {
+			id: ::std::any::type_name::<Self>(),
+			name: #name,
+			disc: Box::new(<#ty as crate::schema::Schema>::schema()),
+			pre_disc_padding: #pre_disc_padding,
+			post_disc_padding: #post_disc_padding,
+			variants,
+			game_message: #game_message,
+			replica: #replica,
+		})
+	}
+}
+
+pub(crate) fn gen_impl(input: &DeriveInput, body: TokenStream) -> TokenStream {
+	let name = &input.ident;
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+	quote! {
+		#[cfg(feature = "schema")]
+		impl #impl_generics crate::schema::Schema for #name #ty_generics #where_clause {
+			#[allow(unused_assignments, unused_mut)]
+			fn schema() -> crate::schema::Type {
+				#body
+			}
+		}
+	}
+}
+
+fn gen_fields(fields: &Fields, encoding: Encoding) -> TokenStream {
+	let mut schemas = vec![];
+	for (i, f) in fields.iter().enumerate() {
+		let name = match &f.ident {
+			Some(x) => x.to_string(),
+			None => i.to_string(),
+		};
+		let mut ty = &f.ty;
+		let padding = gen_padding(get_padding(&f.attrs, "padding"));
+		let default = match encoding {
+			Encoding::Plain => false,
+			Encoding::GameMessage => get_gm_default(f).is_some(),
+			Encoding::Replica => match option_inner(ty) {
+				Some(inner) => {
+					ty = inner;
+					true
+				}
+				None => false,
+			},
+		};
+		schemas.push(quote! {
+			crate::schema::Field {
+				name: #name,
+				ty: <#ty as crate::schema::Schema>::schema(),
+				padding: #padding,
+				default: #default,
+			},
+		});
+	}
+	quote! { vec![#(#schemas)*] }
+}
+
+/// The `T` of an `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+	let segment = match ty {
+		Type::Path(path) => path.path.segments.last()?,
+		_ => return None,
+	};
+	if segment.ident != "Option" {
+		return None;
+	}
+	match &segment.arguments {
+		PathArguments::AngleBracketed(args) => match args.args.first()? {
+			GenericArgument::Type(x) => Some(x),
+			_ => None,
+		},
+		_ => None,
+	}
+}
+
+fn gen_padding(padding: Option<LitInt>) -> TokenStream {
+	match padding {
+		Some(x) => quote! { #x },
+		None => quote! { 0 },
+	}
+}
@@ -1,13 +1,18 @@
 use proc_macro2::{Span, TokenStream};
-use syn::{parse_macro_input, Attribute, token::Comma, Data, DeriveInput, Ident, Meta, NestedMeta, punctuated::Punctuated};
+use syn::{parse_macro_input, Attribute, token::Comma, Data, DeriveInput, Error, Ident, NestedMeta, punctuated::Punctuated, Result};
 use quote::quote;
 
+use crate::attrs::parse_list;
+
 // todo: only run this when generating tests
 pub fn derive(input: proc_macro::TokenStream, reader_code: TokenStream, writer_code: TokenStream) -> proc_macro::TokenStream {
 	let input = parse_macro_input!(input as DeriveInput);
 
 	let name = &input.ident;
-	let test_params = get_test_params(&input.attrs);
+	let test_params = match get_test_params(&input.attrs) {
+		Ok(x) => x,
+		Err(e) => return e.to_compile_error().into(),
+	};
 
 	let mut tests = vec![];
 
@@ -23,7 +28,7 @@ pub fn derive(input: proc_macro::TokenStream, reader_code: TokenStream, writer_c
 				tests.push(test);
 			}
 		}
-		Data::Union(_) => unimplemented!(),
+		Data::Union(data) => return Error::new_spanned(data.union_token, "variant tests can't be derived for unions").to_compile_error().into(),
 	};
 
 	let mod_name = Ident::new(&format!("_{}", name), Span::call_site());
@@ -36,22 +41,11 @@ pub fn derive(input: proc_macro::TokenStream, reader_code: TokenStream, writer_c
 	}).into()
 }
 
-fn get_test_params(attrs: &Vec<Attribute>) -> Option<Punctuated<NestedMeta, Comma>> {
-	for attr in attrs {
-		if !attr.path.is_ident("test_params") {
-			continue;
-		}
-		let meta = match attr.parse_meta() {
-			Err(_) => panic!("encountered unparseable test_params attribute"),
-			Ok(x) => x,
-		};
-		let list = match meta {
-			Meta::List(x) => x.nested,
-			_ => panic!("test_params needs to be list"),
-		};
-		return Some(list);
+fn get_test_params(attrs: &Vec<Attribute>) -> Result<Option<Punctuated<NestedMeta, Comma>>> {
+	match attrs.iter().find(|x| x.path.is_ident("test_params")) {
+		Some(attr) => parse_list(attr, "test_params").map(Some),
+		None => Ok(None),
 	}
-	None
 }
 
 fn gen_test_case(type_name: &Ident, test_params: &Option<Punctuated<NestedMeta, Comma>>, test_name: &Ident, reader_code: &TokenStream, writer_code: &TokenStream) -> TokenStream {
@@ -0,0 +1,21 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, NestedMeta};
+
+use crate::attrs::parse_single_arg;
+
+/// Parses `#[since(version)]`, the first network version a field or variant is encoded in.
+///
+/// Malformed attributes are reported by [`crate::attrs::check`] before this is called.
+pub(crate) fn get_since(attrs: &[Attribute]) -> Option<NestedMeta> {
+	attrs.iter().find(|x| x.path.is_ident("since")).and_then(|x| parse_single_arg(x, "since").ok())
+}
+
+/// Binds the network version of `reader_or_writer` to `__version`, if it's needed.
+pub(crate) fn gen_get_version(needed: bool, reader_or_writer: TokenStream) -> TokenStream {
+	if needed {
+		quote! { let __version = crate::common::NetworkVersion::network_version(&*#reader_or_writer); }
+	} else {
+		quote! {}
+	}
+}
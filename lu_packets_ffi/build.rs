@@ -0,0 +1,11 @@
+use std::env;
+use std::path::PathBuf;
+
+fn main() {
+	println!("cargo:rerun-if-changed=src");
+	println!("cargo:rerun-if-changed=cbindgen.toml");
+	let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+	// the workspace's target directory
+	let target_dir = env::var("CARGO_TARGET_DIR").map(PathBuf::from).unwrap_or_else(|_| PathBuf::from(&crate_dir).join("../target"));
+	cbindgen::generate(&crate_dir).expect("unable to generate C header").write_to_file(target_dir.join("lu_packets.h"));
+}
@@ -0,0 +1,170 @@
+/*!
+	JSON rendering of [`Value`]s.
+
+	Structs become objects of their fields. Enum variants become objects with the variant name as the single key, like `{"ClientValidation": {...}}`, and variants without fields become strings. Tuple structs and variants with a single field are unwrapped to that field. Values of types without reflection support become `null`.
+*/
+use std::borrow::Borrow;
+use std::fmt::Write;
+
+use lu_packets::reflect::Value;
+use lu_packets::world::LnvValue;
+use lu_packets::world::amf3::Amf3;
+
+pub fn to_json(value: &Value) -> String {
+	let mut out = String::new();
+	write_value(&mut out, value);
+	out
+}
+
+fn write_value(out: &mut String, value: &Value) {
+	match value {
+		Value::Bool(x) => write_display(out, x),
+		Value::U8(x) => write_display(out, x),
+		Value::U16(x) => write_display(out, x),
+		Value::U32(x) => write_display(out, x),
+		Value::U64(x) => write_display(out, x),
+		Value::I8(x) => write_display(out, x),
+		Value::I16(x) => write_display(out, x),
+		Value::I32(x) => write_display(out, x),
+		Value::I64(x) => write_display(out, x),
+		Value::F32(x) => write_float(out, *x as f64),
+		Value::F64(x) => write_float(out, *x),
+		Value::String(x) => write_str(out, x),
+		Value::Bytes(x) => write_list(out, x, |out, x| write_display(out, x)),
+		Value::Vector3(x) => write_object(out, &[("x", x.x), ("y", x.y), ("z", x.z)], |out, x| write_float(out, *x as f64)),
+		Value::Quaternion(x) => write_object(out, &[("x", x.x), ("y", x.y), ("z", x.z), ("w", x.w)], |out, x| write_float(out, *x as f64)),
+		Value::LuNameValue(x) => {
+			let mut entries: Vec<_> = x.iter().map(|(k, v)| (String::from(k), v)).collect();
+			// the map is unordered, sort for stable output
+			entries.sort_by(|a, b| a.0.cmp(&b.0));
+			write_object(out, &entries, |out, v| write_lnv(out, v));
+		}
+		Value::Amf3(x) => write_amf3(out, x),
+		Value::Option(None) => out.push_str("null"),
+		Value::Option(Some(x)) => write_value(out, x),
+		Value::List(x) => write_list(out, x, write_value),
+		Value::Struct { variant, fields, .. } => {
+			let write_fields = |out: &mut String| match &fields[..] {
+				[("0", x)] => write_value(out, x),
+				_ => write_object(out, fields, write_value),
+			};
+			match variant {
+				None => write_fields(out),
+				Some(variant) if fields.is_empty() => write_str(out, variant),
+				Some(variant) => {
+					out.push('{');
+					write_str(out, variant);
+					out.push(':');
+					write_fields(out);
+					out.push('}');
+				}
+			}
+		}
+		Value::Unsupported(_) => out.push_str("null"),
+	}
+}
+
+fn write_lnv(out: &mut String, value: &LnvValue) {
+	match value {
+		LnvValue::WString(x) => write_str(out, &String::from(x)),
+		LnvValue::I32(x) => write_display(out, x),
+		LnvValue::F32(x) => write_float(out, *x as f64),
+		LnvValue::F64(x) => write_float(out, *x),
+		LnvValue::U32(x) => write_display(out, x),
+		LnvValue::Bool(x) => write_display(out, x),
+		LnvValue::I64(x) => write_display(out, x),
+		LnvValue::U64(x) => write_display(out, x),
+		LnvValue::String(x) => write_str(out, &String::from(x)),
+	}
+}
+
+fn write_amf3(out: &mut String, value: &Amf3) {
+	match value {
+		Amf3::False => out.push_str("false"),
+		Amf3::True => out.push_str("true"),
+		Amf3::Double(x) => write_float(out, *x),
+		Amf3::String(x) => write_str(out, x.borrow()),
+		Amf3::Array(x) if x.map.is_empty() => write_list(out, &x.vec, write_amf3),
+		Amf3::Array(x) => {
+			let mut entries: Vec<(&str, &Amf3)> = x.map.iter().map(|(k, v)| (k.borrow(), v)).collect();
+			entries.sort_by(|a, b| a.0.cmp(b.0));
+			// the dense part of the array, keyed by index like in AMF3 itself
+			let indices: Vec<_> = (0..x.vec.len()).map(|i| i.to_string()).collect();
+			entries.extend(indices.iter().map(|i| &i[..]).zip(&x.vec));
+			write_object(out, &entries, |out, v| write_amf3(out, v));
+		}
+	}
+}
+
+fn write_list<T>(out: &mut String, list: &[T], write: impl Fn(&mut String, &T)) {
+	out.push('[');
+	for (i, x) in list.iter().enumerate() {
+		if i != 0 {
+			out.push(',');
+		}
+		write(out, x);
+	}
+	out.push(']');
+}
+
+fn write_object<K: AsRef<str>, V>(out: &mut String, entries: &[(K, V)], write: impl Fn(&mut String, &V)) {
+	out.push('{');
+	for (i, (k, v)) in entries.iter().enumerate() {
+		if i != 0 {
+			out.push(',');
+		}
+		write_str(out, k.as_ref());
+		out.push(':');
+		write(out, v);
+	}
+	out.push('}');
+}
+
+fn write_display(out: &mut String, x: impl std::fmt::Display) {
+	write!(out, "{}", x).unwrap();
+}
+
+/// JSON has no NaN or infinity, these become `null`.
+fn write_float(out: &mut String, x: f64) {
+	if x.is_finite() {
+		write_display(out, x);
+	} else {
+		out.push_str("null");
+	}
+}
+
+fn write_str(out: &mut String, string: &str) {
+	out.push('"');
+	for c in string.chars() {
+		match c {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\n' => out.push_str("\\n"),
+			'\r' => out.push_str("\\r"),
+			'\t' => out.push_str("\\t"),
+			c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn escapes() {
+		assert_eq!(to_json(&Value::String("a\"b\\c\n\u{1}".into())), r#""a\"b\\c\n\u0001""#);
+	}
+
+	#[test]
+	fn variants() {
+		let unit = Value::Struct { name: "E", variant: Some("Unit"), fields: vec![] };
+		assert_eq!(to_json(&unit), r#""Unit""#);
+		let newtype = Value::Struct { name: "E", variant: Some("New"), fields: vec![("0", Value::U8(1))] };
+		assert_eq!(to_json(&newtype), r#"{"New":1}"#);
+		let named = Value::Struct { name: "S", variant: None, fields: vec![("a", Value::Option(None)), ("b", Value::F32(f32::NAN))] };
+		assert_eq!(to_json(&named), r#"{"a":null,"b":null}"#);
+	}
+}
@@ -0,0 +1,471 @@
+/*!
+	C ABI for decoding and encoding messages with `lu_packets`.
+
+	This crate is built as a `cdylib`, and the build script generates the C header `lu_packets.h` in the target directory.
+
+	Decoding is supported for all messages received by world servers ([`world::server::Message`](lu_packets::world::server::Message)), which are returned as an opaque [`LuServerMessage`] handle. Key fields can be read with the `lu_server_message_*` accessors, and the full message can be rendered as JSON with [`lu_server_message_json`], or as the Rust `Debug` representation with [`lu_server_message_debug`].
+
+	Encoding is supported for some of the messages sent by world servers, which are returned as complete, ready-to-send [`LuBuffer`]s.
+
+	### Memory management
+
+	Everything returned by this library is owned by the caller and has to be freed using the matching `_free` function. Pointers passed into this library are only borrowed for the duration of the call.
+*/
+use std::convert::TryInto;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::ptr;
+use std::slice;
+
+use endio::{LERead, LEWrite};
+
+use lu_packets::common::{LuWString33, ObjId, OBJID_EMPTY};
+use lu_packets::general::client::DisconnectNotify;
+use lu_packets::raknet::server::Message as RaknetMessage;
+use lu_packets::reflect::ReflectValue;
+use lu_packets::world::{Lot, Vector3, ZoneId};
+use lu_packets::world::client::{CharListChar, CharacterListResponse, InstanceType, LoadStaticZone, Message as ClientMessage};
+use lu_packets::world::server::{LuMessage, Message as ServerMessage, WorldMessage};
+
+mod json;
+
+/// Opaque handle to a decoded world-server-received message.
+pub struct LuServerMessage(ServerMessage);
+
+/**
+	A byte buffer allocated by this library.
+
+	A failed operation is signalled by a null [`data`](Self::data) pointer. Free the buffer with [`lu_buffer_free`].
+*/
+#[repr(C)]
+pub struct LuBuffer {
+	pub data: *mut u8,
+	pub len: usize,
+}
+
+impl LuBuffer {
+	const NULL: Self = Self { data: ptr::null_mut(), len: 0 };
+
+	fn from_message(msg: &ClientMessage) -> Self {
+		let mut vec = vec![];
+		if LEWrite::write(&mut vec, msg).is_err() {
+			return Self::NULL;
+		}
+		let boxed = vec.into_boxed_slice();
+		let len = boxed.len();
+		Self { data: Box::into_raw(boxed) as *mut u8, len }
+	}
+}
+
+/// A character of [`lu_character_list_response`], mirroring [`CharListChar`].
+#[repr(C)]
+pub struct LuCharListChar {
+	pub obj_id: ObjId,
+	/// Null-terminated UTF-8.
+	pub char_name: *const c_char,
+	/// Null-terminated UTF-8.
+	pub pending_name: *const c_char,
+	pub requires_rename: bool,
+	pub is_free_trial: bool,
+	pub torso_color: u32,
+	pub legs_color: u32,
+	pub hair_style: u32,
+	pub hair_color: u32,
+	pub eyebrows_style: u32,
+	pub eyes_style: u32,
+	pub mouth_style: u32,
+	pub last_map_id: u16,
+	pub last_instance_id: u16,
+	pub last_clone_id: u32,
+	pub equipped_items: *const Lot,
+	pub equipped_items_len: usize,
+}
+
+/// Reads the discriminant of a `#[repr(uN)]` enum.
+fn discriminant<T, D: Copy>(val: &T) -> D {
+	unsafe { *(val as *const T as *const D) }
+}
+
+/// Copies `string` null-terminated into `buf`, truncating at a character boundary if necessary, and returns the full length without terminator.
+unsafe fn copy_str(string: &str, buf: *mut c_char, buf_len: usize) -> usize {
+	if !buf.is_null() && buf_len > 0 {
+		let mut len = string.len().min(buf_len - 1);
+		while !string.is_char_boundary(len) {
+			len -= 1;
+		}
+		ptr::copy_nonoverlapping(string.as_ptr() as *const c_char, buf, len);
+		*buf.add(len) = 0;
+	}
+	string.len()
+}
+
+unsafe fn wstr33(string: *const c_char) -> Option<LuWString33> {
+	if string.is_null() {
+		return Some("".try_into().unwrap());
+	}
+	CStr::from_ptr(string).to_str().ok()?.try_into().ok()
+}
+
+fn lu_message(msg: &LuServerMessage) -> Option<&LuMessage> {
+	match &msg.0 {
+		RaknetMessage::UserMessage(x) => Some(x),
+		_ => None,
+	}
+}
+
+fn world_message(msg: &LuServerMessage) -> Option<&WorldMessage> {
+	match lu_message(msg) {
+		Some(LuMessage::World(x)) => Some(x),
+		_ => None,
+	}
+}
+
+/**
+	Decodes a message received by a world server.
+
+	Returns null if the data is not a valid message.
+
+	# Safety
+	`data` must point to `len` readable bytes.
+*/
+#[no_mangle]
+pub unsafe extern "C" fn lu_server_message_decode(data: *const u8, len: usize) -> *mut LuServerMessage {
+	if data.is_null() {
+		return ptr::null_mut();
+	}
+	let mut reader = slice::from_raw_parts(data, len);
+	match LERead::read(&mut reader) {
+		Ok(msg) => Box::into_raw(Box::new(LuServerMessage(msg))),
+		Err(_) => ptr::null_mut(),
+	}
+}
+
+/**
+	Frees a message returned by [`lu_server_message_decode`].
+
+	# Safety
+	`msg` must be null or a handle returned by [`lu_server_message_decode`] that hasn't been freed yet.
+*/
+#[no_mangle]
+pub unsafe extern "C" fn lu_server_message_free(msg: *mut LuServerMessage) {
+	if !msg.is_null() {
+		drop(Box::from_raw(msg));
+	}
+}
+
+/**
+	Returns the raknet message ID, which is 83 for LU messages.
+
+	# Safety
+	`msg` must be a valid handle.
+*/
+#[no_mangle]
+pub unsafe extern "C" fn lu_server_message_raknet_id(msg: *const LuServerMessage) -> u8 {
+	discriminant(&(*msg).0)
+}
+
+/**
+	Returns the [`ServiceId`](lu_packets::common::ServiceId) of an LU message, or `0xffff` for other messages.
+
+	# Safety
+	`msg` must be a valid handle.
+*/
+#[no_mangle]
+pub unsafe extern "C" fn lu_server_message_service_id(msg: *const LuServerMessage) -> u16 {
+	lu_message(&*msg).map_or(u16::MAX, discriminant)
+}
+
+/**
+	Returns the ID of an LU message within its service, or `0xffffffff` for other messages.
+
+	# Safety
+	`msg` must be a valid handle.
+*/
+#[no_mangle]
+pub unsafe extern "C" fn lu_server_message_message_id(msg: *const LuServerMessage) -> u32 {
+	match lu_message(&*msg) {
+		Some(LuMessage::General(x)) => discriminant(x),
+		Some(LuMessage::World(x)) => discriminant(x),
+		None => u32::MAX,
+	}
+}
+
+/**
+	Returns the subject object ID of a game message, or [`OBJID_EMPTY`] for other messages.
+
+	# Safety
+	`msg` must be a valid handle.
+*/
+#[no_mangle]
+pub unsafe extern "C" fn lu_server_message_subject_id(msg: *const LuServerMessage) -> ObjId {
+	match world_message(&*msg) {
+		Some(WorldMessage::SubjectGameMessage(x)) => x.subject_id,
+		_ => OBJID_EMPTY,
+	}
+}
+
+/**
+	Returns the game message ID of a game message, or `0xffff` for other messages.
+
+	# Safety
+	`msg` must be a valid handle.
+*/
+#[no_mangle]
+pub unsafe extern "C" fn lu_server_message_game_message_id(msg: *const LuServerMessage) -> u16 {
+	match world_message(&*msg) {
+		Some(WorldMessage::SubjectGameMessage(x)) => discriminant(&x.message),
+		_ => u16::MAX,
+	}
+}
+
+/**
+	Returns the character ID of a [`CharacterLoginRequest`](lu_packets::world::server::CharacterLoginRequest) or [`CharacterDeleteRequest`](lu_packets::world::server::CharacterDeleteRequest), or [`OBJID_EMPTY`] for other messages.
+
+	# Safety
+	`msg` must be a valid handle.
+*/
+#[no_mangle]
+pub unsafe extern "C" fn lu_server_message_char_id(msg: *const LuServerMessage) -> ObjId {
+	match world_message(&*msg) {
+		Some(WorldMessage::CharacterLoginRequest(x)) => x.char_id,
+		Some(WorldMessage::CharacterDeleteRequest(x)) => x.char_id,
+		_ => OBJID_EMPTY,
+	}
+}
+
+/**
+	Copies the username of a [`ClientValidation`](lu_packets::world::server::ClientValidation) into `buf` as null-terminated UTF-8.
+
+	Returns the length of the username in bytes, without terminator, so that a too small buffer can be detected. Returns `SIZE_MAX` and leaves `buf` untouched for other messages.
+
+	# Safety
+	`msg` must be a valid handle, and `buf` must be null or point to `buf_len` writable bytes.
+*/
+#[no_mangle]
+pub unsafe extern "C" fn lu_server_message_username(msg: *const LuServerMessage, buf: *mut c_char, buf_len: usize) -> usize {
+	match world_message(&*msg) {
+		Some(WorldMessage::ClientValidation(x)) => copy_str(&String::from(&x.username), buf, buf_len),
+		_ => usize::MAX,
+	}
+}
+
+/**
+	Copies the session key of a [`ClientValidation`](lu_packets::world::server::ClientValidation) into `buf`, like [`lu_server_message_username`].
+
+	# Safety
+	`msg` must be a valid handle, and `buf` must be null or point to `buf_len` writable bytes.
+*/
+#[no_mangle]
+pub unsafe extern "C" fn lu_server_message_session_key(msg: *const LuServerMessage, buf: *mut c_char, buf_len: usize) -> usize {
+	match world_message(&*msg) {
+		Some(WorldMessage::ClientValidation(x)) => copy_str(&String::from(&x.session_key), buf, buf_len),
+		_ => usize::MAX,
+	}
+}
+
+/**
+	Renders the message as JSON, as described in the [`json`] module. Free the string with [`lu_string_free`].
+
+	# Safety
+	`msg` must be a valid handle.
+*/
+#[no_mangle]
+pub unsafe extern "C" fn lu_server_message_json(msg: *const LuServerMessage) -> *mut c_char {
+	// JSON strings escape nulls, so this can't fail
+	CString::new(json::to_json(&(*msg).0.to_value())).unwrap().into_raw()
+}
+
+/**
+	Renders the message as human-readable text. Free the string with [`lu_string_free`].
+
+	# Safety
+	`msg` must be a valid handle.
+*/
+#[no_mangle]
+pub unsafe extern "C" fn lu_server_message_debug(msg: *const LuServerMessage) -> *mut c_char {
+	// Debug output of strings escapes nulls, so this can't fail
+	CString::new(format!("{:#?}", (*msg).0)).unwrap().into_raw()
+}
+
+/**
+	Frees a string returned by this library.
+
+	# Safety
+	`string` must be null or a string returned by this library that hasn't been freed yet.
+*/
+#[no_mangle]
+pub unsafe extern "C" fn lu_string_free(string: *mut c_char) {
+	if !string.is_null() {
+		drop(CString::from_raw(string));
+	}
+}
+
+/**
+	Frees a buffer returned by this library.
+
+	# Safety
+	`buf` must be a buffer returned by this library that hasn't been freed yet.
+*/
+#[no_mangle]
+pub unsafe extern "C" fn lu_buffer_free(buf: LuBuffer) {
+	if !buf.data.is_null() {
+		drop(Box::from_raw(slice::from_raw_parts_mut(buf.data, buf.len)));
+	}
+}
+
+/**
+	Encodes a [`LoadStaticZone`] message.
+
+	`instance_type` is the numeric value of [`InstanceType`]. Returns a null buffer if it is out of range.
+*/
+#[no_mangle]
+pub extern "C" fn lu_load_static_zone(map_id: u16, instance_id: u16, clone_id: u32, map_checksum: u32, x: f32, y: f32, z: f32, instance_type: u32) -> LuBuffer {
+	let instance_type = match instance_type {
+		0 => InstanceType::Public,
+		1 => InstanceType::Single,
+		2 => InstanceType::Team,
+		3 => InstanceType::Guild,
+		4 => InstanceType::Match,
+		_ => return LuBuffer::NULL,
+	};
+	let msg = LoadStaticZone {
+		zone_id: ZoneId { map_id, instance_id, clone_id },
+		map_checksum,
+		player_position: Vector3 { x, y, z },
+		instance_type,
+	};
+	LuBuffer::from_message(&msg.into())
+}
+
+/**
+	Encodes a [`DisconnectNotify`] message.
+
+	`reason` is the numeric value of the disconnect reason, `version` is only used for [`WrongGameVersion`](DisconnectNotify::WrongGameVersion) and [`WrongServerVersion`](DisconnectNotify::WrongServerVersion). Returns a null buffer if `reason` is out of range.
+*/
+#[no_mangle]
+pub extern "C" fn lu_disconnect_notify(reason: u32, version: u32) -> LuBuffer {
+	use DisconnectNotify::*;
+
+	let msg = match reason {
+		0 => UnknownServerError,
+		1 => WrongGameVersion(version),
+		2 => WrongServerVersion(version),
+		3 => ConnectionOnInvalidPort,
+		4 => DuplicateLogin,
+		5 => ServerShutdown,
+		6 => UnableToLoadMap,
+		7 => InvalidSessionKey,
+		8 => AccountNotInPendingList,
+		9 => CharacterNotFound,
+		10 => CharacterCorruption,
+		11 => Kick,
+		12 => SaveFailure,
+		13 => FreeTrialExpired,
+		14 => PlayScheduleTimeUp,
+		_ => return LuBuffer::NULL,
+	};
+	LuBuffer::from_message(&msg.into())
+}
+
+/**
+	Encodes a [`CharacterListResponse`] message.
+
+	Returns a null buffer if a name is not valid UTF-8 or there are more than 255 characters.
+
+	# Safety
+	`chars` must point to `chars_len` characters, whose pointers must be valid as described in [`LuCharListChar`].
+*/
+#[no_mangle]
+pub unsafe extern "C" fn lu_character_list_response(selected_char: u8, chars: *const LuCharListChar, chars_len: usize) -> LuBuffer {
+	if chars_len > u8::MAX as usize || (chars.is_null() && chars_len > 0) {
+		return LuBuffer::NULL;
+	}
+	let c_chars = if chars_len == 0 { &[][..] } else { slice::from_raw_parts(chars, chars_len) };
+	let mut list = Vec::with_capacity(chars_len);
+	for c in c_chars {
+		let (char_name, pending_name) = match (wstr33(c.char_name), wstr33(c.pending_name)) {
+			(Some(a), Some(b)) => (a, b),
+			_ => return LuBuffer::NULL,
+		};
+		let equipped_items = if c.equipped_items.is_null() { vec![] } else { slice::from_raw_parts(c.equipped_items, c.equipped_items_len).to_vec() };
+		list.push(CharListChar {
+			obj_id: c.obj_id,
+			char_name,
+			pending_name,
+			requires_rename: c.requires_rename,
+			is_free_trial: c.is_free_trial,
+			torso_color: c.torso_color,
+			legs_color: c.legs_color,
+			hair_style: c.hair_style,
+			hair_color: c.hair_color,
+			eyebrows_style: c.eyebrows_style,
+			eyes_style: c.eyes_style,
+			mouth_style: c.mouth_style,
+			last_location: ZoneId { map_id: c.last_map_id, instance_id: c.last_instance_id, clone_id: c.last_clone_id },
+			equipped_items: equipped_items.into(),
+		});
+	}
+	let msg = CharacterListResponse { selected_char, chars: list };
+	LuBuffer::from_message(&msg.into())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn decode_client_validation() {
+		// raknet message ID and world service ID, followed by the world message
+		let mut data = vec![83, 4, 0];
+		data.extend_from_slice(include_bytes!("../../src/world/server/tests/ClientValidation.bin"));
+		unsafe {
+			let msg = lu_server_message_decode(data.as_ptr(), data.len());
+			assert!(!msg.is_null());
+			assert_eq!(lu_server_message_raknet_id(msg), 83);
+			assert_eq!(lu_server_message_service_id(msg), 4);
+			assert_eq!(lu_server_message_message_id(msg), 1);
+			let mut buf = [0 as c_char; 33];
+			let len = lu_server_message_username(msg, buf.as_mut_ptr(), buf.len());
+			assert_eq!(len, 7);
+			assert_eq!(CStr::from_ptr(buf.as_ptr()).to_bytes(), b"pwjones");
+			let json = lu_server_message_json(msg);
+			let json_str = CStr::from_ptr(json).to_str().unwrap();
+			assert!(json_str.starts_with(r#"{"UserMessage":{"World":{"ClientValidation":{"username":"pwjones","#), "{}", json_str);
+			lu_string_free(json);
+			lu_server_message_free(msg);
+		}
+	}
+
+	#[test]
+	fn wrong_message_kind() {
+		// raknet message ID and world service ID, followed by the world message
+		let mut data = vec![83, 4, 0];
+		data.extend_from_slice(include_bytes!("../../src/world/server/tests/LevelLoadComplete.bin"));
+		unsafe {
+			let msg = lu_server_message_decode(data.as_ptr(), data.len());
+			assert!(!msg.is_null());
+			assert_eq!(lu_server_message_username(msg, ptr::null_mut(), 0), usize::MAX);
+			lu_server_message_free(msg);
+		}
+	}
+
+	#[test]
+	fn encode_disconnect_notify() {
+		let buf = lu_disconnect_notify(1, 0);
+		assert!(!buf.data.is_null());
+		let bin = unsafe { slice::from_raw_parts(buf.data, buf.len) };
+		// raknet message ID and general service ID, followed by the general message
+		assert_eq!(&bin[..3], &[83, 0, 0]);
+		assert_eq!(&bin[3..], &include_bytes!("../../src/general/client/tests/DisconnectNotify.bin")[..]);
+		unsafe { lu_buffer_free(buf) };
+	}
+
+	#[test]
+	fn copy_str_char_boundary() {
+		let mut buf = [0x7f as c_char; 4];
+		// "é" is 2 bytes, so only "ab" fits next to the terminator
+		let len = unsafe { copy_str("abé", buf.as_mut_ptr(), buf.len()) };
+		assert_eq!(len, 4);
+		assert_eq!(unsafe { CStr::from_ptr(buf.as_ptr()) }.to_bytes(), b"ab");
+	}
+}
@@ -3,8 +3,7 @@ use std::io::{Error, ErrorKind::InvalidData, Result as Res, Read};
 
 use endio::{LEWrite, LERead, Deserialize, Serialize};
 use endio::LittleEndian as LE;
-use lu_packets_derive::MessageFromVariants;
-use lu_packets_derive::VariantTests;
+use lu_packets_derive::{Arbitrary, MessageDeserialize, MessageFromVariants, Reflect, Schema, VariantTests};
 
 use crate::common::{LuString3, LuString33, LuString37, LuVarWString, LuWString33, ServiceId};
 use crate::general::client::{DisconnectNotify, Handshake, GeneralMessage};
@@ -14,7 +13,7 @@ use crate::world::server::Language;
 pub type Message = crate::raknet::client::Message<LuMessage>;
 
 /// All LU messages that can be received by a client from an auth server.
-#[derive(Debug, MessageFromVariants, PartialEq, Serialize, Deserialize, VariantTests)]
+#[derive(Arbitrary, Debug, MessageFromVariants, PartialEq, Reflect, Schema, Serialize, MessageDeserialize, VariantTests)]
 #[repr(u16)]
 pub enum LuMessage {
 	General(GeneralMessage) = ServiceId::General as u16,
@@ -40,7 +39,7 @@ impl From<DisconnectNotify> for Message {
 }
 
 /// All client-received auth messages.
-#[derive(Debug, MessageFromVariants, PartialEq, Serialize, Deserialize)]
+#[derive(Arbitrary, Debug, MessageFromVariants, PartialEq, Reflect, Schema, Serialize, MessageDeserialize)]
 #[post_disc_padding = 1]
 #[repr(u32)]
 pub enum ClientMessage {
@@ -66,7 +65,7 @@ pub enum ClientMessage {
 	### Notes
 	Expect the connection to be closed soon after this message is received, if you're not closing it yourself already.
 */
-#[derive(Debug, PartialEq)]
+#[derive(Arbitrary, Debug, PartialEq, VariantTests)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum LoginResponse {
@@ -103,7 +102,7 @@ pub enum LoginResponse {
 	InvalidUsernamePassword = 6,
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Arbitrary, Reflect, Schema, Serialize, Deserialize, Debug, PartialEq)]
 pub struct Stamp {
 	pub type_: u32,
 	pub value: u32,
@@ -209,11 +208,13 @@ impl<R: Read + LERead> Deserialize<LE, R> for LoginResponse {
 				let mut padding = [0; 493];
 				Read::read_exact(reader, &mut padding)?;
 				let msg = LERead::read::<LuVarWString<u16>>(reader)?;
+				let _buffer_len_plus_four: u32 = LERead::read(reader)?;
 				Ok(Self::CustomMessage(msg))
 			}
 			6 => {
 				let mut padding = [0; 495];
 				Read::read_exact(reader, &mut padding)?;
+				let _buffer_len_plus_four: u32 = LERead::read(reader)?;
 				Ok(Self::InvalidUsernamePassword)
 			}
 			_ => Err(Error::new(InvalidData, "invalid login response type")),
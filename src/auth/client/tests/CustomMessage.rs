@@ -0,0 +1 @@
+LoginResponse::CustomMessage(lu!("Your account is locked."))
\ No newline at end of file
@@ -0,0 +1 @@
+LoginResponse::InvalidUsernamePassword
\ No newline at end of file
@@ -0,0 +1,135 @@
+LoginResponse::Ok {
+	events: (
+		lu!(b"Talk_Like_A_Pirate"),
+		lu!(b""),
+		lu!(b""),
+		lu!(b""),
+		lu!(b""),
+		lu!(b""),
+		lu!(b""),
+		lu!(b""),
+	),
+	version: (
+		1,
+		10,
+		64,
+	),
+	session_key: lu!("c3e7083dd6ed9e6bb2b15de9aa14856f"),
+	redirect_address: (
+		lu!(b"171.20.35.43"),
+		2006,
+	),
+	chat_server_address: (
+		lu!(b""),
+		0,
+	),
+	cdn_key: lu!(b""),
+	cdn_ticket: lu!(b"00000000-0000-0000-0000-000000000000"),
+	is_ftp: true,
+	just_upgraded_from_ftp: false,
+	time_remaining_in_ftp: 0,
+	language: Language::en_US,
+	country_code: lu!(b"US"),
+	stamps: vec![
+		Stamp {
+			type_: 0,
+			value: 0,
+			timestamp: 1323397193,
+		},
+		Stamp {
+			type_: 7,
+			value: 36,
+			timestamp: 1323397193,
+		},
+		Stamp {
+			type_: 8,
+			value: 4,
+			timestamp: 1323397193,
+		},
+		Stamp {
+			type_: 9,
+			value: 0,
+			timestamp: 1323397193,
+		},
+		Stamp {
+			type_: 10,
+			value: 0,
+			timestamp: 1323397193,
+		},
+		Stamp {
+			type_: 11,
+			value: 1,
+			timestamp: 1323397193,
+		},
+		Stamp {
+			type_: 14,
+			value: 1,
+			timestamp: 1323397193,
+		},
+		Stamp {
+			type_: 15,
+			value: 0,
+			timestamp: 1323397193,
+		},
+		Stamp {
+			type_: 17,
+			value: 1,
+			timestamp: 1323397193,
+		},
+		Stamp {
+			type_: 5,
+			value: 0,
+			timestamp: 1323397193,
+		},
+		Stamp {
+			type_: 6,
+			value: 1,
+			timestamp: 1323397193,
+		},
+		Stamp {
+			type_: 20,
+			value: 1,
+			timestamp: 1323397193,
+		},
+		Stamp {
+			type_: 19,
+			value: 10044,
+			timestamp: 1323397193,
+		},
+		Stamp {
+			type_: 21,
+			value: 0,
+			timestamp: 1323397192,
+		},
+		Stamp {
+			type_: 22,
+			value: 0,
+			timestamp: 1323397192,
+		},
+		Stamp {
+			type_: 23,
+			value: 10038,
+			timestamp: 1323397193,
+		},
+		Stamp {
+			type_: 27,
+			value: 10038,
+			timestamp: 1323397193,
+		},
+		Stamp {
+			type_: 28,
+			value: 1,
+			timestamp: 1323397193,
+		},
+		Stamp {
+			type_: 29,
+			value: 0,
+			timestamp: 1323397193,
+		},
+		Stamp {
+			type_: 30,
+			value: 10044,
+			timestamp: 1323397193,
+		},
+	],
+}
\ No newline at end of file
@@ -1,6 +1,6 @@
 //! Server-received auth messages.
 use endio::{Deserialize, Serialize};
-use lu_packets_derive::VariantTests;
+use lu_packets_derive::{Arbitrary, MessageDeserialize, MessageHandler, Reflect, Schema, VariantTests};
 
 use crate::common::{LuWString33, LuWString41, LuWString128, LuWString256, ServiceId};
 pub use crate::general::server::GeneralMessage;
@@ -9,7 +9,7 @@ pub use crate::general::server::GeneralMessage;
 pub type Message = crate::raknet::server::Message<LuMessage>;
 
 /// All LU messages that can be received by an auth server.
-#[derive(Debug, Deserialize, PartialEq, Serialize, VariantTests)]
+#[derive(Arbitrary, Debug, MessageDeserialize, PartialEq, Reflect, Schema, Serialize, VariantTests)]
 #[non_exhaustive]
 #[repr(u16)]
 pub enum LuMessage {
@@ -18,7 +18,7 @@ pub enum LuMessage {
 }
 
 /// All server-received auth messages.
-#[derive(Debug, Deserialize, PartialEq, Serialize, VariantTests)]
+#[derive(Arbitrary, Debug, MessageDeserialize, MessageHandler, PartialEq, Reflect, Schema, Serialize, VariantTests)]
 #[post_disc_padding = 1]
 #[repr(u32)]
 pub enum AuthMessage {
@@ -44,7 +44,7 @@ pub enum AuthMessage {
 	### Notes
 	The password is provided in plain text. **Don't** save this password to the database unprocessed, as this constitutes a **security hazard**. Hash and salt it using a strong cryptographic hash function before saving it.
 */
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct LoginRequest {
 	/// The client's user name.
 	pub username: LuWString33,
@@ -59,7 +59,7 @@ pub struct LoginRequest {
 }
 
 /// The client's operating system.
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 #[repr(u8)]
 pub enum ClientOs {
 	Unknown,
@@ -68,7 +68,7 @@ pub enum ClientOs {
 }
 
 /// Stats about the computer the client is running on.
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct ComputerStats {
 	pub memory_stats: LuWString256,
 	pub video_card_info: LuWString128,
@@ -79,7 +79,7 @@ pub struct ComputerStats {
 }
 
 /// Info about the processor the client is running on.
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct ProcessorInfo {
 	/// Number of processors. [`SYSTEM_INFO::dwNumberOfProcessors`](https://docs.microsoft.com/en-us/windows/win32/api/sysinfoapi/ns-sysinfoapi-system_info)
 	pub number_of_processors: u32,
@@ -92,7 +92,7 @@ pub struct ProcessorInfo {
 }
 
 /// Info about the operating system the client is running on.
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct OsInfo {
 	/// Size of [`OSVERSIONINFO`](https://docs.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-osversioninfoa). Pretty useless.
 	pub os_version_info_size: u32,
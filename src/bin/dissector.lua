@@ -0,0 +1,699 @@
+-- Runtime of the generated dissector, interprets the `types` table above.
+
+local lu = Proto("lu", "LEGO Universe")
+local f_message = ProtoField.string("lu.message", "Message")
+lu.fields = { f_message }
+
+local int_sizes = { u8 = 1, u16 = 2, u32 = 4, u64 = 8, i8 = 1, i16 = 2, i32 = 4, i64 = 8, f32 = 4, f64 = 8 }
+
+-- Position in the message. Positions are in bits so that bit-level game message fields can be located.
+local Cursor = {}
+Cursor.__index = Cursor
+
+local function new_cursor(tvb)
+	return setmetatable({ tvb = tvb, pos = 0, bitmode = false, stopped = false, path = {} }, Cursor)
+end
+
+function Cursor:remaining_bits()
+	return self.tvb:len() * 8 - self.pos
+end
+
+function Cursor:check(nbits)
+	if nbits > self:remaining_bits() then
+		error("message is too short", 0)
+	end
+end
+
+function Cursor:skip(nbytes)
+	self:check(nbytes * 8)
+	self.pos = self.pos + nbytes * 8
+end
+
+function Cursor:bit()
+	self:check(1)
+	local byte = self.tvb:range(math.floor(self.pos / 8), 1):uint()
+	local b = math.floor(byte / 2 ^ (7 - self.pos % 8)) % 2
+	self.pos = self.pos + 1
+	return b
+end
+
+-- Reads `n` bytes, MSB first if not byte-aligned, like endio_bit's BEBitReader.
+function Cursor:bytes(n)
+	self:check(n * 8)
+	local out = {}
+	if self.pos % 8 == 0 then
+		local start = self.pos / 8
+		for i = 1, n do
+			out[i] = self.tvb:range(start + i - 1, 1):uint()
+		end
+		self.pos = self.pos + n * 8
+	else
+		for i = 1, n do
+			local v = 0
+			for _ = 1, 8 do
+				v = v * 2 + self:bit()
+			end
+			out[i] = v
+		end
+	end
+	return out
+end
+
+-- Range covering the bytes from bit `start` to the current position.
+function Cursor:range(start)
+	local len = self.tvb:len()
+	local first = math.min(math.floor(start / 8), len - 1)
+	local last = math.max(math.ceil(self.pos / 8), first + 1)
+	return self.tvb:range(first, last - first)
+end
+
+local function add_item(tree, c, start, label)
+	return tree:add(c:range(start), label)
+end
+
+local function finish(item, c, start)
+	item:set_len(c:range(start):len())
+end
+
+local function num(v)
+	if type(v) == "number" then
+		return v
+	end
+	return v:tonumber()
+end
+
+local function le_uint(b, from, n)
+	local v = 0
+	for i = n, 1, -1 do
+		v = v * 256 + b[from + i - 1]
+	end
+	return v
+end
+
+local function decode_float(b, n)
+	local sign, exp, mant, mant_bits, bias
+	if n == 4 then
+		local v = le_uint(b, 1, 4)
+		sign = math.floor(v / 2 ^ 31)
+		exp = math.floor(v / 2 ^ 23) % 256
+		mant = v % 2 ^ 23
+		mant_bits = 23
+		bias = 127
+	else
+		local lo, hi = le_uint(b, 1, 4), le_uint(b, 5, 4)
+		sign = math.floor(hi / 2 ^ 31)
+		exp = math.floor(hi / 2 ^ 20) % 2048
+		mant = (hi % 2 ^ 20) * 2 ^ 32 + lo
+		mant_bits = 52
+		bias = 1023
+	end
+	local val
+	if exp == 2 * bias + 1 then
+		val = mant == 0 and math.huge or 0 / 0
+	elseif exp == 0 then
+		val = mant * 2 ^ (1 - bias - mant_bits)
+	else
+		val = (1 + mant / 2 ^ mant_bits) * 2 ^ (exp - bias)
+	end
+	if sign == 1 then
+		val = -val
+	end
+	return val
+end
+
+local function read_prim(c, name)
+	if name == "bool" then
+		if c.bitmode then
+			return c:bit() == 1
+		end
+		return c:bytes(1)[1] ~= 0
+	end
+	local n = int_sizes[name]
+	local b = c:bytes(n)
+	if name == "f32" or name == "f64" then
+		return decode_float(b, n)
+	end
+	if n == 8 then
+		local lo, hi = le_uint(b, 1, 4), le_uint(b, 5, 4)
+		if name == "u64" then
+			return UInt64.new(lo, hi)
+		end
+		return Int64.new(lo, hi)
+	end
+	local v = le_uint(b, 1, n)
+	if name:sub(1, 1) == "i" and v >= 2 ^ (8 * n - 1) then
+		v = v - 2 ^ (8 * n)
+	end
+	return v
+end
+
+local function utf8_encode(codes)
+	local out = {}
+	for _, cp in ipairs(codes) do
+		if cp < 0x80 then
+			out[#out + 1] = string.char(cp)
+		elseif cp < 0x800 then
+			out[#out + 1] = string.char(0xc0 + math.floor(cp / 0x40), 0x80 + cp % 0x40)
+		else
+			out[#out + 1] = string.char(0xe0 + math.floor(cp / 0x1000), 0x80 + math.floor(cp / 0x40) % 0x40, 0x80 + cp % 0x40)
+		end
+	end
+	return table.concat(out)
+end
+
+local function read_char(c, wide)
+	if wide then
+		return le_uint(c:bytes(2), 1, 2)
+	end
+	return c:bytes(1)[1]
+end
+
+-- Fixed-length strings are null-terminated, variable-length ones aren't.
+local function read_chars(c, wide, n, fixed)
+	local codes = {}
+	local terminated = false
+	for _ = 1, n do
+		local code = read_char(c, wide)
+		if code == 0 and fixed then
+			terminated = true
+		end
+		if not terminated then
+			codes[#codes + 1] = code
+		end
+	end
+	return utf8_encode(codes)
+end
+
+local function resolve(ty)
+	if type(ty) == "string" and types[ty] ~= nil then
+		return types[ty]
+	end
+	return ty
+end
+
+local dissect
+
+local function dissect_fields(fields, game_message, c, tree)
+	local entered_bitmode = false
+	for _, f in ipairs(fields) do
+		if c.stopped then
+			break
+		end
+		if game_message and not c.bitmode and (f.type == "bool" or f.default) then
+			c.bitmode = true
+			entered_bitmode = true
+		end
+		c:skip(f.padding)
+		if f.default then
+			local start = c.pos
+			if c:bit() == 0 then
+				add_item(tree, c, start, f.name .. ": (default)")
+			else
+				dissect(f.type, c, tree, f.name, game_message)
+			end
+		else
+			dissect(f.type, c, tree, f.name, game_message)
+		end
+	end
+	-- the bit writer is flushed at the end of the game message
+	if entered_bitmode then
+		c.bitmode = false
+		c.pos = math.ceil(c.pos / 8) * 8
+	end
+end
+
+-- replica data is read at bit level throughout
+local function enter_replica(ty, c)
+	if ty.replica then
+		c.bitmode = true
+	end
+end
+
+local function dissect_struct(ty, c, tree)
+	enter_replica(ty, c)
+	dissect_fields(ty.fields, ty.game_message, c, tree)
+	if not c.stopped then
+		c:skip(ty.trailing_padding)
+	end
+end
+
+local function dissect_rest(c, tree, label)
+	local start = c.pos
+	local n = math.floor(c:remaining_bits() / 8)
+	c:bytes(n)
+	add_item(tree, c, start, string.format("%s (%d bytes)", label, n))
+end
+
+-- Reads an AMF3 U29, an unsigned integer of up to 4 bytes with 7 bits each, except for 8 in the fourth.
+local function read_u29(c)
+	local v = 0
+	for _ = 1, 3 do
+		local b = c:bytes(1)[1]
+		v = v * 128 + b % 128
+		if b < 128 then
+			return v
+		end
+	end
+	return v * 256 + c:bytes(1)[1]
+end
+
+-- Reads an AMF3 string, which is either inline or a reference to an earlier non-empty one.
+local function read_amf3_string(c, strings)
+	local v = read_u29(c)
+	if v % 2 == 0 then
+		local s = strings[math.floor(v / 2) + 1]
+		if s == nil then
+			error("invalid AMF3 string reference", 0)
+		end
+		return s
+	end
+	local chars = {}
+	for i, b in ipairs(c:bytes(math.floor(v / 2))) do
+		chars[i] = string.char(b)
+	end
+	local s = table.concat(chars)
+	if s ~= "" then
+		strings[#strings + 1] = s
+	end
+	return s
+end
+
+local function dissect_amf3(c, tree, name, strings)
+	local start = c.pos
+	local marker = c:bytes(1)[1]
+	if marker == 2 or marker == 3 then
+		add_item(tree, c, start, string.format("%s: %s", name, tostring(marker == 3)))
+	elseif marker == 5 then
+		add_item(tree, c, start, string.format("%s: %s", name, tostring(read_prim(c, "f64"))))
+	elseif marker == 6 then
+		add_item(tree, c, start, string.format("%s: %q", name, read_amf3_string(c, strings)))
+	elseif marker == 9 then
+		local v = read_u29(c)
+		if v % 2 == 0 then
+			error("AMF3 array references are not supported", 0)
+		end
+		local item = add_item(tree, c, start, string.format("%s: array", name))
+		-- associative part, terminated by an empty key, then the dense part
+		while true do
+			local key = read_amf3_string(c, strings)
+			if key == "" then
+				break
+			end
+			dissect_amf3(c, item, key, strings)
+		end
+		for i = 1, math.floor(v / 2) do
+			dissect_amf3(c, item, string.format("[%d]", i - 1), strings)
+		end
+		finish(item, c, start)
+	else
+		error(string.format("invalid AMF3 marker %d", marker), 0)
+	end
+end
+
+-- Decompresses zlib data into a new data source. `uncompress` was renamed in Wireshark 4.4.
+local function inflate(bytes, name)
+	local hex = {}
+	for i, b in ipairs(bytes) do
+		hex[i] = string.format("%02x", b)
+	end
+	local range = ByteArray.new(table.concat(hex)):tvb(name)()
+	local ok, tvb = pcall(function() return range:uncompress_zlib(name) end)
+	if not ok then
+		tvb = range:uncompress(name)
+	end
+	return tvb
+end
+
+local function dissect_lnv_entries(value, c, tree)
+	local n = num(read_prim(c, "u32"))
+	-- the value types aren't message variants, keep them out of the path
+	local path_len = #c.path
+	for _ = 1, n do
+		if c.stopped then
+			break
+		end
+		local key_len = c:bytes(1)[1]
+		local key = read_chars(c, true, math.floor(key_len / 2), false)
+		dissect(value, c, tree, key)
+	end
+	for i = #c.path, path_len + 1, -1 do
+		c.path[i] = nil
+	end
+end
+
+-- LuNameValues are length-prefixed and usually compressed, except in game messages, where they are text.
+local function dissect_lnv(ty, c, tree, name, gm_param)
+	local start = c.pos
+	if gm_param then
+		local n = num(read_prim(c, "u32"))
+		local s = read_chars(c, true, n, false)
+		-- null terminator
+		if n > 0 then
+			c:skip(2)
+		end
+		add_item(tree, c, start, string.format("%s: %q", name, s))
+		return
+	end
+	read_prim(c, "u32")
+	local compressed = c:bytes(1)[1] ~= 0
+	local item = add_item(tree, c, start, string.format("%s: LuNameValue", name))
+	if compressed then
+		read_prim(c, "u32")
+		local n = num(read_prim(c, "u32"))
+		local ok, tvb = pcall(inflate, c:bytes(n), "LuNameValue")
+		if ok and tvb ~= nil then
+			dissect_lnv_entries(ty.value, new_cursor(tvb), item)
+		else
+			item:add_expert_info(PI_UNDECODED, PI_WARN, "could not decompress")
+		end
+	else
+		-- the entries are byte-level even in replica data
+		local bitmode = c.bitmode
+		c.bitmode = false
+		dissect_lnv_entries(ty.value, c, item)
+		c.bitmode = bitmode
+	end
+	finish(item, c, start)
+end
+
+-- Dissects a value of type `ty` named `name` into `tree`, and returns the value for primitive types. `gm_param` is set for fields of game messages.
+dissect = function(ty, c, tree, name, gm_param)
+	ty = resolve(ty)
+	local start = c.pos
+	if ty == "rest" then
+		dissect_rest(c, tree, name)
+	elseif type(ty) == "string" then
+		local v = read_prim(c, ty)
+		add_item(tree, c, start, string.format("%s: %s", name, tostring(v)))
+		return v
+	elseif ty.kind == "opaque" then
+		dissect_rest(c, tree, string.format("%s: %s, layout not described", name, ty.name))
+		c.stopped = true
+	elseif ty.kind == "lnv" then
+		dissect_lnv(ty, c, tree, name, gm_param)
+	elseif ty.kind == "amf3" then
+		dissect_amf3(c, tree, name, {})
+	elseif ty.kind == "char" then
+		local v = read_char(c, ty.wide)
+		add_item(tree, c, start, string.format("%s: %s", name, utf8_encode({ v })))
+	elseif ty.kind == "array" or ty.kind == "list" then
+		local n = ty.len
+		if ty.kind == "list" then
+			n = num(read_prim(c, ty.len_type))
+		end
+		local elem = resolve(ty.elem)
+		if type(elem) == "table" and elem.kind == "char" then
+			local s = read_chars(c, elem.wide, n, ty.kind == "array")
+			add_item(tree, c, start, string.format("%s: %q", name, s))
+		elseif elem == "u8" then
+			c:bytes(n)
+			add_item(tree, c, start, string.format("%s: %d bytes", name, n))
+		else
+			local item = add_item(tree, c, start, string.format("%s: %d elements", name, n))
+			for i = 1, n do
+				if c.stopped then
+					break
+				end
+				dissect(elem, c, item, string.format("[%d]", i - 1))
+			end
+			finish(item, c, start)
+		end
+	elseif ty.kind == "struct" then
+		local item = add_item(tree, c, start, string.format("%s: %s", name, ty.name))
+		dissect_struct(ty, c, item)
+		finish(item, c, start)
+	elseif ty.kind == "enum" then
+		enter_replica(ty, c)
+		c:skip(ty.pre_disc_padding)
+		local disc = num(read_prim(c, ty.disc))
+		c:skip(ty.post_disc_padding)
+		local variant = ty.variants[disc]
+		if variant == nil then
+			add_item(tree, c, start, string.format("%s: %s, unknown discriminant %d", name, ty.name, disc))
+			c.stopped = true
+			return
+		end
+		c.path[#c.path + 1] = variant.name
+		local item = add_item(tree, c, start, string.format("%s: %s::%s (%d)", name, ty.name, variant.name, disc))
+		local fields = variant.fields
+		local inner = not ty.game_message and #fields == 1 and fields[1].name == "0" and fields[1].padding == 0 and resolve(fields[1].type)
+		-- show the contents of newtype variants directly under the variant
+		if type(inner) == "table" and inner.kind == "struct" then
+			dissect_struct(inner, c, item)
+		elseif type(inner) == "table" and inner.kind == "enum" then
+			dissect(inner, c, item, inner.name)
+		else
+			dissect_fields(fields, ty.game_message, c, item)
+		end
+		finish(item, c, start)
+	end
+end
+
+-- Dissects one raknet message, and returns the path of variant names for the info column.
+local function dissect_message(tvb, pinfo, tree)
+	local subtree = tree:add(lu, tvb())
+	local c = new_cursor(tvb)
+	local ok, err = pcall(dissect, root, c, subtree, "message")
+	local path = table.concat(c.path, " > ")
+	subtree:add(f_message, tvb(), path):set_generated()
+	if not ok then
+		subtree:add_expert_info(PI_MALFORMED, PI_ERROR, tostring(err))
+	elseif not c.stopped and math.ceil(c.pos / 8) < tvb:len() then
+		subtree:add_expert_info(PI_UNDECODED, PI_WARN, string.format("%d trailing bytes", tvb:len() - math.ceil(c.pos / 8)))
+	end
+	return path
+end
+
+function lu.dissector(tvb, pinfo, tree)
+	pinfo.cols.protocol = "LU"
+	pinfo.cols.info = dissect_message(tvb, pinfo, tree)
+end
+
+-- Captures with one raknet message per packet can use link-layer type USER0.
+DissectorTable.get("wtap_encap"):add(wtap_encaps.USER0, lu)
+
+-- RakNet's reliability layer, as in ReliabilityLayer.cpp of RakNet 3.25.
+
+local raknet = Proto("lu_raknet", "LU RakNet")
+
+local reliabilities = { [0] = "Unreliable", "UnreliableSequenced", "Reliable", "ReliableOrdered", "ReliableSequenced" }
+
+-- Messages sent without the reliability layer, before a connection is established.
+local offline_ids = { [9] = true, [10] = true, [18] = true, [22] = true }
+
+-- Reads `n` bits as a number, like RakNet's ReadBits with right-aligned bits.
+function Cursor:bits(n)
+	local v = 0
+	for _ = 1, n do
+		v = v * 2 + self:bit()
+	end
+	return v
+end
+
+function Cursor:u32()
+	return le_uint(self:bytes(4), 1, 4)
+end
+
+-- RakNet's ReadCompressed for unsigned integers of `size` bytes: each leading zero byte is replaced by a set bit, and a zero upper nibble of the last byte by one more.
+function Cursor:compressed(size)
+	local b = {}
+	for i = size, 2, -1 do
+		if self:bit() == 0 then
+			local rest = self:bytes(i)
+			for j = 1, i do
+				b[j] = rest[j]
+			end
+			return le_uint(b, 1, size)
+		end
+		b[i] = 0
+	end
+	if self:bit() == 1 then
+		b[1] = self:bits(4)
+	else
+		b[1] = self:bytes(1)[1]
+	end
+	return le_uint(b, 1, size)
+end
+
+-- Parses a datagram into its acks and packets, erroring if it isn't one.
+local function parse_datagram(tvb)
+	local c = new_cursor(tvb)
+	local d = { acks = {}, packets = {} }
+	if c:bit() == 1 then
+		d.ack_time = c:u32()
+		for _ = 1, c:compressed(2) do
+			local min_equals_max = c:bit() == 1
+			local min = c:u32()
+			local max = min
+			if not min_equals_max then
+				max = c:u32()
+			end
+			d.acks[#d.acks + 1] = { min = min, max = max }
+		end
+	end
+	-- acks only, the rest is padding
+	if c:remaining_bits() < 8 then
+		return d
+	end
+	if c:bit() == 1 then
+		d.remote_time = c:u32()
+	end
+	while c:remaining_bits() >= 32 do
+		local p = { start = c.pos }
+		p.number = c:u32()
+		p.reliability = c:bits(3)
+		if reliabilities[p.reliability] == nil then
+			error("invalid reliability", 0)
+		end
+		if p.reliability == 1 or p.reliability == 3 or p.reliability == 4 then
+			p.channel = c:bits(5)
+			p.order = c:u32()
+		end
+		if c:bit() == 1 then
+			p.split_id = le_uint(c:bytes(2), 1, 2)
+			p.split_index = c:compressed(4)
+			p.split_count = c:compressed(4)
+			if p.split_index >= p.split_count then
+				error("invalid split packet index", 0)
+			end
+		end
+		local nbits = c:compressed(2)
+		if nbits == 0 then
+			error("empty packet", 0)
+		end
+		c.pos = math.ceil(c.pos / 8) * 8
+		p.offset = c.pos / 8
+		p.len = math.ceil(nbits / 8)
+		c:skip(p.len)
+		d.packets[#d.packets + 1] = p
+	end
+	if c:remaining_bits() >= 8 then
+		error("trailing bytes", 0)
+	end
+	if #d.packets == 0 and #d.acks == 0 then
+		error("empty datagram", 0)
+	end
+	return d
+end
+
+-- Fragments of split packets by connection and split ID, collected on the first pass.
+local fragments = {}
+-- Reassembled split packets by connection and split ID, with the frame they're shown in.
+local reassembled = {}
+
+local function split_key(pinfo, p)
+	return string.format("%s:%d>%s:%d#%d", tostring(pinfo.src), pinfo.src_port, tostring(pinfo.dst), pinfo.dst_port, p.split_id)
+end
+
+-- Collects a fragment, and returns the reassembled packet if it is shown in this frame.
+local function reassemble(tvb, pinfo, p)
+	local key = split_key(pinfo, p)
+	if not pinfo.visited then
+		local parts = fragments[key]
+		if parts == nil or parts.count ~= p.split_count then
+			parts = { count = p.split_count, n = 0 }
+			fragments[key] = parts
+		end
+		if parts[p.split_index] == nil then
+			parts.n = parts.n + 1
+		end
+		parts[p.split_index] = tvb:range(p.offset, p.len):bytes()
+		if parts.n == parts.count then
+			local data = ByteArray.new()
+			for i = 0, parts.count - 1 do
+				data:append(parts[i])
+			end
+			reassembled[key] = { data = data, frame = pinfo.number }
+			fragments[key] = nil
+		end
+	end
+	local done = reassembled[key]
+	if done ~= nil and done.frame == pinfo.number then
+		return done.data:tvb("Reassembled message")
+	end
+end
+
+local function dissect_datagram(tvb, pinfo, tree, d)
+	pinfo.cols.protocol = "LU"
+	local subtree = tree:add(raknet, tvb())
+	if d.ack_time ~= nil then
+		local acks = {}
+		for _, a in ipairs(d.acks) do
+			acks[#acks + 1] = a.min == a.max and tostring(a.min) or string.format("%d-%d", a.min, a.max)
+		end
+		subtree:add(tvb(), string.format("Acks: %s (time %d)", table.concat(acks, ", "), d.ack_time))
+	end
+	if d.remote_time ~= nil then
+		subtree:add(tvb(), string.format("Remote system time: %d", d.remote_time))
+	end
+	local info = {}
+	for _, p in ipairs(d.packets) do
+		local first = math.floor(p.start / 8)
+		local label = string.format("Packet %d, %s", p.number, reliabilities[p.reliability])
+		if p.channel ~= nil then
+			label = label .. string.format(", channel %d, index %d", p.channel, p.order)
+		end
+		if p.split_id ~= nil then
+			label = label .. string.format(", split %d part %d/%d", p.split_id, p.split_index + 1, p.split_count)
+		end
+		local item = subtree:add(tvb:range(first, p.offset + p.len - first), label)
+		local payload = tvb:range(p.offset, p.len):tvb()
+		if p.split_id ~= nil then
+			payload = reassemble(tvb, pinfo, p)
+		end
+		if payload ~= nil then
+			info[#info + 1] = dissect_message(payload, pinfo, item)
+		end
+	end
+	if #info == 0 then
+		info[1] = #d.packets == 0 and "Acks" or "Split packet fragment"
+	end
+	pinfo.cols.info = table.concat(info, ", ")
+end
+
+function raknet.dissector(tvb, pinfo, tree)
+	if tvb:len() == 0 then
+		return
+	end
+	if tvb:len() <= 2 and offline_ids[tvb:range(0, 1):uint()] then
+		lu.dissector(tvb, pinfo, tree)
+		return
+	end
+	local ok, d = pcall(parse_datagram, tvb)
+	if not ok then
+		local subtree = tree:add(raknet, tvb())
+		subtree:add_expert_info(PI_MALFORMED, PI_ERROR, tostring(d))
+		return
+	end
+	dissect_datagram(tvb, pinfo, tree, d)
+end
+
+-- Claims UDP datagrams that parse as offline messages or as reliability layer datagrams whose unsplit packets are known messages.
+local function heuristic(tvb, pinfo, tree)
+	if tvb:len() == 0 then
+		return false
+	end
+	if tvb:len() <= 2 then
+		if offline_ids[tvb:range(0, 1):uint()] then
+			lu.dissector(tvb, pinfo, tree)
+			return true
+		end
+		return false
+	end
+	local ok, d = pcall(parse_datagram, tvb)
+	if not ok then
+		return false
+	end
+	local variants = resolve(root).variants
+	for _, p in ipairs(d.packets) do
+		if p.split_id == nil and variants[tvb:range(p.offset, 1):uint()] == nil then
+			return false
+		end
+	end
+	dissect_datagram(tvb, pinfo, tree, d)
+	return true
+end
+
+raknet:register_heuristic("udp", heuristic)
+-- the auth server's port, other servers are detected heuristically
+DissectorTable.get("udp.port"):add(1001, raknet)
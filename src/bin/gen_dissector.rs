@@ -0,0 +1,86 @@
+/*!
+	Generates a Wireshark dissector from the message schema.
+
+	Usage: `cargo run --features schema --bin gen_dissector > lu.lua`, then copy `lu.lua` into Wireshark's plugin directory. With `--json`, the schema is printed as JSON instead.
+
+	The dissector decodes [`unified::Message`](lu_packets::unified::Message), which covers messages in both directions. Types without a described layout are shown as raw bytes, along with everything following them.
+
+	UDP traffic is detected heuristically. The dissector decodes RakNet's reliability layer, including acks and the reassembly of split packets, and then the messages in it. Captures with one message per packet and link-layer type USER0 are decoded as well.
+*/
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use lu_packets::schema::{Field, Schema, Type};
+use lu_packets::unified::Message;
+
+const RUNTIME: &str = include_str!("dissector.lua");
+
+fn main() {
+	let schema = Message::schema();
+	if std::env::args().any(|x| x == "--json") {
+		print!("{}", schema.to_json());
+		return;
+	}
+	let mut types = BTreeMap::new();
+	let root = lua(&schema, &mut types);
+	let mut out = String::from("-- Generated by lu_packets' gen_dissector, do not edit.\n\nlocal types = {}\n");
+	for (id, def) in &types {
+		writeln!(out, "types[{}] = {}", lua_str(id), def).unwrap();
+	}
+	writeln!(out, "local root = {}\n", root).unwrap();
+	out += RUNTIME;
+	print!("{}", out);
+}
+
+/// Converts the type to a Lua table, with structs and enums collected in `types` and referenced by ID.
+fn lua(ty: &Type, types: &mut BTreeMap<&'static str, String>) -> String {
+	match ty {
+		Type::Bool => lua_str("bool"),
+		Type::U8 => lua_str("u8"),
+		Type::U16 => lua_str("u16"),
+		Type::U32 => lua_str("u32"),
+		Type::U64 => lua_str("u64"),
+		Type::I8 => lua_str("i8"),
+		Type::I16 => lua_str("i16"),
+		Type::I32 => lua_str("i32"),
+		Type::I64 => lua_str("i64"),
+		Type::F32 => lua_str("f32"),
+		Type::F64 => lua_str("f64"),
+		Type::Char { wide } => format!("{{ kind = \"char\", wide = {} }}", wide),
+		Type::Array { elem, len } => format!("{{ kind = \"array\", elem = {}, len = {} }}", lua(elem, types), len),
+		Type::List { len, elem } => format!("{{ kind = \"list\", len_type = {}, elem = {} }}", lua(len, types), lua(elem, types)),
+		Type::Rest => lua_str("rest"),
+		Type::LuNameValue { value } => format!("{{ kind = \"lnv\", value = {} }}", lua(value, types)),
+		Type::Amf3 => String::from("{ kind = \"amf3\" }"),
+		Type::Opaque(name) => format!("{{ kind = \"opaque\", name = {} }}", lua_str(name)),
+		Type::Struct(s) => {
+			if !types.contains_key(s.id) {
+				types.insert(s.id, String::new());
+				let def = format!("{{ kind = \"struct\", name = {}, fields = {}, trailing_padding = {}, game_message = {}, replica = {} }}", lua_str(s.name), lua_fields(&s.fields, types), s.trailing_padding, s.game_message, s.replica);
+				types.insert(s.id, def);
+			}
+			lua_str(s.id)
+		}
+		Type::Enum(e) => {
+			if !types.contains_key(e.id) {
+				types.insert(e.id, String::new());
+				let mut variants = String::new();
+				for v in &e.variants {
+					write!(variants, "\n\t[{}] = {{ name = {}, fields = {} }},", v.disc, lua_str(v.name), lua_fields(&v.fields, types)).unwrap();
+				}
+				let def = format!("{{ kind = \"enum\", name = {}, disc = {}, pre_disc_padding = {}, post_disc_padding = {}, variants = {{{}\n}}, game_message = {}, replica = {} }}", lua_str(e.name), lua(&e.disc, types), e.pre_disc_padding, e.post_disc_padding, variants, e.game_message, e.replica);
+				types.insert(e.id, def);
+			}
+			lua_str(e.id)
+		}
+	}
+}
+
+fn lua_fields(fields: &[Field], types: &mut BTreeMap<&'static str, String>) -> String {
+	let fields: Vec<_> = fields.iter().map(|f| format!("{{ name = {}, type = {}, padding = {}, default = {} }}", lua_str(f.name), lua(&f.ty, types), f.padding, f.default)).collect();
+	format!("{{ {} }}", fields.join(", "))
+}
+
+fn lua_str(string: &str) -> String {
+	format!("\"{}\"", string.replace('\\', "\\\\").replace('"', "\\\""))
+}
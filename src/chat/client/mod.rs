@@ -1,11 +1,11 @@
 use endio::{Deserialize, Serialize};
-use lu_packets_derive::{MessageFromVariants, VariantTests};
+use lu_packets_derive::{Arbitrary, MessageDeserialize, MessageFromVariants, Reflect, Schema, VariantTests};
 
 use crate::common::{LuWString33, ObjId};
 use crate::world::client::Message;
 pub use super::{GeneralChatMessage, PrivateChatMessage};
 
-#[derive(Debug, Deserialize, PartialEq, Serialize, MessageFromVariants, VariantTests)]
+#[derive(Arbitrary, Debug, MessageDeserialize, PartialEq, Reflect, Schema, Serialize, MessageFromVariants, VariantTests)]
 #[non_exhaustive]
 #[post_disc_padding = 9]
 #[repr(u32)]
@@ -15,7 +15,7 @@ pub enum ChatMessage {
 	AchievementNotify(AchievementNotify) = 59,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct AchievementNotify {
 	#[padding = 5]
 	pub sender_name: LuWString33,
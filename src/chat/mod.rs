@@ -3,14 +3,15 @@ pub mod client;
 pub mod server;
 
 use std::io::{Read, Write};
-use std::io::Result as Res;
+use std::io::{Error, ErrorKind::InvalidData, Result as Res};
 
 use endio::{Deserialize, LERead, LEWrite, Serialize};
+use lu_packets_derive::{Arbitrary, Reflect, Schema};
 use endio::LittleEndian as LE;
 
-use crate::common::{LuVarWString, LuWString33, ObjId};
+use crate::common::{take, LuVarWString, LuWStrRef, LuWString33, ObjId};
 
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Clone, Copy, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 #[repr(u8)]
 pub enum ChatChannel {
 	SystemNotify,
@@ -38,7 +39,7 @@ pub enum ChatChannel {
 	User,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Arbitrary, Debug, PartialEq)]
 pub struct GeneralChatMessage {
 	pub chat_channel: ChatChannel,
 	pub sender_name: LuWString33,
@@ -97,7 +98,77 @@ where
 	}
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+/**
+	Borrowed view of a [`GeneralChatMessage`], used by [`MessageRef`](crate::unified::MessageRef).
+
+	The message borrows from the input, and the other fields are decoded when accessed.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GeneralChatMessageRef<'a> {
+	header: &'a [u8],
+	message: LuWStrRef<'a>,
+}
+
+impl<'a> GeneralChatMessageRef<'a> {
+	const HEADER_LEN: usize = 82;
+
+	pub fn parse(data: &mut &'a [u8]) -> Res<Self> {
+		let header = take(data, Self::HEADER_LEN)?;
+		let chat_channel: ChatChannel = LERead::read(&mut &header[..])?;
+		let mut str_len: u32 = LERead::read(&mut &header[1..])?;
+		if chat_channel == ChatChannel::Team {
+			str_len = str_len.checked_sub(1).ok_or_else(|| Error::new(InvalidData, "team chat message without null terminator"))?;
+		}
+		let message = LuWStrRef::read(data, str_len as usize)?;
+		take(data, 2)?;
+		Ok(Self { header, message })
+	}
+
+	/// Decodes the fixed-size field at `offset`, which is in bounds since the header length was checked when parsing.
+	fn field<T>(&self, offset: usize) -> T
+	where
+		for<'b> T: Deserialize<LE, &'b [u8]>,
+	{
+		LERead::read(&mut &self.header[offset..]).unwrap()
+	}
+
+	pub fn chat_channel(&self) -> ChatChannel {
+		self.field(0)
+	}
+
+	pub fn sender_name(&self) -> LuWString33 {
+		self.field(5)
+	}
+
+	pub fn sender(&self) -> ObjId {
+		self.field(71)
+	}
+
+	pub fn source_id(&self) -> u16 {
+		self.field(79)
+	}
+
+	pub fn sender_gm_level(&self) -> u8 {
+		self.field(81)
+	}
+
+	pub fn message(&self) -> LuWStrRef<'a> {
+		self.message
+	}
+
+	pub fn into_owned(self) -> GeneralChatMessage {
+		GeneralChatMessage {
+			chat_channel: self.chat_channel(),
+			sender_name: self.sender_name(),
+			sender: self.sender(),
+			source_id: self.source_id(),
+			sender_gm_level: self.sender_gm_level(),
+			message: self.message.to_lu_wstring(),
+		}
+	}
+}
+
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 #[repr(u8)]
 pub enum PrivateChatMessageResponseCode {
 	Sent,
@@ -109,7 +180,7 @@ pub enum PrivateChatMessageResponseCode {
 	ReceiverFreeTrial,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Arbitrary, Debug, PartialEq)]
 pub struct PrivateChatMessage {
 	pub chat_channel: ChatChannel,
 	pub sender_name: LuWString33,
@@ -173,3 +244,87 @@ where
 		LEWrite::write(writer, 0u16)
 	}
 }
+
+/**
+	Borrowed view of a [`PrivateChatMessage`], used by [`MessageRef`](crate::unified::MessageRef).
+
+	The message borrows from the input, and the other fields are decoded when accessed.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PrivateChatMessageRef<'a> {
+	header: &'a [u8],
+	message: LuWStrRef<'a>,
+}
+
+impl<'a> PrivateChatMessageRef<'a> {
+	const HEADER_LEN: usize = 150;
+
+	pub fn parse(data: &mut &'a [u8]) -> Res<Self> {
+		let header = take(data, Self::HEADER_LEN)?;
+		let _: ChatChannel = LERead::read(&mut &header[..])?;
+		let _: PrivateChatMessageResponseCode = LERead::read(&mut &header[149..])?;
+		let str_len: u32 = LERead::read(&mut &header[1..])?;
+		let str_len = str_len.checked_sub(1).ok_or_else(|| Error::new(InvalidData, "private chat message without null terminator"))?;
+		let message = LuWStrRef::read(data, str_len as usize)?;
+		take(data, 2)?;
+		Ok(Self { header, message })
+	}
+
+	/// Decodes the fixed-size field at `offset`, which is in bounds since the header length was checked when parsing.
+	fn field<T>(&self, offset: usize) -> T
+	where
+		for<'b> T: Deserialize<LE, &'b [u8]>,
+	{
+		LERead::read(&mut &self.header[offset..]).unwrap()
+	}
+
+	pub fn chat_channel(&self) -> ChatChannel {
+		self.field(0)
+	}
+
+	pub fn sender_name(&self) -> LuWString33 {
+		self.field(5)
+	}
+
+	pub fn sender(&self) -> ObjId {
+		self.field(71)
+	}
+
+	pub fn source_id(&self) -> u16 {
+		self.field(79)
+	}
+
+	pub fn sender_gm_level(&self) -> u8 {
+		self.field(81)
+	}
+
+	pub fn recipient_name(&self) -> LuWString33 {
+		self.field(82)
+	}
+
+	pub fn recipient_gm_level(&self) -> u8 {
+		self.field(148)
+	}
+
+	pub fn response_code(&self) -> PrivateChatMessageResponseCode {
+		self.field(149)
+	}
+
+	pub fn message(&self) -> LuWStrRef<'a> {
+		self.message
+	}
+
+	pub fn into_owned(self) -> PrivateChatMessage {
+		PrivateChatMessage {
+			chat_channel: self.chat_channel(),
+			sender_name: self.sender_name(),
+			sender: self.sender(),
+			source_id: self.source_id(),
+			sender_gm_level: self.sender_gm_level(),
+			recipient_name: self.recipient_name(),
+			recipient_gm_level: self.recipient_gm_level(),
+			response_code: self.response_code(),
+			message: self.message.to_lu_wstring(),
+		}
+	}
+}
@@ -1,11 +1,12 @@
 use endio::{Deserialize, Serialize};
-use lu_packets_derive::VariantTests;
+use lu_packets_derive::{Arbitrary, MessageDeserialize, MessageHandler, Reflect, Schema, VariantTests};
 
 use crate::common::{LuWString33, ObjId};
+use crate::world::gm::TeamLootOption;
 pub use super::{GeneralChatMessage, PrivateChatMessage};
 use super::ChatChannel;
 
-#[derive(Debug, Deserialize, PartialEq, Serialize, VariantTests)]
+#[derive(Arbitrary, Debug, MessageDeserialize, MessageHandler, PartialEq, Reflect, Schema, Serialize, VariantTests)]
 #[post_disc_padding = 9]
 #[repr(u32)]
 pub enum ChatMessage {
@@ -18,13 +19,16 @@ pub enum ChatMessage {
 	GetIgnoreList = 13,
 	TeamInvite(TeamInvite) = 15,
 	TeamInviteResponse(TeamInviteResponse) = 16,
+	TeamKick(TeamKick) = 17,
 	TeamLeave(TeamLeave) = 18,
+	TeamSetLoot(TeamSetLoot) = 19,
+	TeamSetLeader(TeamSetLeader) = 20,
 	TeamGetStatus = 21,
 	RequestMinimumChatMode(RequestMinimumChatMode) = 50,
 	RequestMinimumChatModePrivate(RequestMinimumChatModePrivate) = 51,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 #[repr(u8)]
 pub enum AddFriendResponseCode {
 	Accepted,
@@ -33,24 +37,24 @@ pub enum AddFriendResponseCode {
 	Cancelled,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct AddFriendRequest {
 	pub friend_name: LuWString33,
 	pub is_best_friend: bool,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct AddFriendResponse {
 	pub response_code: AddFriendResponseCode,
 	pub friend_name: LuWString33,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct AddIgnore {
 	pub char_name: LuWString33,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 #[repr(u8)]
 pub enum TeamInviteResponseCode {
 	Accepted,
@@ -58,28 +62,45 @@ pub enum TeamInviteResponseCode {
 	GeneralError,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct TeamInvite {
 	pub sender_name: LuWString33,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct TeamInviteResponse {
 	pub response_code: TeamInviteResponseCode,
 	pub sender: ObjId,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct TeamKick {
+	pub player_name: LuWString33,
+}
+
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct TeamLeave {
 	pub unused: LuWString33,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// Changes how loot is shared in the team, see [`TeamGetStatusResponse::loot_flag`](crate::world::gm::client::TeamGetStatusResponse::loot_flag). Only the team leader can change it.
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct TeamSetLoot {
+	#[padding = 4]
+	pub loot_option: TeamLootOption,
+}
+
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct TeamSetLeader {
+	pub player_name: LuWString33,
+}
+
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct RequestMinimumChatMode {
 	pub chat_channel: ChatChannel,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct RequestMinimumChatModePrivate {
 	pub chat_channel: ChatChannel,
 	pub recipient_name: LuWString33,
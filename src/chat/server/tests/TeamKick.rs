@@ -0,0 +1,5 @@
+ChatMessage::TeamKick(
+	TeamKick {
+		player_name: lu!("VolcanicHippieDuck"),
+	},
+)
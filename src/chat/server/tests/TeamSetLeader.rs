@@ -0,0 +1,5 @@
+ChatMessage::TeamSetLeader(
+	TeamSetLeader {
+		player_name: lu!("VolcanicHippieDuck"),
+	},
+)
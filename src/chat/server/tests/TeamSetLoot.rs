@@ -0,0 +1,5 @@
+ChatMessage::TeamSetLoot(
+	TeamSetLoot {
+		loot_option: TeamLootOption::Shared,
+	},
+)
@@ -0,0 +1,198 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::io::{Error as IoError, Read, Result as Res};
+
+use endio::{Deserialize, LE, LERead};
+use endio_bit::BEBitReader;
+
+use crate::raknet::client::replica::{ComponentConstruction, ComponentSerialization, ReplicaContext};
+use crate::world::{Lot, LuNameValue};
+
+/// Error returned by [`FromBytesExact::from_bytes_exact`] and [`from_bytes_exact_with`].
+#[derive(Debug)]
+pub enum DecodeError {
+	/// The data couldn't be decoded. `offset` is the number of bytes read when the error occurred.
+	Invalid { offset: usize, error: IoError },
+	/**
+		The data was decoded, but not fully consumed.
+
+		`bit_offset` is where decoding stopped. `rest` holds the unread data, starting with the byte containing `bit_offset` if decoding stopped within a byte.
+	*/
+	Trailing { bit_offset: usize, rest: Vec<u8> },
+}
+
+impl Display for DecodeError {
+	fn fmt(&self, f: &mut Formatter) -> FmtResult {
+		match self {
+			Self::Invalid { offset, error } => write!(f, "decoding failed at byte {}: {}", offset, error),
+			Self::Trailing { bit_offset, rest } => write!(f, "unread data at byte {}, bit {}: {:02x?}", bit_offset / 8, bit_offset % 8, rest),
+		}
+	}
+}
+
+impl Error for DecodeError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::Invalid { error, .. } => Some(error),
+			Self::Trailing { .. } => None,
+		}
+	}
+}
+
+/// Strict decoding, which fails if the data isn't fully consumed.
+pub trait FromBytesExact: Sized {
+	fn from_bytes_exact(data: &[u8]) -> Result<Self, DecodeError>;
+}
+
+impl<T> FromBytesExact for T
+where
+	for<'a> T: Deserialize<LE, &'a [u8]>,
+{
+	fn from_bytes_exact(data: &[u8]) -> Result<Self, DecodeError> {
+		let mut reader = data;
+		let val = LERead::read(&mut reader).map_err(|error| DecodeError::Invalid { offset: data.len() - reader.len(), error })?;
+		if !reader.is_empty() {
+			return Err(DecodeError::Trailing { bit_offset: (data.len() - reader.len()) * 8, rest: reader.to_vec() });
+		}
+		Ok(val)
+	}
+}
+
+/**
+	Reader used by [`from_bytes_exact_with`], forwarding component lookups to the wrapped context.
+
+	Keeps track of the bits left over at the end of replica payloads, so that they can be accounted for.
+*/
+pub struct ExactReader<'a, C> {
+	data: &'a [u8],
+	ctx: C,
+	trailing_bits: (u8, u8),
+}
+
+impl<C> Read for ExactReader<'_, C> {
+	fn read(&mut self, buf: &mut [u8]) -> Res<usize> {
+		Read::read(&mut self.data, buf)
+	}
+}
+
+impl<C: ReplicaContext> ReplicaContext for ExactReader<'_, C> {
+	fn get_comp_constructions<R: Read>(&mut self, network_id: u16, lot: Lot, config: &Option<LuNameValue>) -> Vec<fn(&mut BEBitReader<R>) -> Res<Box<dyn ComponentConstruction>>> {
+		self.ctx.get_comp_constructions(network_id, lot, config)
+	}
+
+	fn get_comp_serializations<R: Read>(&mut self, network_id: u16) -> Vec<fn(&mut BEBitReader<R>) -> Res<Box<dyn ComponentSerialization>>> {
+		self.ctx.get_comp_serializations(network_id)
+	}
+
+	fn trailing_bits(&mut self, count: u8, value: u8) {
+		self.trailing_bits = (count, value);
+	}
+
+	fn network_version(&self) -> u32 {
+		self.ctx.network_version()
+	}
+}
+
+/**
+	Strict decoding of types that need a [`ReplicaContext`], like [`world::client::Message`](crate::world::client::Message).
+
+	Bit-level replica payloads are accounted for to the bit: their trailing bits have to be zero padding.
+*/
+pub fn from_bytes_exact_with<T, C>(data: &[u8], ctx: C) -> Result<T, DecodeError>
+where
+	for<'a> T: Deserialize<LE, ExactReader<'a, C>>,
+{
+	let mut reader = ExactReader { data, ctx, trailing_bits: (0, 0) };
+	let val = LERead::read(&mut reader).map_err(|error| DecodeError::Invalid { offset: data.len() - reader.data.len(), error })?;
+	let consumed = data.len() - reader.data.len();
+	let (count, value) = reader.trailing_bits;
+	if value != 0 {
+		return Err(DecodeError::Trailing { bit_offset: consumed * 8 - count as usize, rest: data[consumed - 1..].to_vec() });
+	}
+	if consumed != data.len() {
+		return Err(DecodeError::Trailing { bit_offset: consumed * 8, rest: reader.data.to_vec() });
+	}
+	Ok(val)
+}
+
+#[cfg(test)]
+mod tests {
+	use endio::LEWrite;
+
+	use super::*;
+	use crate::world::server::LevelLoadComplete;
+	use crate::raknet::client::replica::ReplicaConstruction;
+
+	#[test]
+	fn exact() {
+		let data = [0xe8, 0x03, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00];
+		assert!(LevelLoadComplete::from_bytes_exact(&data).is_ok());
+	}
+
+	#[test]
+	fn trailing() {
+		let data = [0xe8, 0x03, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2a];
+		match LevelLoadComplete::from_bytes_exact(&data) {
+			Err(DecodeError::Trailing { bit_offset, rest }) => {
+				assert_eq!(bit_offset, 64);
+				assert_eq!(rest, [0x2a]);
+			}
+			x => panic!("{:?}", x),
+		}
+	}
+
+	#[test]
+	fn invalid() {
+		let data = [0xe8, 0x03, 0x01, 0x00, 0x00];
+		match LevelLoadComplete::from_bytes_exact(&data) {
+			Err(DecodeError::Invalid { offset, .. }) => assert_eq!(offset, 4),
+			x => panic!("{:?}", x),
+		}
+	}
+
+	struct NoComponents;
+
+	impl ReplicaContext for NoComponents {
+		fn get_comp_constructions<R: Read>(&mut self, _network_id: u16, _lot: Lot, _config: &Option<LuNameValue>) -> Vec<fn(&mut BEBitReader<R>) -> Res<Box<dyn ComponentConstruction>>> {
+			vec![]
+		}
+
+		fn get_comp_serializations<R: Read>(&mut self, _network_id: u16) -> Vec<fn(&mut BEBitReader<R>) -> Res<Box<dyn ComponentSerialization>>> {
+			vec![]
+		}
+	}
+
+	#[test]
+	fn trailing_bits() {
+		let constr = ReplicaConstruction {
+			network_id: 1,
+			object_id: 2,
+			lot: 3,
+			name: lu!("a"),
+			time_since_created_on_server: 0,
+			config: None,
+			is_trigger: false,
+			spawner_id: None,
+			spawner_node_id: None,
+			scale: None,
+			world_state: None,
+			gm_level: None,
+			parent_child_info: None,
+			components: vec![],
+		};
+		let mut data = vec![];
+		data.write(&constr).unwrap();
+		// 177 bits, followed by 7 bits of padding
+		assert_eq!(data.len(), 23);
+		assert!(from_bytes_exact_with::<ReplicaConstruction, _>(&data, NoComponents).is_ok());
+
+		*data.last_mut().unwrap() |= 1;
+		match from_bytes_exact_with::<ReplicaConstruction, _>(&data, NoComponents) {
+			Err(DecodeError::Trailing { bit_offset, rest }) => {
+				assert_eq!(bit_offset, 177);
+				assert_eq!(rest.len(), 1);
+			}
+			x => panic!("{:?}", x),
+		}
+	}
+}
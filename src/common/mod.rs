@@ -1,5 +1,8 @@
 //! Shared types.
+mod exact;
+mod size;
 mod str;
+mod version;
 
 use std::convert::{TryFrom, TryInto};
 use std::fmt::{Formatter, Debug};
@@ -8,15 +11,19 @@ use std::io::Result as Res;
 use std::marker::PhantomData;
 
 use endio::{Deserialize, LE, LERead, LEWrite, Serialize};
+use lu_packets_derive::{Arbitrary, Reflect, Schema};
 
+pub use self::exact::*;
+pub use self::size::*;
 pub use self::str::*;
+pub use self::version::*;
 
 /**
 	Wraps a `Vec` with a length type so the vector can be (de-)serialized.
 
 	Note: the length type is not checked and the `Vec` still uses `usize` internally. Handle with care.
 */
-#[derive(Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct LVec<L, T>(Vec<T>, PhantomData<L>);
 
 impl<L, T> LVec<L, T> {
@@ -99,6 +106,32 @@ where
 	}
 }
 
+/// The length is limited to what fits in the length type.
+#[cfg(feature = "arbitrary")]
+impl<'a, L: TryFrom<usize>, T: arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for LVec<L, T> {
+	fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+		let mut len = u.arbitrary_len::<T>()?;
+		if L::try_from(len).is_err() {
+			// the largest length that fits
+			let mut fits = 0;
+			while fits < len {
+				let mid = fits + (len - fits + 1) / 2;
+				if L::try_from(mid).is_ok() {
+					fits = mid;
+				} else {
+					len = mid - 1;
+				}
+			}
+			len = fits;
+		}
+		let mut vec = Vec::with_capacity(len);
+		for _ in 0..len {
+			vec.push(T::arbitrary(u)?);
+		}
+		Ok(vec.into())
+	}
+}
+
 impl<L, T> std::ops::Deref for LVec<L, T> {
 	type Target = Vec<T>;
 
@@ -121,15 +154,33 @@ impl<L, T> From<Vec<T>> for LVec<L, T> {
 	}
 }
 
-#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[derive(Arbitrary, Debug, PartialEq, Deserialize, Reflect, Schema, Serialize)]
 #[repr(u16)]
 pub enum ServiceId {
 	General = 0,
 	Auth = 1,
 	Chat = 2,
+	/// Server-to-server messages from world servers to the chat server, see [`internal::chat`](crate::internal::chat).
+	ChatInternal = 3,
 	World = 4,
 	Client = 5,
+	/// Server-to-server messages to and from the master server, see [`internal`](crate::internal).
+	Master = 6,
 }
 
 pub type ObjId = u64;
 pub const OBJID_EMPTY: u64 = 0;
+
+/// Object ID of a guild.
+#[derive(Arbitrary, Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Reflect, Schema, Serialize)]
+pub struct GuildId(pub ObjId);
+
+/// Splits off the first `len` bytes of `data`, for borrowed decoding.
+pub(crate) fn take<'a>(data: &mut &'a [u8], len: usize) -> Res<&'a [u8]> {
+	if data.len() < len {
+		return Err(std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "data too short"));
+	}
+	let (taken, rest) = data.split_at(len);
+	*data = rest;
+	Ok(taken)
+}
@@ -0,0 +1,322 @@
+use std::cell::Cell;
+use std::convert::TryFrom;
+use std::io::{Result as Res, Write};
+use std::mem::size_of;
+
+use endio::{LE, LEWrite, Serialize};
+use endio_bit::BEBitWriter;
+
+use crate::raknet::client::replica::ReplicaS;
+use super::{AsciiChar, LVec, Ucs2Char, Versioned, NETWORK_VERSION_LIVE};
+
+/**
+	Writer that discards the data, only counting the bytes written.
+
+	Like [`File`](std::fs::File), a shared reference is a writer too, so the count can be read while a bit writer holds on to it.
+*/
+#[derive(Debug, Default)]
+pub struct SizeCounter(Cell<usize>);
+
+impl SizeCounter {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Number of bytes written so far.
+	pub fn count(&self) -> usize {
+		self.0.get()
+	}
+}
+
+impl Write for &SizeCounter {
+	fn write(&mut self, buf: &[u8]) -> Res<usize> {
+		self.0.set(self.0.get() + buf.len());
+		Ok(buf.len())
+	}
+
+	fn flush(&mut self) -> Res<()> {
+		Ok(())
+	}
+}
+
+impl Write for SizeCounter {
+	fn write(&mut self, buf: &[u8]) -> Res<usize> {
+		Write::write(&mut &*self, buf)
+	}
+
+	fn flush(&mut self) -> Res<()> {
+		Ok(())
+	}
+}
+
+/**
+	Returns the number of bits written by `f`.
+
+	The bits in the last byte are found by filling it up.
+*/
+fn count_bits(f: impl FnOnce(&mut BEBitWriter<Versioned<Vec<u8>>>) -> Res<()>) -> Res<usize> {
+	let mut writer = BEBitWriter::new(Versioned::new(vec![], NETWORK_VERSION_LIVE));
+	f(&mut writer)?;
+	let bytes = writer.get_ref().get_ref().len();
+	let mut fill = 0;
+	while writer.get_ref().get_ref().len() == bytes {
+		writer.write_bit(false)?;
+		fill += 1;
+	}
+	Ok(bytes * 8 + 8 - fill)
+}
+
+/**
+	Number of bytes written when serializing, for [`Encode::serialized_size`].
+
+	Game messages compute it from their fields, as do the primitive, string and [`LVec`](super::LVec) types they're made of, other types count the bytes by serializing.
+*/
+pub(crate) trait ByteSize {
+	fn byte_size(&self) -> Res<usize>;
+}
+
+impl<T> ByteSize for T
+where
+	for<'a> &'a T: Serialize<LE, SizeCounter>,
+{
+	default fn byte_size(&self) -> Res<usize> {
+		let mut counter = SizeCounter::new();
+		LEWrite::write(&mut counter, self)?;
+		Ok(counter.count())
+	}
+}
+
+/**
+	Size calculation and encoding into existing buffers, for all serializable types.
+
+	None of these allocate, apart from types which need to transform their data before writing, like the compressed [`LuNameValue`](crate::world::LuNameValue), and replicas, whose components are written to an intermediate buffer.
+*/
+pub trait Encode {
+	/// Number of bytes written when serializing.
+	fn serialized_size(&self) -> Res<usize>;
+
+	/// Serializes into `buf`, returning the number of bytes written. Fails with [`WriteZero`](std::io::ErrorKind::WriteZero) if `buf` is too small.
+	fn encode_into(&self, buf: &mut [u8]) -> Res<usize>;
+
+	/// Serializes into `buf`, replacing its contents. The buffer's capacity is reused, so a buffer kept around for repeated encoding only grows when a larger message comes along.
+	fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Res<()>;
+}
+
+impl<T> Encode for T
+where
+	for<'a, 'b> &'a T: Serialize<LE, SizeCounter> + Serialize<LE, &'b mut [u8]> + Serialize<LE, Vec<u8>>,
+{
+	fn serialized_size(&self) -> Res<usize> {
+		self.byte_size()
+	}
+
+	fn encode_into(&self, buf: &mut [u8]) -> Res<usize> {
+		let len = buf.len();
+		let mut writer = buf;
+		LEWrite::write(&mut writer, self)?;
+		Ok(len - writer.len())
+	}
+
+	fn encode_into_vec(&self, buf: &mut Vec<u8>) -> Res<()> {
+		buf.clear();
+		LEWrite::write(buf, self)
+	}
+}
+
+/**
+	Size calculation for types serialized at the bit level, like replica components.
+
+	Bits are counted exactly for the live network version, without the padding to the next byte. Required by [`ComponentConstruction`](crate::raknet::client::replica::ComponentConstruction) and [`ComponentSerialization`](crate::raknet::client::replica::ComponentSerialization), so it's available on boxed components too.
+
+	Types deriving `ReplicaSerde` compute it from their fields, like primitives and options do, other types count the bits by serializing.
+*/
+pub trait BitSize {
+	fn bit_size(&self) -> Res<usize>;
+}
+
+impl<T> BitSize for T
+where
+	for<'a> &'a T: Serialize<LE, BEBitWriter<Versioned<Vec<u8>>>>,
+{
+	default fn bit_size(&self) -> Res<usize> {
+		count_bits(|writer| ReplicaS::serialize(self, writer))
+	}
+}
+
+impl BitSize for bool {
+	fn bit_size(&self) -> Res<usize> {
+		Ok(1)
+	}
+}
+
+impl<T: BitSize> BitSize for Option<T>
+where
+	for<'a> &'a T: Serialize<LE, BEBitWriter<Versioned<Vec<u8>>>>,
+{
+	fn bit_size(&self) -> Res<usize> {
+		Ok(1 + match self {
+			Some(x) => x.bit_size()?,
+			None => 0,
+		})
+	}
+}
+
+macro_rules! prim_size {
+	($($typ:ty),*) => {
+		$(
+			impl ByteSize for $typ {
+				fn byte_size(&self) -> Res<usize> {
+					Ok(size_of::<$typ>())
+				}
+			}
+
+			impl BitSize for $typ {
+				fn bit_size(&self) -> Res<usize> {
+					Ok(size_of::<$typ>() * 8)
+				}
+			}
+		)*
+	};
+}
+
+prim_size!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+impl ByteSize for AsciiChar {
+	fn byte_size(&self) -> Res<usize> {
+		Ok(1)
+	}
+}
+
+impl ByteSize for Ucs2Char {
+	fn byte_size(&self) -> Res<usize> {
+		Ok(2)
+	}
+}
+
+impl<L, T> ByteSize for LVec<L, T>
+where
+	L: TryFrom<usize> + Serialize<LE, SizeCounter>,
+	for<'a> &'a T: Serialize<LE, SizeCounter>,
+{
+	fn byte_size(&self) -> Res<usize> {
+		let mut size = size_of::<L>();
+		for x in self.iter() {
+			size += x.byte_size()?;
+		}
+		Ok(size)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::io::ErrorKind::WriteZero;
+
+	use super::*;
+	use crate::raknet::client::replica::simple_physics::SimplePhysicsSerialization;
+	use crate::world::ZoneId;
+	use crate::world::server::LevelLoadComplete;
+
+	#[test]
+	fn serialized_size() {
+		let msg = LevelLoadComplete { zone_id: ZoneId { map_id: 1000, instance_id: 1, clone_id: 0 } };
+		assert_eq!(msg.serialized_size().unwrap(), 8);
+		let mut buf = [0; 8];
+		assert_eq!(msg.encode_into(&mut buf).unwrap(), 8);
+		assert_eq!(buf, [0xe8, 0x03, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00]);
+		assert_eq!(msg.encode_into(&mut buf[..7]).unwrap_err().kind(), WriteZero);
+		let mut vec = vec![0x2a];
+		msg.encode_into_vec(&mut vec).unwrap();
+		assert_eq!(vec, buf);
+	}
+
+	#[test]
+	fn bit_size() {
+		let ser = SimplePhysicsSerialization { velocity_info: None, motion_type: None, position_rotation_info: None };
+		assert_eq!(ser.bit_size().unwrap(), 3);
+	}
+
+	#[test]
+	fn game_message_size() {
+		use crate::world::gm::RemoveItemFromInventory;
+
+		let msg = RemoveItemFromInventory::builder().extra_info(lnv! { "key": 1i32, }).obj_id(42).stack_count(3).build();
+		let mut data = vec![];
+		LEWrite::write(&mut data, &msg).unwrap();
+		assert_eq!(msg.serialized_size().unwrap(), data.len());
+	}
+
+	/// Checks the sizes computed by the derives against the number of bytes or bits written.
+	#[cfg(feature = "arbitrary")]
+	#[test]
+	fn derived_sizes() {
+		use arbitrary::{Arbitrary, Unstructured};
+
+		use crate::raknet::client::replica::{ComponentWriter, ReplicaConstruction, ReplicaSerialization};
+		use crate::world::gm::RemoveItemFromInventory;
+		use crate::world::gm::client::PlayFxEffect;
+		use crate::world::gm::server::StartSkill;
+
+		/// Counts the bytes written through it, since components write to a `dyn Write`.
+		struct ByteCounter<'a>(&'a Cell<usize>);
+
+		impl Write for ByteCounter<'_> {
+			fn write(&mut self, buf: &[u8]) -> Res<usize> {
+				self.0.set(self.0.get() + buf.len());
+				Ok(buf.len())
+			}
+
+			fn flush(&mut self) -> Res<()> {
+				Ok(())
+			}
+		}
+
+		/// Like [`count_bits`], for a component writer.
+		fn component_bits(f: impl FnOnce(&mut ComponentWriter) -> Res<()>) -> usize {
+			let bytes = Cell::new(0);
+			let mut counter = ByteCounter(&bytes);
+			let mut writer = BEBitWriter::new(Versioned::new(&mut counter as &mut dyn Write, NETWORK_VERSION_LIVE));
+			f(&mut writer).unwrap();
+			let written = bytes.get();
+			let mut fill = 0;
+			while bytes.get() == written {
+				writer.write_bit(false).unwrap();
+				fill += 1;
+			}
+			written * 8 + 8 - fill
+		}
+
+		fn check_message<T: for<'a> Arbitrary<'a> + Encode>(data: &[u8])
+		where
+			for<'a> &'a T: Serialize<LE, Vec<u8>>,
+		{
+			let msg = T::arbitrary(&mut Unstructured::new(data)).unwrap();
+			let mut written = vec![];
+			LEWrite::write(&mut written, &msg).unwrap();
+			assert_eq!(msg.serialized_size().unwrap(), written.len());
+		}
+
+		let mut seed = 0x2545f4914f6cdd1du64;
+		let mut data = vec![0; 4096];
+		for _ in 0..50 {
+			for b in data.iter_mut() {
+				// xorshift
+				seed ^= seed << 13;
+				seed ^= seed >> 7;
+				seed ^= seed << 17;
+				*b = seed as u8;
+			}
+			check_message::<RemoveItemFromInventory>(&data);
+			check_message::<PlayFxEffect>(&data);
+			check_message::<StartSkill>(&data);
+
+			let constr = ReplicaConstruction::arbitrary(&mut Unstructured::new(&data)).unwrap();
+			for comp in &constr.components {
+				assert_eq!(comp.bit_size().unwrap(), component_bits(|writer| comp.ser(writer)), "{:?}", comp);
+			}
+			let ser = ReplicaSerialization::arbitrary(&mut Unstructured::new(&data)).unwrap();
+			for comp in &ser.components {
+				assert_eq!(comp.bit_size().unwrap(), component_bits(|writer| comp.ser(writer)), "{:?}", comp);
+			}
+		}
+	}
+}
@@ -44,6 +44,26 @@ macro_rules! abstract_lu_str {
 			}
 		}
 
+		// at most $n - 1 characters, followed by the null terminator
+		#[cfg(feature = "arbitrary")]
+		impl<'a> arbitrary::Arbitrary<'a> for $name {
+			fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+				let len = u.int_in_range(0..=$n - 1)?;
+				let mut chars = [$null; $n];
+				for c in &mut chars[..len] {
+					*c = arbitrary::Arbitrary::arbitrary(u)?;
+				}
+				Ok(Self(chars))
+			}
+		}
+
+		#[cfg(feature = "schema")]
+		impl crate::schema::Schema for $name {
+			fn schema() -> crate::schema::Type {
+				crate::schema::Type::Array { elem: Box::new(<$c as crate::schema::Schema>::schema()), len: $n }
+			}
+		}
+
 		impl<R: Read> Deserialize<LE, R> for $name {
 			fn deserialize(reader: &mut R) -> Res<Self> {
 				let mut bytes = [0u8; $n * std::mem::size_of::<$c>()];
@@ -90,6 +110,20 @@ macro_rules! lu_str {
 				Self::try_from(&string[..])
 			}
 		}
+
+		#[cfg(feature = "reflect")]
+		impl crate::reflect::ReflectValue for $name {
+			fn to_value(&self) -> crate::reflect::Value {
+				crate::reflect::Value::String((&**self).to_string())
+			}
+
+			fn from_value(value: crate::reflect::Value) -> Result<Self, crate::reflect::Value> {
+				match value {
+					crate::reflect::Value::String(x) => Self::try_from(x.as_bytes()).map_err(|_| crate::reflect::Value::String(x)),
+					x => Err(x),
+				}
+			}
+		}
 	};
 }
 
@@ -115,6 +149,20 @@ macro_rules! lu_wstr {
 				String::from_utf16(unsafe { &*(&**wstr as *const [Ucs2Char] as *const [<Ucs2Char as LuChar>::Int]) }).unwrap()
 			}
 		}
+
+		#[cfg(feature = "reflect")]
+		impl crate::reflect::ReflectValue for $name {
+			fn to_value(&self) -> crate::reflect::Value {
+				crate::reflect::Value::String(self.into())
+			}
+
+			fn from_value(value: crate::reflect::Value) -> Result<Self, crate::reflect::Value> {
+				match value {
+					crate::reflect::Value::String(x) => Self::try_from(&x[..]).map_err(|_| crate::reflect::Value::String(x)),
+					x => Err(x),
+				}
+			}
+		}
 	};
 }
 
@@ -22,6 +22,24 @@ pub struct AsciiChar(u8);
 #[derive(Clone, Copy, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct Ucs2Char(u16);
 
+/// Printable characters only, which keeps generated strings valid in text formats like [`LuNameValue`](crate::world::LuNameValue)'s.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AsciiChar {
+	fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+		Ok(Self(u.int_in_range(0x20..=0x7e)?))
+	}
+}
+
+/// Printable characters from the basic multilingual plane, excluding DEL, the C1 controls and surrogates.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Ucs2Char {
+	fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+		const CONTROLS: u16 = 0x9f - 0x7f + 1;
+		let x = u.int_in_range(0x20..=0xd7ff - CONTROLS)?;
+		Ok(Self(if x < 0x7f { x } else { x + CONTROLS }))
+	}
+}
+
 impl LuChar for AsciiChar {
 	type Int = u8;
 	type Error = AsciiError;
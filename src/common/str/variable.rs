@@ -1,7 +1,9 @@
 use std::convert::{TryFrom};
+use std::fmt::Write;
+use std::io::Result as Res;
 use std::marker::PhantomData;
 
-use crate::common::LVec;
+use crate::common::{take, LVec};
 use super::{AsciiChar, AsciiError, LuStrExt, LuWStr, Ucs2Char, Ucs2Error};
 
 pub type LuVarString<L> = LVec<L, AsciiChar>;
@@ -64,3 +66,83 @@ impl<L> TryFrom<&str> for LuVarWString<L> {
 		Ok(Self(chars, PhantomData))
 	}
 }
+
+/**
+	A wide string borrowed from encoded data, used by [`MessageRef`](crate::unified::MessageRef).
+
+	Holds the encoded UTF-16 bytes, which may not be aligned, and decodes characters when they're accessed.
+*/
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct LuWStrRef<'a>(&'a [u8]);
+
+impl<'a> LuWStrRef<'a> {
+	/// Borrows a string of `len` characters from the start of `data`.
+	pub(crate) fn read(data: &mut &'a [u8], len: usize) -> Res<Self> {
+		Ok(Self(take(data, len * 2)?))
+	}
+
+	/// The encoded string, for forwarding it without decoding.
+	pub fn as_bytes(&self) -> &'a [u8] {
+		self.0
+	}
+
+	pub fn len(&self) -> usize {
+		self.0.len() / 2
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	pub fn chars(&self) -> impl Iterator<Item = u16> + 'a {
+		self.0.chunks_exact(2).map(|x| u16::from_le_bytes([x[0], x[1]]))
+	}
+
+	/// Copies the string into an owned one.
+	pub fn to_lu_wstring<L>(&self) -> LuVarWString<L> {
+		self.chars().map(Ucs2Char).collect::<Vec<_>>().into()
+	}
+}
+
+impl std::fmt::Debug for LuWStrRef<'_> {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+		write!(f, "{:?}", self.to_string())
+	}
+}
+
+impl std::fmt::Display for LuWStrRef<'_> {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+		for c in std::char::decode_utf16(self.chars()) {
+			f.write_char(c.unwrap_or(std::char::REPLACEMENT_CHARACTER))?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(feature = "reflect")]
+impl<L> crate::reflect::ReflectValue for LuVarString<L> {
+	fn to_value(&self) -> crate::reflect::Value {
+		crate::reflect::Value::String(self.into())
+	}
+
+	fn from_value(value: crate::reflect::Value) -> Result<Self, crate::reflect::Value> {
+		match value {
+			crate::reflect::Value::String(x) => Self::try_from(x.as_bytes()).map_err(|_| crate::reflect::Value::String(x)),
+			x => Err(x),
+		}
+	}
+}
+
+#[cfg(feature = "reflect")]
+impl<L> crate::reflect::ReflectValue for LuVarWString<L> {
+	fn to_value(&self) -> crate::reflect::Value {
+		crate::reflect::Value::String(self.into())
+	}
+
+	fn from_value(value: crate::reflect::Value) -> Result<Self, crate::reflect::Value> {
+		match value {
+			crate::reflect::Value::String(x) => Self::try_from(&x[..]).map_err(|_| crate::reflect::Value::String(x)),
+			x => Err(x),
+		}
+	}
+}
@@ -0,0 +1,211 @@
+use std::io::{Read, Result as Res, Write};
+
+use endio_bit::{BEBitReader, BEBitWriter};
+
+use crate::raknet::client::replica::ReplicaContext;
+
+/// Network version of the live client, see [`Handshake::network_version`](crate::general::server::Handshake::network_version).
+pub const NETWORK_VERSION_LIVE: u32 = 171022;
+
+/**
+	Reads or writes messages for a specific network version, like one from a client's [`Handshake`](crate::general::server::Handshake).
+
+	Fields and variants marked with `#[since(version)]` are only read and written for that version and later. Readers and writers that aren't wrapped use [`NETWORK_VERSION_LIVE`], except readers with a [`ReplicaContext`], which use [`ReplicaContext::network_version`].
+*/
+#[derive(Debug)]
+pub struct Versioned<T> {
+	inner: T,
+	version: u32,
+}
+
+impl<T> Versioned<T> {
+	pub fn new(inner: T, version: u32) -> Self {
+		Self { inner, version }
+	}
+
+	pub fn version(&self) -> u32 {
+		self.version
+	}
+
+	pub fn get_ref(&self) -> &T {
+		&self.inner
+	}
+
+	pub fn get_mut(&mut self) -> &mut T {
+		&mut self.inner
+	}
+
+	pub fn into_inner(self) -> T {
+		self.inner
+	}
+}
+
+impl<R: Read> Read for Versioned<R> {
+	fn read(&mut self, buf: &mut [u8]) -> Res<usize> {
+		self.inner.read(buf)
+	}
+}
+
+impl<W: Write> Write for Versioned<W> {
+	fn write(&mut self, buf: &[u8]) -> Res<usize> {
+		self.inner.write(buf)
+	}
+
+	fn flush(&mut self) -> Res<()> {
+		self.inner.flush()
+	}
+}
+
+/// Network version of a reader or writer, which `#[since]` is checked against. Bit readers and writers forward to the reader or writer they wrap.
+pub(crate) trait NetworkVersion {
+	fn network_version(&self) -> u32;
+}
+
+impl<T: ?Sized> NetworkVersion for T {
+	default fn network_version(&self) -> u32 {
+		NETWORK_VERSION_LIVE
+	}
+}
+
+impl<T> NetworkVersion for Versioned<T> {
+	fn network_version(&self) -> u32 {
+		self.version
+	}
+}
+
+/// Bit readers and writers are often created over `&mut Versioned`. References to contexts are covered by the [`ReplicaContext`] impl for `&mut C`; a blanket impl for all `&mut T` would overlap with it.
+impl<T> NetworkVersion for &mut Versioned<T> {
+	fn network_version(&self) -> u32 {
+		self.version
+	}
+}
+
+impl<T: ReplicaContext> NetworkVersion for T {
+	fn network_version(&self) -> u32 {
+		ReplicaContext::network_version(self)
+	}
+}
+
+/// Creates a bit reader over `reader` that keeps its network version. `BEBitReader::new(reader)` only does so if `reader` is a [`Versioned`].
+pub(crate) fn bit_reader<R: Read>(reader: &mut R) -> BEBitReader<Versioned<&mut R>> {
+	let version = NetworkVersion::network_version(&*reader);
+	#[cfg(feature = "trace")]
+	crate::trace::align();
+	BEBitReader::new(Versioned::new(reader, version))
+}
+
+/// Reads a bit, and records it for the bit offsets of a [trace](crate::trace).
+pub(crate) fn read_bit<R: Read>(reader: &mut BEBitReader<R>) -> Res<bool> {
+	let bit = reader.read_bit()?;
+	#[cfg(feature = "trace")]
+	crate::trace::read_bits(1);
+	Ok(bit)
+}
+
+/// Creates a bit writer over `writer` that keeps its network version, see [`bit_reader`].
+pub(crate) fn bit_writer<W: Write>(writer: &mut W) -> BEBitWriter<Versioned<&mut W>> {
+	let version = NetworkVersion::network_version(&*writer);
+	BEBitWriter::new(Versioned::new(writer, version))
+}
+
+impl<R: Read> NetworkVersion for BEBitReader<R> {
+	fn network_version(&self) -> u32 {
+		NetworkVersion::network_version(self.get_ref())
+	}
+}
+
+impl<W: Write> NetworkVersion for BEBitWriter<W> {
+	fn network_version(&self) -> u32 {
+		NetworkVersion::network_version(self.get_ref())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use endio::{LERead, LEWrite};
+	use lu_packets_derive::ReplicaSerde;
+
+	use super::*;
+
+	#[derive(Debug, PartialEq, ReplicaSerde)]
+	struct Gated {
+		a: u8,
+		#[since(171022)]
+		b: u8,
+	}
+
+	fn write(gated: &Gated, version: u32) -> Vec<u8> {
+		let mut data = vec![];
+		let mut writer = BEBitWriter::new(Versioned::new(&mut data, version));
+		LEWrite::write(&mut writer, gated).unwrap();
+		writer.flush().unwrap();
+		drop(writer);
+		data
+	}
+
+	fn read(data: &[u8], version: u32) -> Gated {
+		let mut reader = BEBitReader::new(Versioned::new(data, version));
+		LERead::read(&mut reader).unwrap()
+	}
+
+	#[test]
+	fn since() {
+		let gated = Gated { a: 1, b: 2 };
+		assert_eq!(write(&gated, NETWORK_VERSION_LIVE), [1, 2]);
+		assert_eq!(read(&[1, 2], NETWORK_VERSION_LIVE), gated);
+		assert_eq!(write(&gated, 171021), [1]);
+		assert_eq!(read(&[1], 171021), Gated { a: 1, b: 0 });
+	}
+
+	#[test]
+	fn wrapped_reference() {
+		let mut versioned = Versioned::new(&[1u8][..], 171021);
+		let mut reader = BEBitReader::new(&mut versioned);
+		assert_eq!(LERead::read::<Gated>(&mut reader).unwrap(), Gated { a: 1, b: 0 });
+	}
+
+	#[test]
+	fn nested_bit_reader() {
+		let mut versioned = Versioned::new(&[1u8][..], 171021);
+		let mut outer = BEBitReader::new(&mut versioned);
+		let mut reader = bit_reader(&mut outer);
+		assert_eq!(LERead::read::<Gated>(&mut reader).unwrap(), Gated { a: 1, b: 0 });
+		let mut data = vec![];
+		let mut outer = BEBitWriter::new(Versioned::new(&mut data, 171021));
+		let mut writer = bit_writer(&mut outer);
+		LEWrite::write(&mut writer, &Gated { a: 1, b: 2 }).unwrap();
+		writer.flush().unwrap();
+		drop(writer);
+		drop(outer);
+		assert_eq!(data, [1]);
+	}
+
+	#[test]
+	fn context() {
+		struct Context<'a>(&'a [u8]);
+
+		impl Read for Context<'_> {
+			fn read(&mut self, buf: &mut [u8]) -> Res<usize> {
+				Read::read(&mut self.0, buf)
+			}
+		}
+
+		impl ReplicaContext for Context<'_> {
+			fn get_comp_constructions<R: Read>(&mut self, _network_id: u16, _lot: crate::world::Lot, _config: &Option<crate::world::LuNameValue>) -> Vec<fn(&mut BEBitReader<R>) -> Res<Box<dyn crate::raknet::client::replica::ComponentConstruction>>> {
+				vec![]
+			}
+
+			fn get_comp_serializations<R: Read>(&mut self, _network_id: u16) -> Vec<fn(&mut BEBitReader<R>) -> Res<Box<dyn crate::raknet::client::replica::ComponentSerialization>>> {
+				vec![]
+			}
+
+			fn network_version(&self) -> u32 {
+				171021
+			}
+		}
+
+		let mut context = Context(&[1]);
+		let mut reader = BEBitReader::new(&mut context);
+		assert_eq!(LERead::read::<Gated>(&mut reader).unwrap(), Gated { a: 1, b: 0 });
+	}
+}
@@ -1,11 +1,11 @@
 //! Client-received general messages.
 use endio::{Deserialize, Serialize};
-use lu_packets_derive::VariantTests;
+use lu_packets_derive::{Arbitrary, MessageDeserialize, Reflect, Schema, VariantTests};
 
 use crate::common::ServiceId;
 
 /// Client-received general messages.
-#[derive(Debug, Deserialize, PartialEq, Serialize, VariantTests)]
+#[derive(Arbitrary, Debug, MessageDeserialize, PartialEq, Reflect, Schema, Serialize, VariantTests)]
 #[post_disc_padding = 1]
 #[repr(u32)]
 pub enum GeneralMessage {
@@ -28,7 +28,7 @@ pub enum GeneralMessage {
 	### Notes
 	As the version confirm process was designed with more than just client-server in mind, it sends the server's network version and service id as well, even though this isn't really needed by the client (even the service id isn't needed, since you usually only connect to auth once, and it's the very first connection). This could be simplified if the protocol is ever revised.
 */
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 #[trailing_padding = 41]
 pub struct Handshake {
 	/// The network protocol version of the server. For servers compatible with live, this is `171022`. This was relevant mainly back when LU was actively updated. Server projects making modifications to the network protocol should set this to a different value.
@@ -53,7 +53,7 @@ pub struct Handshake {
 	### Notes
 	You can be disconnected without receiving this packet, for example when your connection is lost. The server is also not obligated to send this packet and may disconnect you without doing so.
 */
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 #[repr(u32)]
 pub enum DisconnectNotify {
 	/// Unspecified disconnect reason.
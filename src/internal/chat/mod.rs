@@ -0,0 +1,64 @@
+//! Internal messages received by the chat server from world servers.
+use endio::{Deserialize, Serialize};
+use lu_packets_derive::{Arbitrary, MessageDeserialize, Reflect, Schema, VariantTests};
+
+use crate::common::{LuVarString, LuWString33, ObjId, ServiceId};
+use crate::world::ZoneId;
+
+pub use crate::general::server::GeneralMessage;
+
+/// All messages that can be received by a chat server from a world server.
+pub type Message = crate::raknet::server::Message<LuMessage>;
+
+/// All LU messages that can be received by a chat server from a world server.
+#[derive(Arbitrary, Debug, MessageDeserialize, PartialEq, Reflect, Schema, Serialize, VariantTests)]
+#[non_exhaustive]
+#[repr(u16)]
+pub enum LuMessage {
+	General(GeneralMessage) = ServiceId::General as u16,
+	ChatInternal(ChatInternalMessage) = ServiceId::ChatInternal as u16,
+}
+
+/// All chat-server-received internal messages.
+#[derive(Arbitrary, Debug, MessageDeserialize, PartialEq, Reflect, Schema, Serialize, VariantTests)]
+#[non_exhaustive]
+#[post_disc_padding = 1]
+#[repr(u32)]
+pub enum ChatInternalMessage {
+	PlayerAddedNotification(PlayerAddedNotification) = 0,
+	PlayerRemovedNotification(PlayerRemovedNotification) = 1,
+	Announcement(Announcement) = 13,
+	MailSendNotify(MailSendNotify) = 15,
+}
+
+/**
+	Notifies the chat server that a character has entered a world.
+
+	### Handling
+	Track the character's location for friends list and team updates, and send [`FriendUpdateNotify`](crate::world::client::FriendUpdateNotify) to the character's friends.
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct PlayerAddedNotification {
+	pub char_id: ObjId,
+	pub char_name: LuWString33,
+	pub zone_id: ZoneId,
+}
+
+/// Notifies the chat server that a character has left its world.
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct PlayerRemovedNotification {
+	pub char_id: ObjId,
+}
+
+/// Asks the chat server to broadcast an announcement to all players, e.g. for a GM announcement.
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct Announcement {
+	pub title: LuVarString<u32>,
+	pub message: LuVarString<u32>,
+}
+
+/// Notifies the chat server that a character has received mail, so the recipient's world server can be told to update the unread count.
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct MailSendNotify {
+	pub recipient_name: LuWString33,
+}
@@ -0,0 +1,6 @@
+ChatInternalMessage::Announcement(
+	Announcement {
+		title: lu!(b"Server restart"),
+		message: lu!(b"The server will restart in 5 minutes."),
+	},
+)
@@ -0,0 +1,7 @@
+LuMessage::ChatInternal(
+	ChatInternalMessage::PlayerRemovedNotification(
+		PlayerRemovedNotification {
+			char_id: 1152921510436607008,
+		},
+	),
+)
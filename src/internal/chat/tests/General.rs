@@ -0,0 +1,10 @@
+LuMessage::General(
+	GeneralMessage::Handshake(
+		crate::general::server::Handshake {
+			network_version: 171022,
+			service_id: ServiceId::World,
+			process_id: 2284,
+			port: 63074,
+		},
+	),
+)
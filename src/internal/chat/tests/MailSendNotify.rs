@@ -0,0 +1,5 @@
+ChatInternalMessage::MailSendNotify(
+	MailSendNotify {
+		recipient_name: lu!("RockVonViper"),
+	},
+)
@@ -0,0 +1,11 @@
+ChatInternalMessage::PlayerAddedNotification(
+	PlayerAddedNotification {
+		char_id: 1152921510436607008,
+		char_name: lu!("RockVonViper"),
+		zone_id: ZoneId {
+			map_id: 1100,
+			instance_id: 5,
+			clone_id: 0,
+		},
+	},
+)
@@ -0,0 +1,5 @@
+ChatInternalMessage::PlayerRemovedNotification(
+	PlayerRemovedNotification {
+		char_id: 1152921510436607008,
+	},
+)
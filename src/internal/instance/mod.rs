@@ -0,0 +1,107 @@
+//! Internal messages received by auth, chat and world servers from the master server.
+use endio::{Deserialize, Serialize};
+use lu_packets_derive::{Arbitrary, MessageDeserialize, Reflect, Schema, VariantTests};
+
+use crate::common::{LuString33, LuWString33, ServiceId};
+use crate::world::{MapId, ZoneId};
+
+pub use crate::general::client::GeneralMessage;
+
+/// All messages that can be received by a server from the master server.
+pub type Message = crate::raknet::client::Message<LuMessage>;
+
+/// All LU messages that can be received by a server from the master server.
+#[derive(Arbitrary, Debug, MessageDeserialize, PartialEq, Reflect, Schema, Serialize, VariantTests)]
+#[non_exhaustive]
+#[repr(u16)]
+pub enum LuMessage {
+	General(GeneralMessage) = ServiceId::General as u16,
+	Master(InstanceMessage) = ServiceId::Master as u16,
+}
+
+/**
+	All internal messages received from the master server.
+
+	Message IDs are shared with [`MasterMessage`](super::master::MasterMessage).
+*/
+#[derive(Arbitrary, Debug, MessageDeserialize, PartialEq, Reflect, Schema, Serialize, VariantTests)]
+#[non_exhaustive]
+#[post_disc_padding = 1]
+#[repr(u32)]
+pub enum InstanceMessage {
+	RequestPersistentIdResponse(RequestPersistentIdResponse) = 2,
+	RequestZoneTransferResponse(RequestZoneTransferResponse) = 4,
+	SessionKeyResponse(SessionKeyResponse) = 8,
+	PrepZone(PrepZone) = 14,
+	Shutdown = 15,
+	ShutdownImmediate = 17,
+	AffirmTransferRequest(AffirmTransferRequest) = 19,
+	NewSessionAlert(NewSessionAlert) = 21,
+}
+
+/// Provides a new globally unique object ID, in response to [`RequestPersistentId`](super::master::RequestPersistentId).
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct RequestPersistentIdResponse {
+	pub request_id: u64,
+	/// The new ID. Persistent IDs are 32-bit, flags for the full object ID are added by the requesting server.
+	pub persistent_id: u32,
+}
+
+/**
+	Provides the address of a world server hosting a zone.
+
+	### Trigger
+	Receipt of [`RequestZoneTransfer`](super::master::RequestZoneTransfer) or [`RequestPrivateZone`](super::master::RequestPrivateZone).
+
+	### Handling
+	Redirect the player using [`TransferToWorld`](crate::world::client::TransferToWorld).
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct RequestZoneTransferResponse {
+	pub request_id: u64,
+	pub mythran_shift: bool,
+	pub zone_id: ZoneId,
+	pub server_ip: LuString33,
+	pub server_port: u16,
+}
+
+/**
+	Provides the session key of an account, in response to [`RequestSessionKey`](super::master::RequestSessionKey).
+
+	### Handling
+	Compare the session key with the one in [`ClientValidation`](crate::world::server::ClientValidation) and disconnect the client if they don't match.
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct SessionKeyResponse {
+	pub session_key: LuWString33,
+	pub username: LuWString33,
+}
+
+/// Tells an idle world server to load a zone.
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct PrepZone {
+	pub map_id: MapId,
+}
+
+/**
+	Asks a world server whether it is ready to receive a transferred player.
+
+	### Response
+	Respond with [`AffirmTransferResponse`](super::master::AffirmTransferResponse).
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct AffirmTransferRequest {
+	pub request_id: u64,
+}
+
+/**
+	Notifies servers that an account has logged in again.
+
+	### Handling
+	Disconnect any existing session of the account with [`DisconnectNotify::DuplicateLogin`](crate::general::client::DisconnectNotify::DuplicateLogin).
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct NewSessionAlert {
+	pub session_key: LuWString33,
+	pub username: LuWString33,
+}
@@ -0,0 +1,5 @@
+InstanceMessage::AffirmTransferRequest(
+	AffirmTransferRequest {
+		request_id: 45,
+	},
+)
@@ -0,0 +1,8 @@
+LuMessage::General(
+	GeneralMessage::Handshake(
+		crate::general::client::Handshake {
+			network_version: 171022,
+			service_id: ServiceId::Master,
+		},
+	),
+)
@@ -0,0 +1,3 @@
+LuMessage::Master(
+	InstanceMessage::Shutdown,
+)
@@ -0,0 +1,5 @@
+InstanceMessage::PrepZone(
+	PrepZone {
+		map_id: 1300,
+	},
+)
@@ -0,0 +1,6 @@
+InstanceMessage::RequestPersistentIdResponse(
+	RequestPersistentIdResponse {
+		request_id: 42,
+		persistent_id: 1337,
+	},
+)
@@ -0,0 +1,13 @@
+InstanceMessage::RequestZoneTransferResponse(
+	RequestZoneTransferResponse {
+		request_id: 43,
+		mythran_shift: false,
+		zone_id: ZoneId {
+			map_id: 1200,
+			instance_id: 2,
+			clone_id: 0,
+		},
+		server_ip: lu!(b"171.20.35.42"),
+		server_port: 2006,
+	},
+)
@@ -0,0 +1,6 @@
+InstanceMessage::SessionKeyResponse(
+	SessionKeyResponse {
+		session_key: lu!("9fdce6d87c0712819c3a13cec99de30a"),
+		username: lu!("pwjones"),
+	},
+)
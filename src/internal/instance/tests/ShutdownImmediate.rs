@@ -0,0 +1 @@
+InstanceMessage::ShutdownImmediate
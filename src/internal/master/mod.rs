@@ -0,0 +1,193 @@
+//! Master-received internal messages.
+use endio::{Deserialize, Serialize};
+use lu_packets_derive::{Arbitrary, MessageDeserialize, Reflect, Schema, VariantTests};
+
+use crate::common::{LuString33, LuWString33, ServiceId};
+use crate::world::{CloneId, MapId, ZoneId};
+use super::ServerType;
+
+pub use crate::general::server::GeneralMessage;
+
+/// All messages that can be received by a master server.
+pub type Message = crate::raknet::server::Message<LuMessage>;
+
+/// All LU messages that can be received by a master server.
+#[derive(Arbitrary, Debug, MessageDeserialize, PartialEq, Reflect, Schema, Serialize, VariantTests)]
+#[non_exhaustive]
+#[repr(u16)]
+pub enum LuMessage {
+	General(GeneralMessage) = ServiceId::General as u16,
+	Master(MasterMessage) = ServiceId::Master as u16,
+}
+
+/**
+	All master-received internal messages.
+
+	Message IDs are shared with [`InstanceMessage`](super::instance::InstanceMessage), responses have the ID following their request.
+*/
+#[derive(Arbitrary, Debug, MessageDeserialize, PartialEq, Reflect, Schema, Serialize, VariantTests)]
+#[non_exhaustive]
+#[post_disc_padding = 1]
+#[repr(u32)]
+pub enum MasterMessage {
+	RequestPersistentId(RequestPersistentId) = 1,
+	RequestZoneTransfer(RequestZoneTransfer) = 3,
+	ServerInfo(ServerInfo) = 5,
+	RequestSessionKey(RequestSessionKey) = 6,
+	SetSessionKey(SetSessionKey) = 7,
+	PlayerAdded(PlayerAdded) = 9,
+	PlayerRemoved(PlayerRemoved) = 10,
+	CreatePrivateZone(CreatePrivateZone) = 11,
+	RequestPrivateZone(RequestPrivateZone) = 12,
+	WorldReady(WorldReady) = 13,
+	ShutdownResponse = 16,
+	ShutdownUniverse = 18,
+	AffirmTransferResponse(AffirmTransferResponse) = 20,
+}
+
+/**
+	Requests a new object ID that is unique across all servers.
+
+	### Trigger
+	A server needing to persist a new object, for example when creating a character or an item.
+
+	### Handling
+	Generate the next ID from a counter persisted in the database.
+
+	### Response
+	Respond with [`RequestPersistentIdResponse`](super::instance::RequestPersistentIdResponse) using the same [`request_id`](Self::request_id).
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct RequestPersistentId {
+	/// ID chosen by the requesting server to match the response to the request.
+	pub request_id: u64,
+}
+
+/**
+	Requests the address of a world server hosting a zone.
+
+	### Trigger
+	A world server transferring a player to a different zone.
+
+	### Handling
+	Look up a world server instance hosting the zone with free capacity, or start a new one.
+
+	### Response
+	Respond with [`RequestZoneTransferResponse`](super::instance::RequestZoneTransferResponse) once the instance is ready.
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct RequestZoneTransfer {
+	/// ID chosen by the requesting server to match the response to the request.
+	pub request_id: u64,
+	/// Whether the transfer is a GM-initiated instance change.
+	pub mythran_shift: bool,
+	/// Map ID of the destination zone.
+	pub map_id: MapId,
+	/// Clone ID of the destination zone, for properties.
+	pub clone_id: CloneId,
+}
+
+/**
+	Registers a server process with the master server.
+
+	### Trigger
+	Establishment of the connection to the master server, after the handshake.
+
+	### Handling
+	Store the server's address so that clients can be redirected to it.
+
+	### Response
+	None. World servers will send [`WorldReady`] once their zone has loaded.
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct ServerInfo {
+	/// Port the server accepts client connections on.
+	pub port: u16,
+	/// Zone hosted by the server, [`ZoneId::INVALID`] for non-world servers.
+	pub zone_id: ZoneId,
+	/// Kind of the server.
+	pub server_type: ServerType,
+	/// Address the server accepts client connections on.
+	pub ip: LuString33,
+}
+
+/**
+	Requests the session key of an account.
+
+	### Trigger
+	Receipt of [`ClientValidation`](crate::world::server::ClientValidation) by a world or chat server.
+
+	### Response
+	Respond with [`SessionKeyResponse`](super::instance::SessionKeyResponse).
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct RequestSessionKey {
+	pub username: LuWString33,
+}
+
+/**
+	Stores the session key of an account that just logged in.
+
+	### Trigger
+	Sending of a successful [`LoginResponse`](crate::auth::client::LoginResponse) by the auth server.
+
+	### Handling
+	Store the session key. If there already is a session for the account, notify the servers with [`NewSessionAlert`](super::instance::NewSessionAlert) so that they disconnect the old session.
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct SetSessionKey {
+	pub session_key: LuWString33,
+	pub username: LuWString33,
+}
+
+/// Notifies the master server that a player has joined a world server, for load balancing.
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct PlayerAdded {
+	pub zone_id: ZoneId,
+}
+
+/// Notifies the master server that a player has left a world server, for load balancing.
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct PlayerRemoved {
+	pub zone_id: ZoneId,
+}
+
+/// Creates a password-protected private instance of a zone.
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct CreatePrivateZone {
+	pub map_id: MapId,
+	pub clone_id: CloneId,
+	pub password: LuWString33,
+}
+
+/**
+	Requests a transfer to a private instance.
+
+	### Response
+	Respond with [`RequestZoneTransferResponse`](super::instance::RequestZoneTransferResponse) if an instance with the password exists.
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct RequestPrivateZone {
+	/// ID chosen by the requesting server to match the response to the request.
+	pub request_id: u64,
+	/// Whether the transfer is a GM-initiated instance change.
+	pub mythran_shift: bool,
+	pub password: LuWString33,
+}
+
+/**
+	Notifies the master server that a world server has finished loading its zone.
+
+	### Handling
+	Answer any pending [`RequestZoneTransfer`]s waiting on this instance.
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct WorldReady {
+	pub zone_id: ZoneId,
+}
+
+/// Confirms that a world server is ready to receive a transferred player, in response to [`AffirmTransferRequest`](super::instance::AffirmTransferRequest).
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct AffirmTransferResponse {
+	pub request_id: u64,
+}
@@ -0,0 +1,5 @@
+MasterMessage::AffirmTransferResponse(
+	AffirmTransferResponse {
+		request_id: 45,
+	},
+)
@@ -0,0 +1,7 @@
+MasterMessage::CreatePrivateZone(
+	CreatePrivateZone {
+		map_id: 1101,
+		clone_id: 0,
+		password: lu!("hunter2"),
+	},
+)
@@ -0,0 +1,3 @@
+LuMessage::Master(
+	MasterMessage::ShutdownResponse,
+)
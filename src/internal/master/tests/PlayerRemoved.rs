@@ -0,0 +1,9 @@
+MasterMessage::PlayerRemoved(
+	PlayerRemoved {
+		zone_id: ZoneId {
+			map_id: 1100,
+			instance_id: 5,
+			clone_id: 0,
+		},
+	},
+)
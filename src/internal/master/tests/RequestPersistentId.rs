@@ -0,0 +1,5 @@
+MasterMessage::RequestPersistentId(
+	RequestPersistentId {
+		request_id: 42,
+	},
+)
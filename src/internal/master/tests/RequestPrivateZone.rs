@@ -0,0 +1,7 @@
+MasterMessage::RequestPrivateZone(
+	RequestPrivateZone {
+		request_id: 44,
+		mythran_shift: true,
+		password: lu!("hunter2"),
+	},
+)
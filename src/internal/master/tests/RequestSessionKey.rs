@@ -0,0 +1,5 @@
+MasterMessage::RequestSessionKey(
+	RequestSessionKey {
+		username: lu!("pwjones"),
+	},
+)
@@ -0,0 +1,8 @@
+MasterMessage::RequestZoneTransfer(
+	RequestZoneTransfer {
+		request_id: 43,
+		mythran_shift: false,
+		map_id: 1200,
+		clone_id: 0,
+	},
+)
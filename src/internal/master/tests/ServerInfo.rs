@@ -0,0 +1,12 @@
+MasterMessage::ServerInfo(
+	ServerInfo {
+		port: 2005,
+		zone_id: ZoneId {
+			map_id: 1000,
+			instance_id: 3,
+			clone_id: 0,
+		},
+		server_type: ServerType::World,
+		ip: lu!(b"171.20.35.42"),
+	},
+)
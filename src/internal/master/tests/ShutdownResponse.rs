@@ -0,0 +1 @@
+MasterMessage::ShutdownResponse
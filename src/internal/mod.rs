@@ -0,0 +1,22 @@
+/*!
+	Server-to-server messages.
+
+	LU's back end is split into multiple processes: a master server coordinating the setup, and auth, chat and world servers connecting to it. These servers talk to each other using the same framing as the client-facing protocol, with [`ServiceId::Master`](crate::common::ServiceId::Master) and [`ServiceId::ChatInternal`](crate::common::ServiceId::ChatInternal) as service IDs.
+
+	World servers don't talk to each other directly. Anything concerning multiple world servers, like zone transfers, is routed through the master server.
+*/
+pub mod chat;
+pub mod instance;
+pub mod master;
+
+use endio::{Deserialize, Serialize};
+use lu_packets_derive::{Arbitrary, Reflect, Schema};
+
+/// The kind of server process connecting to the master server.
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+#[repr(u32)]
+pub enum ServerType {
+	Auth,
+	Chat,
+	World,
+}
@@ -138,5 +138,12 @@ pub mod auth;
 pub mod chat;
 pub mod common;
 pub mod general;
+pub mod internal;
+#[cfg(feature = "reflect")]
+pub mod reflect;
+#[cfg(feature = "schema")]
+pub mod schema;
+#[cfg(feature = "trace")]
+pub mod trace;
 pub mod world;
 pub mod unified;
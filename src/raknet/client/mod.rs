@@ -1,20 +1,37 @@
 //! Client-received raknet messages.
 pub mod replica;
 
+use std::io::{Read, Write};
+use std::io::Result as Res;
+
 use endio::{Deserialize, Serialize};
-use lu_packets_derive::ReplicaVariantTests;
+use endio::LittleEndian as LE;
+use lu_packets_derive::{Arbitrary, MessageDeserialize, Reflect, ReplicaVariantTests, Schema};
 
-use super::SystemAddress;
+use super::{read_offline_padding, write_offline_padding, AdvertiseSystem, SystemAddress, Timestamp};
 use replica::{ReplicaConstruction, ReplicaSerialization};
 
-#[derive(Debug, Deserialize, PartialEq, Serialize, ReplicaVariantTests)]
+#[derive(Arbitrary, Debug, MessageDeserialize, PartialEq, Reflect, Schema, Serialize, ReplicaVariantTests)]
 #[test_params(crate::world::client::LuMessage)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum Message<U> {
 	ConnectedPong(ConnectedPong) = 3,
+	OpenConnectionReply(OpenConnectionReply) = 10,
 	ConnectionRequestAccepted(ConnectionRequestAccepted) = 14,
+	/// Generated locally by raknet when the server doesn't reply to the connection attempt, never sent over the network.
+	ConnectionAttemptFailed = 15,
+	/// Offline message, sent when the server is full.
+	NoFreeIncomingConnections = 18,
 	DisconnectionNotification = 19,
+	/// Generated locally by raknet when a connection times out, never sent over the network.
+	ConnectionLost = 20,
+	/// Offline message, sent when the client's address is banned.
+	ConnectionBanned = 22,
+	/// Sent when the [`ConnectionRequest::password`](crate::raknet::server::ConnectionRequest::password) doesn't match.
+	InvalidPassword = 23,
+	Timestamp(Timestamp<U>) = 25,
+	AdvertiseSystem(AdvertiseSystem) = 27,
 	ReplicaConstruction(ReplicaConstruction) = 36,
 	ReplicaSerialization(ReplicaSerialization) = 39,
 	UserMessage(U) = 83,
@@ -44,12 +61,37 @@ impl<U> From<ReplicaSerialization> for Message<U> {
 	}
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct ConnectedPong {
 	pub ping_send_time: u32,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+/**
+	Offline message reserving a connection slot.
+
+	### Trigger
+	Receipt of [`OpenConnectionRequest`](crate::raknet::server::OpenConnectionRequest).
+
+	### Response
+	Send a [`ConnectionRequest`](crate::raknet::server::ConnectionRequest).
+*/
+#[derive(Arbitrary, Debug, PartialEq)]
+pub struct OpenConnectionReply;
+
+impl<R: Read> Deserialize<LE, R> for OpenConnectionReply {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		read_offline_padding(reader)?;
+		Ok(Self)
+	}
+}
+
+impl<'a, W: Write> Serialize<LE, W> for &'a OpenConnectionReply {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		write_offline_padding(writer)
+	}
+}
+
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct ConnectionRequestAccepted {
 	pub peer_addr: SystemAddress,
 	#[padding = 2]
@@ -1,10 +1,9 @@
 use std::io::Result as Res;
 
 use endio::Serialize;
-use endio_bit::BEBitWriter;
 use lu_packets_derive::{BitVariantTests, ReplicaSerde};
 
-use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization};
+use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ComponentWriter};
 use super::vendor::VendorInfo;
 
 #[derive(BitVariantTests, Debug, PartialEq, ReplicaSerde)]
@@ -13,7 +12,7 @@ pub struct AchievementVendorConstruction {
 }
 
 impl ComponentConstruction for AchievementVendorConstruction {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -21,7 +20,7 @@ impl ComponentConstruction for AchievementVendorConstruction {
 pub type AchievementVendorSerialization = AchievementVendorConstruction;
 
 impl ComponentSerialization for AchievementVendorSerialization {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
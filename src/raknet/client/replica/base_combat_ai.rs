@@ -1,13 +1,12 @@
 use std::io::Result as Res;
 
 use endio::{Deserialize, Serialize};
-use endio_bit::BEBitWriter;
-use lu_packets_derive::{BitVariantTests, ReplicaSerde};
+use lu_packets_derive::{Arbitrary, BitVariantTests, ReplicaSerde};
 
 use crate::common::ObjId;
-use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization};
+use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ComponentWriter};
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Serialize)]
 #[repr(u32)]
 pub enum AiCombatState {
 	Idle,
@@ -29,7 +28,7 @@ pub struct BaseCombatAiConstruction {
 }
 
 impl ComponentConstruction for BaseCombatAiConstruction {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -37,7 +36,7 @@ impl ComponentConstruction for BaseCombatAiConstruction {
 pub type BaseCombatAiSerialization = BaseCombatAiConstruction;
 
 impl ComponentSerialization for BaseCombatAiSerialization {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -1,11 +1,10 @@
 use std::io::Result as Res;
 
 use endio::Serialize;
-use endio_bit::BEBitWriter;
 use lu_packets_derive::{BitVariantTests, ReplicaSerde};
 
 use crate::common::ObjId;
-use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization};
+use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ComponentWriter};
 
 #[derive(BitVariantTests, Debug, PartialEq, ReplicaSerde)]
 pub struct BbbConstruction {
@@ -13,7 +12,7 @@ pub struct BbbConstruction {
 }
 
 impl ComponentConstruction for BbbConstruction {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -21,7 +20,7 @@ impl ComponentConstruction for BbbConstruction {
 pub type BbbSerialization = BbbConstruction;
 
 impl ComponentSerialization for BbbSerialization {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
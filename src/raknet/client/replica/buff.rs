@@ -2,13 +2,13 @@ use std::io::{Read, Result as Res, Write};
 
 use endio::{Deserialize, LE, LERead, LEWrite, Serialize};
 use endio_bit::{BEBitReader, BEBitWriter};
-use lu_packets_derive::{BitVariantTests, ReplicaSerde};
+use lu_packets_derive::{Arbitrary, BitVariantTests, ReplicaSerde};
 
-use crate::common::{LVec, ObjId};
-use super::{ReplicaD, ComponentConstruction, ComponentProtocol, ComponentSerialization};
+use crate::common::{LVec, ObjId, read_bit};
+use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ComponentWriter, ReplicaD};
 
 // so close to being able to do serialization automatically...if not for the irregularity with `added_by_teammate`...
-#[derive(Debug, PartialEq)]
+#[derive(Arbitrary, Debug, PartialEq)]
 pub struct BuffInfo {
 	pub buff_id: u32,
 	pub time_left: Option<u32>,
@@ -29,16 +29,16 @@ impl<R: Read> Deserialize<LE, BEBitReader<R>> for BuffInfo {
 	fn deserialize(reader: &mut BEBitReader<R>) -> Res<Self> {
 		let buff_id = LERead::read(reader)?;
 		let time_left = ReplicaD::deserialize(reader)?;
-		let cancel_on_death = reader.read_bit()?;
-		let cancel_on_zone = reader.read_bit()?;
-		let cancel_on_damaged = reader.read_bit()?;
-		let cancel_on_remove_buff = reader.read_bit()?;
-		let cancel_on_ui = reader.read_bit()?;
-		let cancel_on_logout = reader.read_bit()?;
-		let cancel_on_unequip = reader.read_bit()?;
-		let cancel_on_damage_absorb_ran_out = reader.read_bit()?;
-		let added_by_teammate = reader.read_bit()?;
-		let apply_on_teammates = reader.read_bit()?;
+		let cancel_on_death = read_bit(reader)?;
+		let cancel_on_zone = read_bit(reader)?;
+		let cancel_on_damaged = read_bit(reader)?;
+		let cancel_on_remove_buff = read_bit(reader)?;
+		let cancel_on_ui = read_bit(reader)?;
+		let cancel_on_logout = read_bit(reader)?;
+		let cancel_on_unequip = read_bit(reader)?;
+		let cancel_on_damage_absorb_ran_out = read_bit(reader)?;
+		let added_by_teammate = read_bit(reader)?;
+		let apply_on_teammates = read_bit(reader)?;
 		let added_by_teammate = if added_by_teammate { Some(LERead::read(reader)?) } else { None };
 		let ref_count = LERead::read(reader)?;
 		Ok(Self { buff_id, time_left, cancel_on_death, cancel_on_zone, cancel_on_damaged, cancel_on_remove_buff, cancel_on_ui, cancel_on_logout, cancel_on_unequip, cancel_on_damage_absorb_ran_out, added_by_teammate, apply_on_teammates, ref_count })
@@ -76,13 +76,13 @@ pub struct BuffConstruction {
 pub struct BuffSerialization {}
 
 impl ComponentConstruction for BuffConstruction {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
 
 impl ComponentSerialization for BuffSerialization {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -2,12 +2,12 @@ use std::io::{Error, ErrorKind::InvalidData, Read, Result as Res, Write};
 
 use endio::{Deserialize, LE, LERead, LEWrite, Serialize};
 use endio_bit::{BEBitReader, BEBitWriter};
-use lu_packets_derive::{BitVariantTests, ReplicaSerde};
+use lu_packets_derive::{Arbitrary, BitVariantTests, ReplicaSerde};
 
-use crate::common::{LuVarWString, ObjId};
-use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization};
+use crate::common::{GuildId, LuVarWString};
+use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ComponentWriter};
 
-#[derive(Debug, PartialEq)]
+#[derive(Arbitrary, Debug, PartialEq)]
 pub enum TransitionState {
 	None,
 	Arrive { last_custom_build_parts: LuVarWString<u16> },
@@ -17,6 +17,8 @@ pub enum TransitionState {
 impl<R: Read> Deserialize<LE, BEBitReader<R>> for TransitionState {
 	fn deserialize(reader: &mut BEBitReader<R>) -> Res<Self> {
 		let disc = reader.read_bits(2)?;
+		#[cfg(feature = "trace")]
+		crate::trace::read_bits(2);
 		Ok(match disc {
 			0 => TransitionState::None,
 			1 => TransitionState::Arrive { last_custom_build_parts: LERead::read(reader)? },
@@ -48,7 +50,7 @@ pub struct GmPvpInfo {
 	pub editor_level: u8,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Serialize)]
 #[repr(u32)]
 pub enum GameActivity {
 	None,
@@ -62,7 +64,7 @@ pub enum GameActivity {
 #[derive(Debug, PartialEq, ReplicaSerde)]
 #[trailing_padding = 4] // country code, unused
 pub struct SocialInfo {
-	pub guild_id: ObjId,
+	pub guild_id: GuildId,
 	pub guild_name: LuVarWString<u8>,
 	pub is_lego_club_member: bool,
 }
@@ -130,13 +132,13 @@ pub struct CharacterSerialization {
 }
 
 impl ComponentConstruction for CharacterConstruction {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
 
 impl ComponentSerialization for CharacterSerialization {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
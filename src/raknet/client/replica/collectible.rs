@@ -1,10 +1,9 @@
 use std::io::Result as Res;
 
 use endio::Serialize;
-use endio_bit::BEBitWriter;
 use lu_packets_derive::{BitVariantTests, ReplicaSerde};
 
-use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization};
+use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ComponentWriter};
 
 #[derive(BitVariantTests, Debug, PartialEq, ReplicaSerde)]
 pub struct CollectibleConstruction {
@@ -12,7 +11,7 @@ pub struct CollectibleConstruction {
 }
 
 impl ComponentConstruction for CollectibleConstruction {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -20,7 +19,7 @@ impl ComponentConstruction for CollectibleConstruction {
 pub type CollectibleSerialization = CollectibleConstruction;
 
 impl ComponentSerialization for CollectibleSerialization {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -1,12 +1,11 @@
 use std::io::{Result as Res};
 
 use endio::{Deserialize, Serialize};
-use endio_bit::BEBitWriter;
-use lu_packets_derive::{BitVariantTests, ReplicaSerde};
+use lu_packets_derive::{Arbitrary, BitVariantTests, ReplicaSerde};
 
 use crate::common::ObjId;
 use crate::world::{Vector3, Quaternion};
-use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization};
+use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ComponentWriter};
 
 #[derive(Debug, PartialEq, ReplicaSerde)]
 pub struct JetpackInfo {
@@ -15,7 +14,7 @@ pub struct JetpackInfo {
 	pub bypass_checks: bool,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Serialize)]
 pub struct StunImmunityInfo {
 	// todo: type
 	pub immune_to_stun_move: i32,
@@ -27,7 +26,7 @@ pub struct StunImmunityInfo {
 	pub immune_to_stun_interact: i32,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Serialize)]
 pub struct CheatInfo {
 	pub gravity_scale: f32,
 	pub run_multiplier: f32,
@@ -100,13 +99,13 @@ pub struct ControllablePhysicsSerialization {
 }
 
 impl ComponentConstruction for ControllablePhysicsConstruction {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
 
 impl ComponentSerialization for ControllablePhysicsSerialization {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
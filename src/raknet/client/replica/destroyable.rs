@@ -2,12 +2,12 @@ use std::io::{Read, Result as Res, Write};
 
 use endio::{Deserialize, LE, LERead, LEWrite, Serialize};
 use endio_bit::{BEBitReader, BEBitWriter};
-use lu_packets_derive::{BitVariantTests, ReplicaSerde};
+use lu_packets_derive::{Arbitrary, BitVariantTests, ReplicaSerde};
 
-use crate::common::LVec;
-use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization};
+use crate::common::{LVec, read_bit};
+use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ComponentWriter};
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Serialize)]
 pub struct StatusImmunityInfo {
 	pub immune_to_basic_attack: u32,
 	pub immune_to_damage_over_time: u32,
@@ -27,7 +27,7 @@ pub struct SmashableInfo {
 }
 
 // so close to being able to do serialization automatically...if not for the irregularity with `smashable_info`...
-#[derive(Debug, PartialEq)]
+#[derive(Arbitrary, Debug, PartialEq)]
 pub struct StatsInfo {
 	pub cur_health: u32,
 	pub max_health: f32,
@@ -58,16 +58,16 @@ impl<R: Read> Deserialize<LE, BEBitReader<R>> for StatsInfo {
 		let cur_imag   = LERead::read(reader)?;
 		let max_imag   = LERead::read(reader)?;
 		let damage_absorption_points = LERead::read(reader)?;
-		let immunity     = reader.read_bit()?;
-		let is_gm_immune = reader.read_bit()?;
-		let is_shielded  = reader.read_bit()?;
+		let immunity     = read_bit(reader)?;
+		let is_gm_immune = read_bit(reader)?;
+		let is_shielded  = read_bit(reader)?;
 		let actual_max_health = LERead::read(reader)?;
 		let actual_max_armor  = LERead::read(reader)?;
 		let actual_max_imag   = LERead::read(reader)?;
 		let factions          = LERead::read(reader)?;
-		let is_smashable = reader.read_bit()?;
-		let is_dead      = reader.read_bit()?;
-		let is_smashed   = reader.read_bit()?;
+		let is_smashable = read_bit(reader)?;
+		let is_dead      = read_bit(reader)?;
+		let is_smashed   = read_bit(reader)?;
 		let smashable_info = if is_smashable {
 			Some(LERead::read(reader)?)
 		} else {
@@ -154,13 +154,13 @@ pub struct DestroyableSerialization {
 }
 
 impl ComponentConstruction for DestroyableConstruction {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
 
 impl ComponentSerialization for DestroyableSerialization {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
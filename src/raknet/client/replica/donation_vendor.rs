@@ -1,10 +1,9 @@
 use std::io::Result as Res;
 
 use endio::Serialize;
-use endio_bit::BEBitWriter;
 use lu_packets_derive::{BitVariantTests, ReplicaSerde};
 
-use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization};
+use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ComponentWriter};
 use super::vendor::VendorInfo;
 
 #[derive(Debug, PartialEq, ReplicaSerde)]
@@ -21,7 +20,7 @@ pub struct DonationVendorConstruction {
 }
 
 impl ComponentConstruction for DonationVendorConstruction {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -29,7 +28,7 @@ impl ComponentConstruction for DonationVendorConstruction {
 pub type DonationVendorSerialization = DonationVendorConstruction;
 
 impl ComponentSerialization for DonationVendorSerialization {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -1,13 +1,12 @@
 use std::io::Result as Res;
 
 use endio::Serialize;
-use endio_bit::BEBitWriter;
 use lu_packets_derive::{BitVariantTests, ReplicaSerde};
 
 use crate::common::{LVec, ObjId};
 use crate::world::{LuNameValue, Lot, Quaternion, Vector3};
 use crate::world::gm::InventoryType;
-use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization};
+use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ComponentWriter};
 
 #[derive(Debug, PartialEq, ReplicaSerde)]
 pub struct EquippedItemInfo {
@@ -35,7 +34,7 @@ pub struct InventoryConstruction {
 }
 
 impl ComponentConstruction for InventoryConstruction {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -43,7 +42,7 @@ impl ComponentConstruction for InventoryConstruction {
 pub type InventorySerialization = InventoryConstruction;
 
 impl ComponentSerialization for InventorySerialization {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
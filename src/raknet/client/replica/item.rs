@@ -1,13 +1,12 @@
 use std::io::Result as Res;
 
 use endio::{Deserialize, Serialize};
-use endio_bit::BEBitWriter;
-use lu_packets_derive::{BitVariantTests, ReplicaSerde};
+use lu_packets_derive::{Arbitrary, BitVariantTests, ReplicaSerde};
 
 use crate::common::{LuVarWString, ObjId};
-use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization};
+use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ComponentWriter};
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Serialize)]
 #[repr(u32)]
 pub enum UgcModerationStatus {
 	NoStatus,
@@ -28,7 +27,7 @@ pub struct ItemConstruction {
 }
 
 impl ComponentConstruction for ItemConstruction {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -36,7 +35,7 @@ impl ComponentConstruction for ItemConstruction {
 pub type ItemSerialization = ItemConstruction;
 
 impl ComponentSerialization for ItemSerialization {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -1,11 +1,10 @@
 use std::io::Result as Res;
 
 use endio::Serialize;
-use endio_bit::BEBitWriter;
 use lu_packets_derive::{BitVariantTests, ReplicaSerde};
 
 use crate::world::Lot;
-use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization};
+use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ComponentWriter};
 
 #[derive(BitVariantTests, Debug, PartialEq, ReplicaSerde)]
 pub struct LupExhibitConstruction {
@@ -13,7 +12,7 @@ pub struct LupExhibitConstruction {
 }
 
 impl ComponentConstruction for LupExhibitConstruction {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -21,7 +20,7 @@ impl ComponentConstruction for LupExhibitConstruction {
 pub type LupExhibitSerialization = LupExhibitConstruction;
 
 impl ComponentSerialization for LupExhibitSerialization {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -34,20 +34,20 @@ pub mod vehicle_physics;
 pub mod vendor;
 
 use std::fmt::Debug;
-use std::io::{Read, Result as Res, Write};
+use std::io::{ErrorKind, Read, Result as Res, Write};
 
 use endio::{Deserialize, LE, LERead, LEWrite, Serialize};
 use endio_bit::{BEBitReader, BEBitWriter};
-use lu_packets_derive::ReplicaSerde;
+use lu_packets_derive::{Arbitrary, ReplicaSerde};
 
-use crate::common::{ObjId, LuVarWString, LVec};
+use crate::common::{BitSize, NetworkVersion, ObjId, LuVarWString, LVec, read_bit, Versioned, NETWORK_VERSION_LIVE};
 use crate::world::{Lot, LuNameValue};
 
-trait ReplicaD<R: Read>: Sized {
+pub(crate) trait ReplicaD<R: Read>: Sized {
 	fn deserialize(reader: &mut BEBitReader<R>) -> Res<Self>;
 }
 
-trait ReplicaS<W: Write> {
+pub(crate) trait ReplicaS<W: Write> {
 	fn serialize(self, writer: &mut BEBitWriter<W>) -> Res<()>;
 }
 
@@ -68,7 +68,7 @@ where
 
 impl<R: Read> ReplicaD<R> for bool {
 	fn deserialize(reader: &mut BEBitReader<R>) -> Res<Self> {
-		reader.read_bit()
+		read_bit(reader)
 	}
 }
 
@@ -80,7 +80,7 @@ impl<'a, W: Write> ReplicaS<W> for &'a bool {
 
 impl<R: Read, T: ReplicaD<R> + Deserialize<LE, BEBitReader<R>>> ReplicaD<R> for Option<T> {
 	fn deserialize(reader: &mut BEBitReader<R>) -> Res<Self> {
-		let bit = reader.read_bit()?;
+		let bit = read_bit(reader)?;
 		Ok(if !bit { None } else { Some(ReplicaD::deserialize(reader)?) })
 	}
 }
@@ -98,12 +98,15 @@ where
 	}
 }
 
-pub trait ComponentConstruction: Debug {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()>;
+/// Bit writer that components are written with. It writes straight to the output of the replica, so encoding a replica needs no buffer of its own.
+pub type ComponentWriter<'a> = BEBitWriter<Versioned<&'a mut dyn Write>>;
+
+pub trait ComponentConstruction: BitSize + Debug {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()>;
 }
 
-pub trait ComponentSerialization: Debug {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()>;
+pub trait ComponentSerialization: BitSize + Debug {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()>;
 }
 
 pub trait ComponentProtocol {
@@ -114,6 +117,75 @@ pub trait ComponentProtocol {
 pub trait ReplicaContext {
 	fn get_comp_constructions<R: Read>(&mut self, network_id: u16, lot: Lot, config: &Option<LuNameValue>) -> Vec<fn(&mut BEBitReader<R>) -> Res<Box<dyn ComponentConstruction>>>;
 	fn get_comp_serializations<R: Read>(&mut self, network_id: u16) -> Vec<fn(&mut BEBitReader<R>) -> Res<Box<dyn ComponentSerialization>>>;
+	/**
+		Called at the end of a replica payload with the `count` bits left over in its last byte, `value` holding them in the low bits.
+
+		These are normally zero padding. Used by [`from_bytes_exact_with`](crate::common::from_bytes_exact_with) to account for the payload to the bit.
+	*/
+	fn trailing_bits(&mut self, _count: u8, _value: u8) {}
+
+	/// Network version to decode for, which fields and variants marked with `#[since(version)]` are checked against. See [`Versioned`] for readers without a context.
+	fn network_version(&self) -> u32 {
+		NETWORK_VERSION_LIVE
+	}
+}
+
+impl<C: ReplicaContext + ?Sized> ReplicaContext for &mut C {
+	fn get_comp_constructions<R: Read>(&mut self, network_id: u16, lot: Lot, config: &Option<LuNameValue>) -> Vec<fn(&mut BEBitReader<R>) -> Res<Box<dyn ComponentConstruction>>> {
+		(**self).get_comp_constructions(network_id, lot, config)
+	}
+
+	fn get_comp_serializations<R: Read>(&mut self, network_id: u16) -> Vec<fn(&mut BEBitReader<R>) -> Res<Box<dyn ComponentSerialization>>> {
+		(**self).get_comp_serializations(network_id)
+	}
+
+	fn trailing_bits(&mut self, count: u8, value: u8) {
+		(**self).trailing_bits(count, value)
+	}
+
+	fn network_version(&self) -> u32 {
+		(**self).network_version()
+	}
+}
+
+/// Wraps the reader of a bit-level payload, so that the bits left in its last byte can be read without reading past it.
+struct PayloadReader<R> {
+	inner: R,
+	ended: bool,
+}
+
+impl<R> NetworkVersion for PayloadReader<&mut R> {
+	fn network_version(&self) -> u32 {
+		NetworkVersion::network_version(&*self.inner)
+	}
+}
+
+impl<R: Read> Read for PayloadReader<R> {
+	fn read(&mut self, buf: &mut [u8]) -> Res<usize> {
+		if self.ended {
+			return Ok(0);
+		}
+		self.inner.read(buf)
+	}
+}
+
+/// Reads the bits left in the last byte of a payload and reports them to the context.
+fn end_payload<R: Read + ReplicaContext>(bit_reader: &mut BEBitReader<PayloadReader<&mut R>>) -> Res<()> {
+	unsafe { bit_reader.get_mut_unchecked() }.ended = true;
+	let mut count = 0;
+	let mut value = 0;
+	while count < 8 {
+		match read_bit(bit_reader) {
+			Ok(bit) => {
+				value = value << 1 | bit as u8;
+				count += 1;
+			}
+			Err(e) if e.kind() == ErrorKind::UnexpectedEof => break,
+			Err(e) => return Err(e),
+		}
+	}
+	unsafe { bit_reader.get_mut_unchecked() }.inner.trailing_bits(count, value);
+	Ok(())
 }
 
 #[derive(Debug, PartialEq, ReplicaSerde)]
@@ -122,7 +194,7 @@ pub struct ParentInfo {
 	pub update_position_with_parent: bool,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ChildInfo {
 	pub child_ids: LVec<u16, ObjId>,
 }
@@ -158,11 +230,37 @@ impl PartialEq<ReplicaConstruction> for ReplicaConstruction {
 	}
 }
 
+/// The components are chosen by the network ID, see [`ArbitraryComponents`].
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ReplicaConstruction {
+	fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+		let network_id = u.arbitrary()?;
+		Ok(Self {
+			network_id,
+			object_id: u.arbitrary()?,
+			lot: u.arbitrary()?,
+			name: u.arbitrary()?,
+			time_since_created_on_server: u.arbitrary()?,
+			config: u.arbitrary()?,
+			is_trigger: u.arbitrary()?,
+			spawner_id: u.arbitrary()?,
+			spawner_node_id: u.arbitrary()?,
+			scale: u.arbitrary()?,
+			world_state: u.arbitrary()?,
+			gm_level: u.arbitrary()?,
+			parent_child_info: u.arbitrary()?,
+			components: arbitrary_constructions(network_id, u)?,
+		})
+	}
+}
+
 impl<R: Read + ReplicaContext> Deserialize<LE, R> for ReplicaConstruction {
 	#[rustfmt::skip]
 	fn deserialize(reader: &mut R) -> Res<Self> {
-		let mut bit_reader = BEBitReader::new(reader);
-		let bit = bit_reader.read_bit()?;
+		#[cfg(feature = "trace")]
+		crate::trace::align();
+		let mut bit_reader = BEBitReader::new(PayloadReader { inner: reader, ended: false });
+		let bit = read_bit(&mut bit_reader)?;
 		assert_eq!(bit, true);
 		let network_id = LERead::read(&mut bit_reader)?;
 		let object_id  = LERead::read(&mut bit_reader)?;
@@ -170,7 +268,7 @@ impl<R: Read + ReplicaContext> Deserialize<LE, R> for ReplicaConstruction {
 		let name       = LERead::read(&mut bit_reader)?;
 		let time_since_created_on_server = LERead::read(&mut bit_reader)?;
 		let config = ReplicaD::deserialize(&mut bit_reader)?;
-		let is_trigger = bit_reader.read_bit()?;
+		let is_trigger = read_bit(&mut bit_reader)?;
 		let spawner_id        = ReplicaD::deserialize(&mut bit_reader)?;
 		let spawner_node_id   = ReplicaD::deserialize(&mut bit_reader)?;
 		let scale             = ReplicaD::deserialize(&mut bit_reader)?;
@@ -178,9 +276,15 @@ impl<R: Read + ReplicaContext> Deserialize<LE, R> for ReplicaConstruction {
 		let gm_level          = ReplicaD::deserialize(&mut bit_reader)?;
 		let parent_child_info = ReplicaD::deserialize(&mut bit_reader)?;
 		let mut components = vec![];
-		for new in unsafe {bit_reader.get_mut_unchecked()}.get_comp_constructions(network_id, lot, &config) {
-			components.push(new(&mut bit_reader)?);
+		for (_i, new) in unsafe {bit_reader.get_mut_unchecked()}.inner.get_comp_constructions(network_id, lot, &config).into_iter().enumerate() {
+			#[cfg(feature = "trace")]
+			let __trace = crate::trace::enter_component(_i);
+			let comp = new(&mut bit_reader)?;
+			#[cfg(feature = "trace")]
+			__trace.exit(&comp);
+			components.push(comp);
 		}
+		end_payload(&mut bit_reader)?;
 
 		Ok(Self {
 			network_id,
@@ -203,7 +307,8 @@ impl<R: Read + ReplicaContext> Deserialize<LE, R> for ReplicaConstruction {
 
 impl<'a, W: Write> Serialize<LE, W> for &'a ReplicaConstruction {
 	fn serialize(self, writer: &mut W) -> Res<()> {
-		let mut bit_writer = BEBitWriter::new(vec![]);
+		let version = NetworkVersion::network_version(&*writer);
+		let mut bit_writer = BEBitWriter::new(Versioned::new(writer as &mut dyn Write, version));
 		bit_writer.write_bit(true)?;
 		LEWrite::write(&mut bit_writer, self.network_id)?;
 		LEWrite::write(&mut bit_writer, self.object_id)?;
@@ -222,9 +327,7 @@ impl<'a, W: Write> Serialize<LE, W> for &'a ReplicaConstruction {
 		for comp in &self.components {
 			comp.ser(&mut bit_writer)?;
 		}
-		bit_writer.flush()?;
-		LEWrite::write(writer, bit_writer.get_ref())?;
-		Ok(())
+		bit_writer.flush()
 	}
 }
 
@@ -242,16 +345,33 @@ impl PartialEq<ReplicaSerialization> for ReplicaSerialization {
 	}
 }
 
+/// The components are chosen by the network ID, see [`ArbitraryComponents`].
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ReplicaSerialization {
+	fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+		let network_id = u.arbitrary()?;
+		Ok(Self { network_id, parent_child_info: u.arbitrary()?, components: arbitrary_serializations(network_id, u)? })
+	}
+}
+
 impl<R: Read + ReplicaContext> Deserialize<LE, R> for ReplicaSerialization {
 	fn deserialize(reader: &mut R) -> Res<Self> {
 		let network_id = LERead::read(reader)?;
 		let comp_desers = reader.get_comp_serializations(network_id);
-		let mut bit_reader = BEBitReader::new(reader);
+		#[cfg(feature = "trace")]
+		crate::trace::align();
+		let mut bit_reader = BEBitReader::new(PayloadReader { inner: reader, ended: false });
 		let parent_child_info = ReplicaD::deserialize(&mut bit_reader)?;
 		let mut components = vec![];
-		for new in comp_desers {
-			components.push(new(&mut bit_reader)?);
+		for (_i, new) in comp_desers.into_iter().enumerate() {
+			#[cfg(feature = "trace")]
+			let __trace = crate::trace::enter_component(_i);
+			let comp = new(&mut bit_reader)?;
+			#[cfg(feature = "trace")]
+			__trace.exit(&comp);
+			components.push(comp);
 		}
+		end_payload(&mut bit_reader)?;
 
 		Ok(Self { network_id, parent_child_info, components })
 	}
@@ -260,18 +380,128 @@ impl<R: Read + ReplicaContext> Deserialize<LE, R> for ReplicaSerialization {
 impl<'a, W: Write> Serialize<LE, W> for &'a ReplicaSerialization {
 	fn serialize(self, writer: &mut W) -> Res<()> {
 		LEWrite::write(writer, self.network_id)?;
-		let mut bit_writer = BEBitWriter::new(vec![]);
+		let version = NetworkVersion::network_version(&*writer);
+		let mut bit_writer = BEBitWriter::new(Versioned::new(writer as &mut dyn Write, version));
 		ReplicaS::serialize(&self.parent_child_info, &mut bit_writer)?;
 
 		for comp in &self.components {
 			comp.ser(&mut bit_writer)?;
 		}
-		bit_writer.flush()?;
-		LEWrite::write(writer, bit_writer.get_ref())?;
-		Ok(())
+		bit_writer.flush()
 	}
 }
 
+/**
+	Context for decoding [arbitrary](arbitrary::Arbitrary) replicas.
+
+	Which components an arbitrary replica has is derived from its network ID, with every component protocol of this module being a candidate. This context derives them the same way, so it can decode any generated construction or serialization.
+*/
+#[cfg(feature = "arbitrary")]
+#[derive(Debug)]
+pub struct ArbitraryComponents;
+
+/// Whether each protocol in [`arbitrary_components!`] is included, about a quarter of them are.
+#[cfg(feature = "arbitrary")]
+fn included_components(network_id: u16) -> impl Iterator<Item = bool> {
+	// splitmix64
+	let mut x = (network_id as u64).wrapping_add(0x9e3779b97f4a7c15);
+	x = (x ^ (x >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+	x = (x ^ (x >> 27)).wrapping_mul(0x94d049bb133111eb);
+	x ^= x >> 31;
+	let mask = x & x.rotate_left(32);
+	(0..64).map(move |i| mask >> i & 1 != 0)
+}
+
+macro_rules! arbitrary_components {
+	($($protocol:path,)*) => {
+		#[cfg(feature = "arbitrary")]
+		fn arbitrary_constructions(network_id: u16, u: &mut arbitrary::Unstructured) -> arbitrary::Result<Vec<Box<dyn ComponentConstruction>>> {
+			let mut included = included_components(network_id);
+			let mut comps: Vec<Box<dyn ComponentConstruction>> = vec![];
+			$(
+				if included.next().unwrap() {
+					comps.push(Box::new(u.arbitrary::<<$protocol as ComponentProtocol>::Construction>()?));
+				}
+			)*
+			Ok(comps)
+		}
+
+		#[cfg(feature = "arbitrary")]
+		fn arbitrary_serializations(network_id: u16, u: &mut arbitrary::Unstructured) -> arbitrary::Result<Vec<Box<dyn ComponentSerialization>>> {
+			let mut included = included_components(network_id);
+			let mut comps: Vec<Box<dyn ComponentSerialization>> = vec![];
+			$(
+				if included.next().unwrap() {
+					comps.push(Box::new(u.arbitrary::<<$protocol as ComponentProtocol>::Serialization>()?));
+				}
+			)*
+			Ok(comps)
+		}
+
+		#[cfg(feature = "arbitrary")]
+		impl ReplicaContext for ArbitraryComponents {
+			fn get_comp_constructions<R: Read>(&mut self, network_id: u16, _lot: Lot, _config: &Option<LuNameValue>) -> Vec<fn(&mut BEBitReader<R>) -> Res<Box<dyn ComponentConstruction>>> {
+				let mut included = included_components(network_id);
+				let mut constrs: Vec<fn(&mut BEBitReader<R>) -> Res<Box<dyn ComponentConstruction>>> = vec![];
+				$(
+					if included.next().unwrap() {
+						constrs.push(|x| Ok(Box::new(<<$protocol as ComponentProtocol>::Construction as Deserialize<LE, BEBitReader<R>>>::deserialize(x)?)));
+					}
+				)*
+				constrs
+			}
+
+			fn get_comp_serializations<R: Read>(&mut self, network_id: u16) -> Vec<fn(&mut BEBitReader<R>) -> Res<Box<dyn ComponentSerialization>>> {
+				let mut included = included_components(network_id);
+				let mut sers: Vec<fn(&mut BEBitReader<R>) -> Res<Box<dyn ComponentSerialization>>> = vec![];
+				$(
+					if included.next().unwrap() {
+						sers.push(|x| Ok(Box::new(<<$protocol as ComponentProtocol>::Serialization as Deserialize<LE, BEBitReader<R>>>::deserialize(x)?)));
+					}
+				)*
+				sers
+			}
+		}
+	};
+}
+
+arbitrary_components! {
+	achievement_vendor::AchievementVendorProtocol,
+	base_combat_ai::BaseCombatAiProtocol,
+	bbb::BbbProtocol,
+	bouncer::BouncerProtocol,
+	buff::BuffProtocol,
+	character::CharacterProtocol,
+	collectible::CollectibleProtocol,
+	controllable_physics::ControllablePhysicsProtocol,
+	destroyable::DestroyableProtocol,
+	donation_vendor::DonationVendorProtocol,
+	fx::FxProtocol,
+	inventory::InventoryProtocol,
+	item::ItemProtocol,
+	level_progression::LevelProgressionProtocol,
+	lup_exhibit::LupExhibitProtocol,
+	module_assembly::ModuleAssemblyProtocol,
+	moving_platform::MovingPlatformProtocol,
+	mutable_model_behavior::MutableModelBehaviorProtocol,
+	pet::PetProtocol,
+	phantom_physics::PhantomPhysicsProtocol,
+	player_forced_movement::PlayerForcedMovementProtocol,
+	possessable::PossessableProtocol,
+	possession_control::PossessionControlProtocol,
+	quickbuild::QuickbuildProtocol,
+	racing_control::RacingControlProtocol,
+	rigid_body_phantom_physics::RigidBodyPhantomPhysicsProtocol,
+	script::ScriptProtocol,
+	scripted_activity::ScriptedActivityProtocol,
+	shooting_gallery::ShootingGalleryProtocol,
+	simple_physics::SimplePhysicsProtocol,
+	skill::SkillProtocol,
+	switch::SwitchProtocol,
+	vehicle_physics::VehiclePhysicsProtocol,
+	vendor::VendorProtocol,
+}
+
 #[cfg(test)]
 #[derive(Debug)]
 pub(super) struct DummyContext<'a> {
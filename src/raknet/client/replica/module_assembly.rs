@@ -1,11 +1,10 @@
 use std::io::Result as Res;
 
 use endio::Serialize;
-use endio_bit::BEBitWriter;
 use lu_packets_derive::{BitVariantTests, ReplicaSerde};
 
 use crate::common::{LuVarWString, ObjId};
-use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization};
+use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ComponentWriter};
 
 #[derive(Debug, PartialEq, ReplicaSerde)]
 pub struct ModuleAssemblyInfo {
@@ -20,7 +19,7 @@ pub struct ModuleAssemblyConstruction {
 }
 
 impl ComponentConstruction for ModuleAssemblyConstruction {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -29,7 +28,7 @@ impl ComponentConstruction for ModuleAssemblyConstruction {
 pub struct ModuleAssemblySerialization {}
 
 impl ComponentSerialization for ModuleAssemblySerialization {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -4,9 +4,9 @@ use endio::{Deserialize, LE, Serialize};
 use endio_bit::{BEBitReader, BEBitWriter};
 use lu_packets_derive::{BitVariantTests, ReplicaSerde};
 
-use crate::common::LuVarWString;
+use crate::common::{LuVarWString, read_bit};
 use crate::world::Vector3;
-use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ReplicaD};
+use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ComponentWriter, ReplicaD};
 use super::simple_physics::PositionRotationInfo;
 
 #[derive(Debug, PartialEq, ReplicaSerde)]
@@ -60,14 +60,25 @@ pub struct MovingPlatformConstruction {
 	pub subcomponent_infos: Option<Vec<PlatformSubcomponentInfo>>,
 }
 
+// path info with an empty path name isn't written
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for MovingPlatformConstruction {
+	fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+		let path_info: Option<PlatformPathInfo> = u.arbitrary()?;
+		Ok(Self { path_info: path_info.filter(|x| !x.path_name.is_empty()), subcomponent_infos: u.arbitrary()? })
+	}
+}
+
 impl<R: Read> Deserialize<LE, BEBitReader<R>> for MovingPlatformConstruction {
 	fn deserialize(reader: &mut BEBitReader<R>) -> Res<Self> {
-		let has_subcomponent_infos = reader.read_bit()?;
-		let flag = reader.read_bit()?;
+		#[cfg(feature = "trace")]
+		let _trace_type = crate::trace::enter_type("MovingPlatformConstruction");
+		let has_subcomponent_infos = read_bit(reader)?;
+		let flag = read_bit(reader)?;
 		let path_info = if flag { ReplicaD::deserialize(reader)? } else { None };
 		let subcomponent_infos = if has_subcomponent_infos {
 			let mut infos = vec![];
-			while reader.read_bit()? {
+			while read_bit(reader)? {
 				let subcomp = ReplicaD::deserialize(reader)?;
 				infos.push(subcomp);
 			}
@@ -105,7 +116,7 @@ impl<'a, W: Write> Serialize<LE, BEBitWriter<W>> for &'a MovingPlatformConstruct
 }
 
 impl ComponentConstruction for MovingPlatformConstruction {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -113,7 +124,7 @@ impl ComponentConstruction for MovingPlatformConstruction {
 pub type MovingPlatformSerialization = MovingPlatformConstruction;
 
 impl ComponentSerialization for MovingPlatformSerialization {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -1,14 +1,13 @@
 use std::io::Result as Res;
 
 use endio::{Deserialize, Serialize};
-use endio_bit::BEBitWriter;
-use lu_packets_derive::{BitVariantTests, ReplicaSerde};
+use lu_packets_derive::{Arbitrary, BitVariantTests, ReplicaSerde};
 
 use crate::common::ObjId;
 use crate::world::{Vector3, Quaternion};
-use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization};
+use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ComponentWriter};
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Serialize)]
 #[repr(i32)]
 pub enum PhysicsBehaviorType {
 	/// todo: option
@@ -47,7 +46,7 @@ pub struct MutableModelBehaviorConstruction {
 }
 
 impl ComponentConstruction for MutableModelBehaviorConstruction {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -65,7 +64,7 @@ pub struct MutableModelBehaviorSerialization {
 }
 
 impl ComponentSerialization for MutableModelBehaviorSerialization {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
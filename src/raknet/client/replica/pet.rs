@@ -1,14 +1,13 @@
 use std::io::Result as Res;
 
 use endio::{Deserialize, Serialize};
-use endio_bit::BEBitWriter;
-use lu_packets_derive::{BitVariantTests, ReplicaSerde};
+use lu_packets_derive::{Arbitrary, BitVariantTests, ReplicaSerde};
 
 use crate::common::{LuVarWString, ObjId};
 use crate::world::gm::client::{PetAbilityType, PetModerationStatus};
-use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization};
+use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ComponentWriter};
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Serialize)]
 #[repr(u8)]
 pub enum PossessionType {
 	NoPossession,
@@ -40,7 +39,7 @@ pub struct PetConstruction {
 }
 
 impl ComponentConstruction for PetConstruction {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -48,7 +47,7 @@ impl ComponentConstruction for PetConstruction {
 pub type PetSerialization = PetConstruction;
 
 impl ComponentSerialization for PetSerialization {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -1,14 +1,13 @@
 use std::io::{Result as Res};
 
 use endio::{Deserialize, Serialize};
-use endio_bit::BEBitWriter;
-use lu_packets_derive::{BitVariantTests, ReplicaSerde};
+use lu_packets_derive::{Arbitrary, BitVariantTests, ReplicaSerde};
 
 use crate::world::Vector3;
-use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization};
+use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ComponentWriter};
 use super::simple_physics::PositionRotationInfo;
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Serialize)]
 #[repr(u32)]
 pub enum PhysicsEffectType {
 	Push,
@@ -44,7 +43,7 @@ pub struct PhantomPhysicsConstruction {
 }
 
 impl ComponentConstruction for PhantomPhysicsConstruction {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -52,7 +51,7 @@ impl ComponentConstruction for PhantomPhysicsConstruction {
 pub type PhantomPhysicsSerialization = PhantomPhysicsConstruction;
 
 impl ComponentSerialization for PhantomPhysicsSerialization {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
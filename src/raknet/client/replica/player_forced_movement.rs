@@ -1,10 +1,9 @@
 use std::io::Result as Res;
 
 use endio::Serialize;
-use endio_bit::BEBitWriter;
 use lu_packets_derive::{BitVariantTests, ReplicaSerde};
 
-use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization};
+use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ComponentWriter};
 
 #[derive(Debug, PartialEq, ReplicaSerde)]
 pub struct ForcedMovementInfo {
@@ -18,7 +17,7 @@ pub struct PlayerForcedMovementConstruction {
 }
 
 impl ComponentConstruction for PlayerForcedMovementConstruction {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -26,7 +25,7 @@ impl ComponentConstruction for PlayerForcedMovementConstruction {
 pub type PlayerForcedMovementSerialization = PlayerForcedMovementConstruction;
 
 impl ComponentSerialization for PlayerForcedMovementSerialization {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -2,10 +2,10 @@ use std::io::{Read, Result as Res, Write};
 
 use endio::{Deserialize, LE, Serialize};
 use endio_bit::{BEBitReader, BEBitWriter};
-use lu_packets_derive::{BitVariantTests, ReplicaSerde};
+use lu_packets_derive::{Arbitrary, BitVariantTests, ReplicaSerde};
 
-use crate::common::{LuVarWString, LVec, ObjId};
-use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ReplicaD};
+use crate::common::{LuVarWString, LVec, ObjId, read_bit};
+use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ComponentWriter, ReplicaD};
 use super::scripted_activity::ActivityUserInfo;
 
 #[derive(Debug, PartialEq, ReplicaSerde)]
@@ -35,7 +35,7 @@ pub struct DuringRacePlayerInfo {
 	pub race_time: f32,
 }
 
-#[derive(BitVariantTests, Debug, PartialEq)]
+#[derive(Arbitrary, BitVariantTests, Debug, PartialEq)]
 pub struct RacingControlConstruction {
 	pub activity_user_infos: Option<LVec<u32, ActivityUserInfo>>,
 	pub expected_player_count: Option<u16>,
@@ -47,11 +47,13 @@ pub struct RacingControlConstruction {
 
 impl<R: Read> Deserialize<LE, BEBitReader<R>> for RacingControlConstruction {
 	fn deserialize(reader: &mut BEBitReader<R>) -> Res<Self> {
+		#[cfg(feature = "trace")]
+		let _trace_type = crate::trace::enter_type("RacingControlConstruction");
 		let activity_user_infos = ReplicaD::deserialize(reader)?;
 		let expected_player_count = ReplicaD::deserialize(reader)?;
-		let pre_race_player_infos = if reader.read_bit()? {
+		let pre_race_player_infos = if read_bit(reader)? {
 			let mut infos = vec![];
-			while reader.read_bit()? {
+			while read_bit(reader)? {
 				let info = ReplicaD::deserialize(reader)?;
 				infos.push(info);
 			}
@@ -59,9 +61,9 @@ impl<R: Read> Deserialize<LE, BEBitReader<R>> for RacingControlConstruction {
 		} else {
 			None
 		};
-		let post_race_player_infos = if reader.read_bit()? {
+		let post_race_player_infos = if read_bit(reader)? {
 			let mut infos = vec![];
-			while reader.read_bit()? {
+			while read_bit(reader)? {
 				let info = ReplicaD::deserialize(reader)?;
 				infos.push(info);
 			}
@@ -70,9 +72,9 @@ impl<R: Read> Deserialize<LE, BEBitReader<R>> for RacingControlConstruction {
 			None
 		};
 		let race_info = ReplicaD::deserialize(reader)?;
-		let during_race_player_infos = if reader.read_bit()? {
+		let during_race_player_infos = if read_bit(reader)? {
 			let mut infos = vec![];
-			while reader.read_bit()? {
+			while read_bit(reader)? {
 				let info = ReplicaD::deserialize(reader)?;
 				infos.push(info);
 			}
@@ -118,7 +120,7 @@ impl<'a, W: Write> Serialize<LE, BEBitWriter<W>> for &'a RacingControlConstructi
 }
 
 impl ComponentConstruction for RacingControlConstruction {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
@@ -126,7 +128,7 @@ impl ComponentConstruction for RacingControlConstruction {
 pub type RacingControlSerialization = RacingControlConstruction;
 
 impl ComponentSerialization for RacingControlSerialization {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
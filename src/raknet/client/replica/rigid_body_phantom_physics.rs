@@ -1,10 +1,9 @@
 use std::io::Result as Res;
 
 use endio::Serialize;
-use endio_bit::BEBitWriter;
 use lu_packets_derive::{BitVariantTests, ReplicaSerde};
 
-use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization};
+use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ComponentWriter};
 use super::simple_physics::PositionRotationInfo;
 
 #[derive(BitVariantTests, Debug, PartialEq, ReplicaSerde)]
@@ -13,7 +12,7 @@ pub struct RigidBodyPhantomPhysicsConstruction {
 }
 
 impl ComponentConstruction for RigidBodyPhantomPhysicsConstruction {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		Serialize::serialize(self, writer)
 	}
 }
@@ -21,7 +20,7 @@ impl ComponentConstruction for RigidBodyPhantomPhysicsConstruction {
 pub type RigidBodyPhantomPhysicsSerialization = RigidBodyPhantomPhysicsConstruction;
 
 impl ComponentSerialization for RigidBodyPhantomPhysicsSerialization {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		Serialize::serialize(self, writer)
 	}
 }
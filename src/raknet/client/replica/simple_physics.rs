@@ -1,13 +1,12 @@
 use std::io::Result as Res;
 
 use endio::{Deserialize, Serialize};
-use endio_bit::BEBitWriter;
-use lu_packets_derive::{BitVariantTests, ReplicaSerde};
+use lu_packets_derive::{Arbitrary, BitVariantTests, ReplicaSerde};
 
 use crate::world::{Vector3, Quaternion};
-use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization};
+use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ComponentWriter};
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Serialize)]
 #[repr(u32)]
 pub enum ClimbingProperty {
 	None,
@@ -22,7 +21,7 @@ pub struct VelocityInfo {
 	pub angular_velocity: Vector3,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Serialize)]
 #[repr(u32)]
 pub enum MotionType {
 	Dynamic = 1,
@@ -56,13 +55,13 @@ pub struct SimplePhysicsSerialization {
 }
 
 impl ComponentConstruction for SimplePhysicsConstruction {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		Serialize::serialize(self, writer)
 	}
 }
 
 impl ComponentSerialization for SimplePhysicsSerialization {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		Serialize::serialize(self, writer)
 	}
 }
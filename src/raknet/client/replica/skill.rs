@@ -1,16 +1,17 @@
 use std::io::Result as Res;
 
-use endio::Serialize;
-use endio_bit::BEBitWriter;
-use lu_packets_derive::{BitVariantTests, ReplicaSerde};
+use endio::{Deserialize, Serialize};
+use lu_packets_derive::{Arbitrary, BitVariantTests, ReplicaSerde};
 
 use crate::common::{LVec, ObjId};
-use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization};
+use crate::world::gm::{BehaviorId, CastType, SkillId};
+use super::{ComponentConstruction, ComponentProtocol, ComponentSerialization, ComponentWriter};
 
 #[derive(Debug, PartialEq, ReplicaSerde)]
 pub struct BehaviorInfo {
 	pub unknown_1: u32,
-	pub action: u32, // todo: type
+	/// The behavior being run.
+	pub action: BehaviorId,
 	pub wait_time_ms: u32,
 	pub template_id: u32, // todo: type
 	pub caster: ObjId,
@@ -22,12 +23,16 @@ pub struct BehaviorInfo {
 	pub imagination_cost: u32,
 }
 
+/// How a skill in progress gets cancelled. The values aren't documented yet.
+#[derive(Arbitrary, Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct SkillCancelType(pub u32);
+
 #[derive(Debug, PartialEq, ReplicaSerde)]
 pub struct SkillInfo {
 	pub unknown_1: u32,
-	pub skill_id: u32,    // todo: type
-	pub cast_type: u32,   // todo: type
-	pub cancel_type: u32, // todo: type
+	pub skill_id: SkillId,
+	pub cast_type: CastType,
+	pub cancel_type: SkillCancelType,
 	pub behaviors: LVec<u32, BehaviorInfo>,
 }
 
@@ -40,13 +45,13 @@ pub struct SkillConstruction {
 pub struct SkillSerialization {}
 
 impl ComponentConstruction for SkillConstruction {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
 
 impl ComponentSerialization for SkillSerialization {
-	fn ser(&self, writer: &mut BEBitWriter<Vec<u8>>) -> Res<()> {
+	fn ser(&self, writer: &mut ComponentWriter) -> Res<()> {
 		self.serialize(writer)
 	}
 }
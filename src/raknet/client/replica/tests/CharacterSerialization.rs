@@ -8,7 +8,7 @@ CharacterSerialization {
 	}),
 	current_activity: Some(GameActivity::Quickbuilding),
 	social_info: Some(SocialInfo {
-		guild_id: 46,
+		guild_id: GuildId(46),
 		guild_name: lu!("guild"),
 		is_lego_club_member: true,
 	}),
@@ -2,13 +2,13 @@ SkillConstruction {
 	skills_in_progress: Some(vec![
 		SkillInfo {
 			unknown_1: 0,
-			skill_id: 1,
-			cast_type: 2,
-			cancel_type: 3,
+			skill_id: SkillId(1),
+			cast_type: CastType::EverlastingConsumable,
+			cancel_type: SkillCancelType(3),
 			behaviors: vec![
 				BehaviorInfo {
 					unknown_1: 4,
-					action: 5,
+					action: BehaviorId(5),
 					wait_time_ms: 6,
 					template_id: 7,
 					caster: 8,
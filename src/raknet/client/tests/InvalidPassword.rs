@@ -0,0 +1 @@
+Message::InvalidPassword
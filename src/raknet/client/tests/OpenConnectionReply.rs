@@ -0,0 +1,3 @@
+Message::OpenConnectionReply(
+	OpenConnectionReply,
+)
@@ -0,0 +1,10 @@
+Message::Timestamp(
+	Timestamp {
+		timestamp: 3481264,
+		message: crate::raknet::TimestampedMessage::UserMessage(
+			crate::world::client::LuMessage::Client(
+				crate::world::client::ClientMessage::ImpendingReloadNotify,
+			),
+		),
+	},
+)
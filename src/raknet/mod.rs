@@ -2,13 +2,74 @@
 pub mod client;
 pub mod server;
 
+use std::io::{Read, Write};
+use std::io::Result as Res;
 use std::net::Ipv4Addr;
 
-use endio::{Deserialize, Serialize};
+use endio::{Deserialize, LERead, Serialize};
+use lu_packets_derive::{Arbitrary, MessageDeserialize, Reflect, Schema};
+use endio::LittleEndian as LE;
 
 /// A combination of Ipv4Addr and port. todo: just use SocketAddrV4
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct SystemAddress {
 	pub ip: Ipv4Addr,
 	pub port: u16,
 }
+
+/**
+	Prefixes a message with the time it was sent at.
+
+	Raknet translates the timestamp from the sender's clock to the receiver's clock, which makes it possible to compensate for latency in time-sensitive messages.
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct Timestamp<U> {
+	/// Send time in milliseconds, in the receiver's clock.
+	pub timestamp: u32,
+	/// The timestamped message.
+	pub message: TimestampedMessage<U>,
+}
+
+/// Messages that can be prefixed with a [`Timestamp`]. LU only timestamps user messages.
+#[derive(Arbitrary, Debug, MessageDeserialize, PartialEq, Reflect, Schema, Serialize)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum TimestampedMessage<U> {
+	UserMessage(U) = 83,
+}
+
+/**
+	Offline message data sent by [`AdvertiseSystem`](server::Message::AdvertiseSystem).
+
+	The data is application-defined, LU doesn't use this.
+*/
+#[derive(Arbitrary, Debug, PartialEq, Reflect)]
+pub struct AdvertiseSystem {
+	pub data: Box<[u8]>,
+}
+
+impl<R: Read> Deserialize<LE, R> for AdvertiseSystem {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let mut data = vec![];
+		reader.read_to_end(&mut data)?;
+		let data = data.into_boxed_slice();
+		Ok(Self { data })
+	}
+}
+
+impl<'a, W: Write> Serialize<LE, W> for &'a AdvertiseSystem {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		writer.write_all(&self.data)?;
+		Ok(())
+	}
+}
+
+/// Offline messages consisting of just the message ID are padded to two bytes, because some routers drop one-byte packets.
+fn read_offline_padding<R: Read>(reader: &mut R) -> Res<()> {
+	let _: u8 = LERead::read(reader)?;
+	Ok(())
+}
+
+fn write_offline_padding<W: Write>(writer: &mut W) -> Res<()> {
+	writer.write_all(&[0])
+}
@@ -4,28 +4,33 @@ use std::io::Result as Res;
 
 use endio::{Deserialize, Serialize};
 use endio::LittleEndian as LE;
-use lu_packets_derive::VariantTests;
+use lu_packets_derive::{Arbitrary, MessageDeserialize, Reflect, Schema, VariantTests};
 
-use super::SystemAddress;
+use super::{read_offline_padding, write_offline_padding, AdvertiseSystem, SystemAddress, Timestamp};
 
-#[derive(Debug, Deserialize, PartialEq, Serialize, VariantTests)]
+#[derive(Arbitrary, Debug, MessageDeserialize, PartialEq, Reflect, Schema, Serialize, VariantTests)]
 #[test_params(crate::world::server::LuMessage)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum Message<U> {
 	InternalPing(InternalPing) = 0,
 	ConnectionRequest(ConnectionRequest) = 4,
+	OpenConnectionRequest(OpenConnectionRequest) = 9,
 	NewIncomingConnection(NewIncomingConnection) = 17,
 	DisconnectionNotification = 19,
+	/// Generated locally by raknet when a connection times out, never sent over the network.
+	ConnectionLost = 20,
+	Timestamp(Timestamp<U>) = 25,
+	AdvertiseSystem(AdvertiseSystem) = 27,
 	UserMessage(U) = 83,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct InternalPing {
 	pub send_time: u32,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Arbitrary, Debug, PartialEq, Reflect)]
 pub struct ConnectionRequest {
 	pub password: Box<[u8]>,
 }
@@ -46,7 +51,29 @@ impl<'a, W: Write> Serialize<LE, W> for &'a ConnectionRequest {
 	}
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+/**
+	Offline message initiating a connection.
+
+	### Response
+	Respond with [`OpenConnectionReply`](crate::raknet::client::OpenConnectionReply) if there is a free connection slot, otherwise with [`NoFreeIncomingConnections`](crate::raknet::client::Message::NoFreeIncomingConnections), or [`ConnectionBanned`](crate::raknet::client::Message::ConnectionBanned) if the address is banned.
+*/
+#[derive(Arbitrary, Debug, PartialEq, Reflect)]
+pub struct OpenConnectionRequest;
+
+impl<R: Read> Deserialize<LE, R> for OpenConnectionRequest {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		read_offline_padding(reader)?;
+		Ok(Self)
+	}
+}
+
+impl<'a, W: Write> Serialize<LE, W> for &'a OpenConnectionRequest {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		write_offline_padding(writer)
+	}
+}
+
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct NewIncomingConnection {
 	pub peer_addr: SystemAddress,
 	pub local_addr: SystemAddress,
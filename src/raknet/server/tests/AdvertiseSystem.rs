@@ -0,0 +1,5 @@
+Message::AdvertiseSystem(
+	AdvertiseSystem {
+		data: Vec::from(&b"LU server"[..]).into_boxed_slice(),
+	},
+)
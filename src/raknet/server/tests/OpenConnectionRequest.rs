@@ -0,0 +1,3 @@
+Message::OpenConnectionRequest(
+	OpenConnectionRequest,
+)
@@ -0,0 +1,366 @@
+/*!
+	Runtime reflection over message fields.
+
+	Enabled with the `reflect` feature. Types deriving [`Reflect`](lu_packets_derive::Reflect), [`GameMessage`](lu_packets_derive::GameMessage) or [`ReplicaSerde`](lu_packets_derive::ReplicaSerde) list their fields, which can be read and written as dynamic [`Value`]s, without knowing the concrete type. This is meant for generic tooling like packet editors and fuzzers.
+
+	Nested fields are addressed with paths like `zone_id.map_id`. Enums expose the fields of their current variant.
+*/
+use std::any::type_name;
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::common::LVec;
+use crate::world::{LuNameValue, Quaternion, Vector3};
+use crate::world::amf3::Amf3;
+
+/// A type with named fields, or an enum with the fields of its current variant.
+pub trait Reflect {
+	/// Name of the type.
+	fn type_name(&self) -> &'static str;
+
+	/// Name of the current variant, for enums.
+	fn variant(&self) -> Option<&'static str> {
+		None
+	}
+
+	/// Names of the variants, for enums.
+	fn variants(&self) -> &'static [&'static str] {
+		&[]
+	}
+
+	/// Switches to a variant without fields.
+	fn set_variant(&mut self, variant: &str) -> Result<(), ReflectError> {
+		Err(ReflectError::NoVariant(variant.into()))
+	}
+
+	/// Fields of the struct, or of the current variant. Tuple fields are named by their index.
+	fn fields(&self) -> Vec<(&'static str, &dyn DynValue)>;
+
+	fn fields_mut(&mut self) -> Vec<(&'static str, &mut dyn DynValue)>;
+
+	/// Returns the value of the field at `path`.
+	fn get(&self, path: &str) -> Option<Value> {
+		let (name, rest) = split_path(path);
+		let (_, field) = self.fields().into_iter().find(|(x, _)| *x == name)?;
+		match rest {
+			None => Some(field.value()),
+			Some(rest) => field.reflect()?.get(rest),
+		}
+	}
+
+	/// Sets the field at `path`, which fails if there's no such field or the value has the wrong type.
+	fn set(&mut self, path: &str, value: Value) -> Result<(), ReflectError> {
+		let (name, rest) = split_path(path);
+		let field = match self.fields_mut().into_iter().find(|(x, _)| *x == name) {
+			Some((_, x)) => x,
+			None => return Err(ReflectError::NoField(path.into())),
+		};
+		match rest {
+			None => field.set_value(value),
+			Some(rest) => match field.reflect_mut() {
+				Some(x) => x.set(rest, value),
+				None => Err(ReflectError::NoField(path.into())),
+			},
+		}
+	}
+}
+
+fn split_path(path: &str) -> (&str, Option<&str>) {
+	match path.find('.') {
+		Some(i) => (&path[..i], Some(&path[i + 1..])),
+		None => (path, None),
+	}
+}
+
+/// Value of a field.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+	Bool(bool),
+	U8(u8),
+	U16(u16),
+	U32(u32),
+	U64(u64),
+	I8(i8),
+	I16(i16),
+	I32(i32),
+	I64(i64),
+	F32(f32),
+	F64(f64),
+	/// Any of the LU string types.
+	String(String),
+	Bytes(Vec<u8>),
+	Vector3(Vector3),
+	Quaternion(Quaternion),
+	LuNameValue(LuNameValue),
+	Amf3(Amf3),
+	Option(Option<Box<Value>>),
+	List(Vec<Value>),
+	/// Snapshot of a [`Reflect`] type. Can't be set as a whole, set its fields instead.
+	Struct { name: &'static str, variant: Option<&'static str>, fields: Vec<(&'static str, Value)> },
+	/// Value of a type without reflection support, identified by its type name.
+	Unsupported(&'static str),
+}
+
+#[derive(Debug)]
+pub enum ReflectError {
+	/// There's no field at this path.
+	NoField(String),
+	/// There's no variant with this name, or it has fields.
+	NoVariant(String),
+	/// The value doesn't fit the field's type.
+	WrongType { expected: &'static str, value: Value },
+}
+
+impl Display for ReflectError {
+	fn fmt(&self, f: &mut Formatter) -> FmtResult {
+		match self {
+			Self::NoField(x) => write!(f, "no field {}", x),
+			Self::NoVariant(x) => write!(f, "no variant {} without fields", x),
+			Self::WrongType { expected, value } => write!(f, "expected {}, got {:?}", expected, value),
+		}
+	}
+}
+
+impl std::error::Error for ReflectError {}
+
+/// Conversion of a field type to and from [`Value`]. Implemented for all types, with types that aren't supported converting to [`Value::Unsupported`].
+pub trait ReflectValue: Sized {
+	fn to_value(&self) -> Value;
+	/// Converts back from a value, returning the value if it doesn't fit.
+	fn from_value(value: Value) -> Result<Self, Value>;
+	/// The nested fields, for types implementing [`Reflect`].
+	fn as_reflect(&self) -> Option<&dyn Reflect>;
+	fn as_reflect_mut(&mut self) -> Option<&mut dyn Reflect>;
+}
+
+impl<T> ReflectValue for T {
+	default fn to_value(&self) -> Value {
+		Value::Unsupported(type_name::<T>())
+	}
+
+	default fn from_value(value: Value) -> Result<Self, Value> {
+		Err(value)
+	}
+
+	default fn as_reflect(&self) -> Option<&dyn Reflect> {
+		None
+	}
+
+	default fn as_reflect_mut(&mut self) -> Option<&mut dyn Reflect> {
+		None
+	}
+}
+
+impl<T: Reflect> ReflectValue for T {
+	default fn to_value(&self) -> Value {
+		let fields = self.fields().into_iter().map(|(name, x)| (name, x.value())).collect();
+		Value::Struct { name: self.type_name(), variant: self.variant(), fields }
+	}
+
+	default fn from_value(value: Value) -> Result<Self, Value> {
+		Err(value)
+	}
+
+	default fn as_reflect(&self) -> Option<&dyn Reflect> {
+		Some(self)
+	}
+
+	default fn as_reflect_mut(&mut self) -> Option<&mut dyn Reflect> {
+		Some(self)
+	}
+}
+
+/// Object-safe version of [`ReflectValue`], used for the fields of [`Reflect`] types.
+pub trait DynValue {
+	fn value_type(&self) -> &'static str;
+	fn value(&self) -> Value;
+	fn set_value(&mut self, value: Value) -> Result<(), ReflectError>;
+	fn reflect(&self) -> Option<&dyn Reflect>;
+	fn reflect_mut(&mut self) -> Option<&mut dyn Reflect>;
+}
+
+impl<T> DynValue for T {
+	fn value_type(&self) -> &'static str {
+		type_name::<T>()
+	}
+
+	fn value(&self) -> Value {
+		self.to_value()
+	}
+
+	fn set_value(&mut self, value: Value) -> Result<(), ReflectError> {
+		*self = T::from_value(value).map_err(|value| ReflectError::WrongType { expected: type_name::<T>(), value })?;
+		Ok(())
+	}
+
+	fn reflect(&self) -> Option<&dyn Reflect> {
+		ReflectValue::as_reflect(self)
+	}
+
+	fn reflect_mut(&mut self) -> Option<&mut dyn Reflect> {
+		ReflectValue::as_reflect_mut(self)
+	}
+}
+
+/// Implements [`ReflectValue`] for a type stored directly in a [`Value`] variant.
+macro_rules! reflect_value {
+	($typ:ty, $variant:ident) => {
+		impl ReflectValue for $typ {
+			fn to_value(&self) -> Value {
+				Value::$variant(self.clone())
+			}
+
+			fn from_value(value: Value) -> Result<Self, Value> {
+				match value {
+					Value::$variant(x) => Ok(x),
+					x => Err(x),
+				}
+			}
+		}
+	};
+}
+
+reflect_value!(bool, Bool);
+reflect_value!(u8, U8);
+reflect_value!(u16, U16);
+reflect_value!(u32, U32);
+reflect_value!(u64, U64);
+reflect_value!(i8, I8);
+reflect_value!(i16, I16);
+reflect_value!(i32, I32);
+reflect_value!(i64, I64);
+reflect_value!(f32, F32);
+reflect_value!(f64, F64);
+reflect_value!(Vector3, Vector3);
+reflect_value!(Quaternion, Quaternion);
+reflect_value!(LuNameValue, LuNameValue);
+reflect_value!(Amf3, Amf3);
+reflect_value!(Vec<u8>, Bytes);
+
+impl ReflectValue for Box<[u8]> {
+	fn to_value(&self) -> Value {
+		Value::Bytes(self.to_vec())
+	}
+
+	fn from_value(value: Value) -> Result<Self, Value> {
+		match value {
+			Value::Bytes(x) => Ok(x.into()),
+			x => Err(x),
+		}
+	}
+}
+
+impl<T> ReflectValue for Option<T> {
+	fn to_value(&self) -> Value {
+		Value::Option(self.as_ref().map(|x| Box::new(x.to_value())))
+	}
+
+	fn from_value(value: Value) -> Result<Self, Value> {
+		match value {
+			Value::Option(None) => Ok(None),
+			Value::Option(Some(x)) => T::from_value(*x).map(Some).map_err(|x| Value::Option(Some(Box::new(x)))),
+			x => Err(x),
+		}
+	}
+
+	fn as_reflect(&self) -> Option<&dyn Reflect> {
+		self.as_ref().and_then(ReflectValue::as_reflect)
+	}
+
+	fn as_reflect_mut(&mut self) -> Option<&mut dyn Reflect> {
+		self.as_mut().and_then(ReflectValue::as_reflect_mut)
+	}
+}
+
+fn list_from_value<T>(value: Value) -> Result<Vec<T>, Value> {
+	let values = match value {
+		Value::List(x) => x,
+		x => return Err(x),
+	};
+	let mut list = Vec::with_capacity(values.len());
+	let mut values = values.into_iter();
+	while let Some(x) = values.next() {
+		match T::from_value(x) {
+			Ok(x) => list.push(x),
+			Err(x) => {
+				// give back the unconverted value, with the rest of the list
+				let mut rest: Vec<_> = list.iter().map(T::to_value).collect();
+				rest.push(x);
+				rest.extend(values);
+				return Err(Value::List(rest));
+			}
+		}
+	}
+	Ok(list)
+}
+
+impl<T> ReflectValue for Vec<T> {
+	default fn to_value(&self) -> Value {
+		Value::List(self.iter().map(T::to_value).collect())
+	}
+
+	default fn from_value(value: Value) -> Result<Self, Value> {
+		list_from_value(value)
+	}
+}
+
+impl<L, T> ReflectValue for LVec<L, T> {
+	default fn to_value(&self) -> Value {
+		Value::List(self.iter().map(T::to_value).collect())
+	}
+
+	default fn from_value(value: Value) -> Result<Self, Value> {
+		list_from_value(value).map(Into::into)
+	}
+}
+
+impl<L> ReflectValue for LVec<L, u8> {
+	fn to_value(&self) -> Value {
+		Value::Bytes(self.to_vec())
+	}
+
+	fn from_value(value: Value) -> Result<Self, Value> {
+		match value {
+			Value::Bytes(x) => Ok(x.into()),
+			x => Err(x),
+		}
+	}
+}
+
+impl<T, const N: usize> ReflectValue for [T; N] {
+	fn to_value(&self) -> Value {
+		Value::List(self.iter().map(T::to_value).collect())
+	}
+
+	fn from_value(value: Value) -> Result<Self, Value> {
+		let list = list_from_value(value)?;
+		<[T; N]>::try_from(list).map_err(|x: Vec<T>| Value::List(x.iter().map(T::to_value).collect()))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::world::ZoneId;
+	use crate::world::server::LevelLoadComplete;
+
+	#[test]
+	fn get_set() {
+		let mut msg = LevelLoadComplete { zone_id: ZoneId { map_id: 1000, instance_id: 1, clone_id: 0 } };
+		assert_eq!(msg.type_name(), "LevelLoadComplete");
+		assert_eq!(msg.fields()[0].0, "zone_id");
+		assert_eq!(msg.get("zone_id.map_id"), Some(Value::U16(1000)));
+		msg.set("zone_id.instance_id", Value::U16(2)).unwrap();
+		assert_eq!(msg.zone_id.instance_id, 2);
+		assert!(matches!(msg.set("zone_id.clone_id", Value::Bool(true)), Err(ReflectError::WrongType { .. })));
+		assert!(matches!(msg.set("zone_id.nonexistent", Value::U16(2)), Err(ReflectError::NoField(_))));
+	}
+
+	#[test]
+	fn option() {
+		let mut val: Option<u32> = None;
+		val.set_value(Value::Option(Some(Box::new(Value::U32(3))))).unwrap();
+		assert_eq!(val, Some(3));
+		assert_eq!(val.value(), Value::Option(Some(Box::new(Value::U32(3)))));
+	}
+}
@@ -0,0 +1,315 @@
+/*!
+	Machine-readable description of the wire format.
+
+	Enabled with the `schema` feature. Types deriving [`Schema`](lu_packets_derive::Schema) or [`GameMessage`](lu_packets_derive::GameMessage) describe their layout, including padding, discriminants and the bit-level encoding of game messages. Types without a description, such as ones with hand-written (de-)serialization, are reported as [`Type::Opaque`].
+
+	The `gen_dissector` binary turns the schema of [`unified::Message`](crate::unified::Message) into a Wireshark dissector.
+*/
+use std::any::type_name;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::common::{AsciiChar, LVec, Ucs2Char};
+use crate::world::{LnvValue, LuNameValue};
+use crate::world::amf3::Amf3;
+
+/// A type with a known wire layout.
+pub trait Schema {
+	fn schema() -> Type;
+}
+
+impl<T> Schema for T {
+	default fn schema() -> Type {
+		Type::Opaque(type_name::<T>())
+	}
+}
+
+/// Layout of a type.
+#[derive(Debug)]
+pub enum Type {
+	/// One byte, or one bit when read at bit level.
+	Bool,
+	U8,
+	U16,
+	U32,
+	U64,
+	I8,
+	I16,
+	I32,
+	I64,
+	F32,
+	F64,
+	/// String character, UTF-16 code unit if `wide`.
+	Char { wide: bool },
+	/// Fixed number of elements, e.g. fixed-length strings.
+	Array { elem: Box<Type>, len: usize },
+	/// Length-prefixed elements.
+	List { len: Box<Type>, elem: Box<Type> },
+	/// All remaining bytes of the message.
+	Rest,
+	/**
+		[`LuNameValue`](crate::world::LuNameValue), with entries of type `value`.
+
+		Encoded as a length-prefixed, usually zlib-compressed list of entries, except in game messages, where it's a length-prefixed wide string of `key=type:value` lines followed by a null character if not empty.
+	*/
+	LuNameValue { value: Box<Type> },
+	/// [`Amf3`](crate::world::amf3::Amf3) value.
+	Amf3,
+	Struct(Struct),
+	Enum(Enum),
+	/// Type whose layout isn't described. Nothing after it can be located.
+	Opaque(&'static str),
+}
+
+#[derive(Debug)]
+pub struct Struct {
+	/// Unique name, used for deduplication.
+	pub id: &'static str,
+	pub name: &'static str,
+	pub fields: Vec<Field>,
+	pub trailing_padding: usize,
+	/**
+		Whether this is a game message.
+
+		Game messages switch to bit-level reading at their first `bool` or defaulted field, for the rest of the message.
+	*/
+	pub game_message: bool,
+	/// Whether this is read at bit level throughout, with `bool`s as single bits. This is the case for replica data, and for game messages with bit-level parts that the first `bool` or defaulted field doesn't precede, like [`ClientTradeUpdate`](crate::world::gm::server::ClientTradeUpdate).
+	pub replica: bool,
+}
+
+#[derive(Debug)]
+pub struct Field {
+	pub name: &'static str,
+	pub ty: Type,
+	/// Padding bytes before the field.
+	pub padding: usize,
+	/// Whether the field is preceded by a flag bit and only present if the flag is set (game messages and replica data only).
+	pub default: bool,
+}
+
+#[derive(Debug)]
+pub struct Enum {
+	/// Unique name, used for deduplication.
+	pub id: &'static str,
+	pub name: &'static str,
+	pub disc: Box<Type>,
+	pub pre_disc_padding: usize,
+	pub post_disc_padding: usize,
+	pub variants: Vec<Variant>,
+	/// Whether the variants' fields are encoded like the fields of a game message, see [`Struct::game_message`].
+	pub game_message: bool,
+	/// Whether this is replica data, see [`Struct::replica`].
+	pub replica: bool,
+}
+
+#[derive(Debug)]
+pub struct Variant {
+	pub name: &'static str,
+	pub disc: u64,
+	pub fields: Vec<Field>,
+}
+
+macro_rules! schema {
+	($typ:ty, $val:expr) => {
+		impl Schema for $typ {
+			fn schema() -> Type {
+				$val
+			}
+		}
+	};
+}
+
+schema!(bool, Type::Bool);
+schema!(u8, Type::U8);
+schema!(u16, Type::U16);
+schema!(u32, Type::U32);
+schema!(u64, Type::U64);
+schema!(i8, Type::I8);
+schema!(i16, Type::I16);
+schema!(i32, Type::I32);
+schema!(i64, Type::I64);
+schema!(f32, Type::F32);
+schema!(f64, Type::F64);
+schema!(AsciiChar, Type::Char { wide: false });
+schema!(Ucs2Char, Type::Char { wide: true });
+schema!(std::net::Ipv4Addr, Type::Array { elem: Box::new(Type::U8), len: 4 });
+schema!(Box<[u8]>, Type::Rest);
+// only used in game messages, where it's length-prefixed, see `GmParam`
+schema!(Vec<u8>, Type::List { len: Box::new(Type::U32), elem: Box::new(Type::U8) });
+
+impl<T, const N: usize> Schema for [T; N] {
+	fn schema() -> Type {
+		Type::Array { elem: Box::new(T::schema()), len: N }
+	}
+}
+
+impl<L, T> Schema for LVec<L, T> {
+	fn schema() -> Type {
+		Type::List { len: Box::new(L::schema()), elem: Box::new(T::schema()) }
+	}
+}
+
+impl Schema for LuNameValue {
+	fn schema() -> Type {
+		Type::LuNameValue { value: Box::new(LnvValue::schema()) }
+	}
+}
+
+schema!(Amf3, Type::Amf3);
+
+impl Type {
+	/**
+		Renders the type as JSON.
+
+		Structs and enums are listed once under `types`, keyed by their [`id`](Struct::id), and referenced by ID everywhere else.
+	*/
+	pub fn to_json(&self) -> String {
+		let mut types = BTreeMap::new();
+		let root = self.json(&mut types);
+		let mut out = String::from("{\n\"root\": ");
+		out += &root;
+		out += ",\n\"types\": {";
+		for (i, (id, def)) in types.iter().enumerate() {
+			if i != 0 {
+				out.push(',');
+			}
+			write!(out, "\n{}: {}", json_str(id), def).unwrap();
+		}
+		out += "\n}\n}\n";
+		out
+	}
+
+	fn json(&self, types: &mut BTreeMap<&'static str, String>) -> String {
+		match self {
+			Type::Bool => json_str("bool"),
+			Type::U8 => json_str("u8"),
+			Type::U16 => json_str("u16"),
+			Type::U32 => json_str("u32"),
+			Type::U64 => json_str("u64"),
+			Type::I8 => json_str("i8"),
+			Type::I16 => json_str("i16"),
+			Type::I32 => json_str("i32"),
+			Type::I64 => json_str("i64"),
+			Type::F32 => json_str("f32"),
+			Type::F64 => json_str("f64"),
+			Type::Char { wide } => format!("{{\"kind\": \"char\", \"wide\": {}}}", wide),
+			Type::Array { elem, len } => format!("{{\"kind\": \"array\", \"elem\": {}, \"len\": {}}}", elem.json(types), len),
+			Type::List { len, elem } => format!("{{\"kind\": \"list\", \"len\": {}, \"elem\": {}}}", len.json(types), elem.json(types)),
+			Type::Rest => json_str("rest"),
+			Type::LuNameValue { value } => format!("{{\"kind\": \"lnv\", \"value\": {}}}", value.json(types)),
+			Type::Amf3 => String::from("{\"kind\": \"amf3\"}"),
+			Type::Opaque(name) => format!("{{\"kind\": \"opaque\", \"name\": {}}}", json_str(name)),
+			Type::Struct(s) => {
+				if !types.contains_key(s.id) {
+					// insert placeholder first so recursive references terminate
+					types.insert(s.id, String::new());
+					let def = format!("{{\"kind\": \"struct\", \"name\": {}, \"fields\": {}, \"trailing_padding\": {}, \"game_message\": {}, \"replica\": {}}}", json_str(s.name), fields_json(&s.fields, types), s.trailing_padding, s.game_message, s.replica);
+					types.insert(s.id, def);
+				}
+				json_str(s.id)
+			}
+			Type::Enum(e) => {
+				if !types.contains_key(e.id) {
+					types.insert(e.id, String::new());
+					let variants: Vec<_> = e.variants.iter().map(|v| format!("{{\"name\": {}, \"disc\": {}, \"fields\": {}}}", json_str(v.name), v.disc, fields_json(&v.fields, types))).collect();
+					let def = format!("{{\"kind\": \"enum\", \"name\": {}, \"disc\": {}, \"pre_disc_padding\": {}, \"post_disc_padding\": {}, \"variants\": [{}], \"game_message\": {}, \"replica\": {}}}", json_str(e.name), e.disc.json(types), e.pre_disc_padding, e.post_disc_padding, variants.join(", "), e.game_message, e.replica);
+					types.insert(e.id, def);
+				}
+				json_str(e.id)
+			}
+		}
+	}
+}
+
+fn fields_json(fields: &[Field], types: &mut BTreeMap<&'static str, String>) -> String {
+	let fields: Vec<_> = fields.iter().map(|f| format!("{{\"name\": {}, \"type\": {}, \"padding\": {}, \"default\": {}}}", json_str(f.name), f.ty.json(types), f.padding, f.default)).collect();
+	format!("[{}]", fields.join(", "))
+}
+
+fn json_str(string: &str) -> String {
+	let mut out = String::from("\"");
+	for c in string.chars() {
+		match c {
+			'"' => out += "\\\"",
+			'\\' => out += "\\\\",
+			c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+	out
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::common::{LuVarString, LuVarWString, LuWString33};
+	use crate::raknet::client::replica::bbb::BbbConstruction;
+	use crate::world::server::LevelLoadComplete;
+
+	#[test]
+	fn derived_struct() {
+		let s = match LevelLoadComplete::schema() {
+			Type::Struct(x) => x,
+			x => panic!("{:?}", x),
+		};
+		assert_eq!(s.name, "LevelLoadComplete");
+		assert_eq!(s.fields.len(), 1);
+		assert_eq!(s.fields[0].name, "zone_id");
+	}
+
+	#[test]
+	fn strings() {
+		assert!(matches!(LuWString33::schema(), Type::Array { elem, len: 33 } if matches!(*elem, Type::Char { wide: true })));
+		assert!(matches!(<LuVarString<u8>>::schema(), Type::List { len, elem } if matches!((&*len, &*elem), (Type::U8, Type::Char { wide: false }))));
+		assert!(matches!(<LuVarWString<u32>>::schema(), Type::List { len, elem } if matches!((&*len, &*elem), (Type::U32, Type::Char { wide: true }))));
+	}
+
+	#[test]
+	fn lnv_and_amf3() {
+		match LuNameValue::schema() {
+			Type::LuNameValue { value } => assert!(matches!(*value, Type::Enum(Enum { name: "LnvValue", .. }))),
+			x => panic!("{:?}", x),
+		}
+		assert!(matches!(Amf3::schema(), Type::Amf3));
+	}
+
+	#[test]
+	fn replica() {
+		let s = match BbbConstruction::schema() {
+			Type::Struct(x) => x,
+			x => panic!("{:?}", x),
+		};
+		assert!(s.replica);
+		assert!(!s.game_message);
+		assert_eq!(s.fields[0].name, "metadata_source_item");
+		// `Option`s are a flag bit followed by the value
+		assert!(s.fields[0].default);
+		assert!(matches!(s.fields[0].ty, Type::U64));
+	}
+
+	#[test]
+	fn trade_update() {
+		use crate::world::gm::server::ClientTradeUpdate;
+
+		let s = match ClientTradeUpdate::schema() {
+			Type::Struct(x) => x,
+			x => panic!("{:?}", x),
+		};
+		assert!(s.game_message && s.replica);
+		let item = match &s.fields[1].ty {
+			Type::List { elem, .. } => match &**elem {
+				Type::Struct(x) => x,
+				x => panic!("{:?}", x),
+			},
+			x => panic!("{:?}", x),
+		};
+		assert!(item.fields.iter().filter(|f| f.default).map(|f| f.name).eq(["count", "slot", "inv_type", "extra_info"]));
+	}
+
+	#[test]
+	fn opaque_fallback() {
+		assert!(matches!(<std::fs::File as Schema>::schema(), Type::Opaque(_)));
+	}
+}
@@ -0,0 +1,416 @@
+/*!
+	Annotated decoding for debugging parse failures.
+
+	Enabled with the `trace` feature. [`trace`] decodes a message from a byte buffer with its regular deserialization code, through a reader that records every read. The code generated by the [`GameMessage`](lu_packets_derive::GameMessage) and [`ReplicaSerde`](lu_packets_derive::ReplicaSerde) derives additionally records the path and value of each field it reads, and the first field whose read fails. The result renders as an annotated hex dump, which shows where a read went past the end of the data or into garbage.
+
+	Reads by other code, like the [`Deserialize`](endio::Deserialize) derive or hand-written deserialization, are recorded one entry per read, under the path of the innermost field or variant being decoded. Message enums and the components of replicas add their variant or component to the path, e.g. `ReplicaConstruction.components[3].DestroyableConstruction.stats`.
+
+	Entries have byte and bit offsets. Bit-level fields share bytes with their neighbors, so their byte offsets cover the bytes that were pulled from the data while reading them, which can be none, while their bit offsets cover the bits of the field.
+*/
+use std::any::type_name;
+use std::cell::RefCell;
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult, Write};
+use std::io::{Read, Result as Res};
+
+use endio::{Deserialize, LE, LERead};
+
+/// A field or read recorded during tracing.
+#[derive(Debug)]
+pub struct TraceEntry {
+	/// Path of the field, e.g. `GameMessage.RequestDie.killer_id`.
+	pub path: String,
+	/// Byte offsets of the data pulled from the reader while reading the field.
+	pub start: usize,
+	pub end: usize,
+	/// Bit offsets of the field.
+	pub bit_start: usize,
+	pub bit_end: usize,
+	/// Debug representation of the field, or the bytes read. Empty for fields with traced fields of their own.
+	pub value: String,
+}
+
+/// The read at which decoding failed.
+#[derive(Debug)]
+pub struct TraceError {
+	/// Path of the innermost traced field being read.
+	pub path: String,
+	/// Byte offset of the failed read.
+	pub offset: usize,
+	/// Bit offset of the failed read.
+	pub bit_offset: usize,
+	pub message: String,
+}
+
+/// Result of [`trace`].
+#[derive(Debug)]
+pub struct Trace<'a> {
+	pub data: &'a [u8],
+	pub entries: Vec<TraceEntry>,
+	pub error: Option<TraceError>,
+	/// Byte offset after the last read.
+	pub end: usize,
+}
+
+/// Reader over the traced data, which records reads that aren't part of a traced field.
+pub struct TraceReader<'a> {
+	data: &'a [u8],
+}
+
+impl Read for TraceReader<'_> {
+	fn read(&mut self, buf: &mut [u8]) -> Res<usize> {
+		let n = Read::read(&mut self.data, buf)?;
+		RECORDER.with(|r| {
+			if let Some(r) = &mut *r.borrow_mut() {
+				r.read(&buf[..n]);
+			}
+		});
+		Ok(n)
+	}
+}
+
+/// Traces the decoding of `data` as `T`.
+pub fn trace<T>(data: &[u8]) -> Trace
+where
+	for<'a> T: Deserialize<LE, TraceReader<'a>>,
+{
+	trace_with(data, |reader| LERead::read::<T>(reader))
+}
+
+/// Traces decoding with `decode`, for readers that need to be wrapped, like ones providing a [`ReplicaContext`](crate::raknet::client::replica::ReplicaContext).
+pub fn trace_with<'a, T>(data: &'a [u8], decode: impl FnOnce(&mut TraceReader<'a>) -> Res<T>) -> Trace<'a> {
+	let root = type_name::<T>().rsplit("::").next().unwrap();
+	let recorder = Recorder { pos: 0, bit: 0, path: vec![root.into()], scopes: vec![], name_component: false, entries: vec![], error: None };
+	// support tracing in traced code, e.g. from a `Debug` impl
+	let outer = RECORDER.with(|r| r.replace(Some(recorder)));
+	let res = decode(&mut TraceReader { data });
+	let recorder = RECORDER.with(|r| r.replace(outer)).unwrap();
+	let bit_offset = recorder.bit_pos();
+	let Recorder { pos, path, entries, error, .. } = recorder;
+	let error = res.err().map(|e| {
+		let (path, offset, bit_offset) = error.unwrap_or_else(|| (path.join("."), pos, bit_offset));
+		TraceError { path, offset, bit_offset, message: e.to_string() }
+	});
+	Trace { data, entries, error, end: pos }
+}
+
+thread_local! {
+	static RECORDER: RefCell<Option<Recorder>> = RefCell::new(None);
+}
+
+struct Recorder {
+	/// Bytes read so far.
+	pos: usize,
+	/// Bits of the last byte read so far by a bit reader, 0 if the reads are byte aligned.
+	bit: u8,
+	path: Vec<String>,
+	/// Open scopes, with the index of the field's entry for fields.
+	scopes: Vec<Option<usize>>,
+	/// Whether the next type entered is a replica component, see [`enter_type`].
+	name_component: bool,
+	entries: Vec<TraceEntry>,
+	/// Path, byte and bit offset of the innermost field whose read failed.
+	error: Option<(String, usize, usize)>,
+}
+
+impl Recorder {
+	fn bit_pos(&self) -> usize {
+		if self.bit == 0 {
+			self.pos * 8
+		} else {
+			self.pos * 8 - 8 + self.bit as usize
+		}
+	}
+
+	fn read(&mut self, bytes: &[u8]) {
+		let start = self.pos;
+		let bit_start = self.bit_pos();
+		self.pos += bytes.len();
+		self.name_component = false;
+		// reads in traced fields are covered by the field's entry
+		if let Some(Some(_)) = self.scopes.last() {
+			return;
+		}
+		if bytes.is_empty() {
+			return;
+		}
+		let value = bytes.iter().map(|x| format!("{:02x}", x)).collect::<Vec<_>>().join(" ");
+		self.entries.push(TraceEntry { path: self.path.join("."), start, end: self.pos, bit_start, bit_end: self.bit_pos(), value });
+	}
+
+	fn enter(&mut self, name: &str, field: bool) {
+		self.path.push(name.into());
+		self.name_component = false;
+		let entry = if field {
+			let bit_pos = self.bit_pos();
+			self.entries.push(TraceEntry { path: self.path.join("."), start: self.pos, end: self.pos, bit_start: bit_pos, bit_end: bit_pos, value: String::new() });
+			Some(self.entries.len() - 1)
+		} else {
+			None
+		};
+		self.scopes.push(entry);
+	}
+
+	fn exit(&mut self, value: Option<String>) {
+		if let Some(Some(i)) = self.scopes.pop() {
+			let has_children = i + 1 < self.entries.len();
+			let bit_pos = self.bit_pos();
+			let entry = &mut self.entries[i];
+			entry.end = self.pos;
+			entry.bit_end = bit_pos;
+			match value {
+				Some(x) if !has_children => entry.value = x,
+				Some(_) => {}
+				None => {
+					entry.value = "(failed)".into();
+					if self.error.is_none() {
+						self.error = Some((entry.path.clone(), self.pos, bit_pos));
+					}
+				}
+			}
+		}
+		self.path.pop();
+	}
+}
+
+/// Records a field read by generated code, see [`enter_field`].
+pub(crate) struct FieldGuard {
+	active: bool,
+}
+
+/**
+	Starts recording the read of a field, to be finished with [`FieldGuard::exit`] once the field is read.
+
+	If the guard is dropped without that, the field's read failed.
+*/
+pub(crate) fn enter_field(name: &str) -> FieldGuard {
+	FieldGuard { active: with_recorder(|r| r.enter(name, true)) }
+}
+
+/// Adds a variant to the path of the following reads, until the guard is dropped.
+pub(crate) fn enter_variant(name: &str) -> VariantGuard {
+	VariantGuard { active: with_recorder(|r| r.enter(name, false)) }
+}
+
+pub(crate) struct VariantGuard {
+	active: bool,
+}
+
+/// Starts recording the read of the component at `index` of a replica, like [`enter_field`]. The component's type is added to the path by [`enter_type`].
+pub(crate) fn enter_component(index: usize) -> FieldGuard {
+	FieldGuard {
+		active: with_recorder(|r| {
+			r.enter(&format!("components[{}]", index), true);
+			r.name_component = true;
+		}),
+	}
+}
+
+/// Adds the type being decoded to the path until the guard is dropped, if it's a replica component, see [`enter_component`].
+pub(crate) fn enter_type(name: &str) -> VariantGuard {
+	let mut active = false;
+	with_recorder(|r| {
+		if std::mem::take(&mut r.name_component) {
+			r.enter(name, false);
+			active = true;
+		}
+	});
+	VariantGuard { active }
+}
+
+/// Records that a bit reader read `count` bits, for the bit offsets of the following entries. Bit readers only pull a byte from the data for the first bit read from it.
+pub(crate) fn read_bits(count: u8) {
+	with_recorder(|r| r.bit = (r.bit + count) % 8);
+}
+
+/// Records that the following reads start at a byte boundary, like the reads of a new bit reader.
+pub(crate) fn align() {
+	with_recorder(|r| r.bit = 0);
+}
+
+/// Runs `f` if tracing, and returns whether it ran.
+fn with_recorder(f: impl FnOnce(&mut Recorder)) -> bool {
+	RECORDER.with(|r| match &mut *r.borrow_mut() {
+		Some(r) => {
+			f(r);
+			true
+		}
+		None => false,
+	})
+}
+
+impl FieldGuard {
+	pub(crate) fn exit<T>(mut self, value: &T) {
+		if self.active {
+			self.active = false;
+			let value = TraceValue::trace_value(value);
+			with_recorder(|r| r.exit(Some(value)));
+		}
+	}
+}
+
+impl Drop for FieldGuard {
+	fn drop(&mut self) {
+		if self.active {
+			with_recorder(|r| r.exit(None));
+		}
+	}
+}
+
+impl Drop for VariantGuard {
+	fn drop(&mut self) {
+		if self.active {
+			with_recorder(|r| r.exit(Some(String::new())));
+		}
+	}
+}
+
+/// Representation of a field's value in the trace.
+trait TraceValue {
+	fn trace_value(&self) -> String;
+}
+
+impl<T> TraceValue for T {
+	default fn trace_value(&self) -> String {
+		String::from("?")
+	}
+}
+
+impl<T: Debug> TraceValue for T {
+	fn trace_value(&self) -> String {
+		format!("{:?}", self)
+	}
+}
+
+impl Trace<'_> {
+	/// Whether all data was decoded without error.
+	pub fn is_complete(&self) -> bool {
+		self.error.is_none() && self.end == self.data.len()
+	}
+}
+
+fn fmt_offset(offset: usize) -> String {
+	format!("{:#06x}  ", offset)
+}
+
+/// Formats a bit offset as its byte offset, followed by the bit in that byte if it's not byte aligned.
+fn fmt_bit_offset(bit_offset: usize) -> String {
+	match bit_offset % 8 {
+		0 => fmt_offset(bit_offset / 8),
+		bit => format!("{:#06x}.{}", bit_offset / 8, bit),
+	}
+}
+
+/// Formats a length in bits as bytes, or as bits if it's not a whole number of bytes.
+fn fmt_bit_len(bit_len: usize) -> String {
+	match bit_len % 8 {
+		0 => format!("{:>4}B", bit_len / 8),
+		_ => format!("{:>4}b", bit_len),
+	}
+}
+
+/// Renders the trace as a hex dump, with one line per entry. The hex bytes are the bytes containing the entry's bits.
+impl Display for Trace<'_> {
+	fn fmt(&self, f: &mut Formatter) -> FmtResult {
+		const MAX_BYTES: usize = 12;
+		for e in &self.entries {
+			let start = e.bit_start / 8;
+			let end = (e.bit_end + 7) / 8;
+			let mut hex = String::new();
+			for byte in &self.data[start..end.min(start + MAX_BYTES)] {
+				write!(hex, "{:02x} ", byte).unwrap();
+			}
+			if end - start > MAX_BYTES {
+				hex += "..";
+			}
+			writeln!(f, "{} {:<38} {}  {} = {}", fmt_bit_offset(e.bit_start), hex, fmt_bit_len(e.bit_end - e.bit_start), e.path, e.value)?;
+		}
+		if let Some(err) = &self.error {
+			writeln!(f, "{} !! {}: {}", fmt_bit_offset(err.bit_offset), err.path, err.message)?;
+		}
+		if self.error.is_none() && self.end < self.data.len() {
+			writeln!(f, "{} !! {} bytes left unread: {:02x?}", fmt_offset(self.end), self.data.len() - self.end, &self.data[self.end..])?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use endio::LEWrite;
+	use endio_bit::BEBitReader;
+
+	use super::*;
+	use crate::raknet::client::replica::{ComponentConstruction, ComponentSerialization, ReplicaContext, ReplicaSerialization};
+	use crate::raknet::client::replica::simple_physics::{MotionType, SimplePhysicsSerialization};
+	use crate::world::{Lot, LuNameValue};
+	use crate::world::gm::client::GameMessage;
+	use crate::world::server::LevelLoadComplete;
+
+	struct SimplePhysicsContext<'a, 'b>(&'b mut TraceReader<'a>);
+
+	impl Read for SimplePhysicsContext<'_, '_> {
+		fn read(&mut self, buf: &mut [u8]) -> Res<usize> {
+			Read::read(self.0, buf)
+		}
+	}
+
+	impl ReplicaContext for SimplePhysicsContext<'_, '_> {
+		fn get_comp_constructions<R: Read>(&mut self, _network_id: u16, _lot: Lot, _config: &Option<LuNameValue>) -> Vec<fn(&mut BEBitReader<R>) -> Res<Box<dyn ComponentConstruction>>> {
+			vec![]
+		}
+
+		fn get_comp_serializations<R: Read>(&mut self, _network_id: u16) -> Vec<fn(&mut BEBitReader<R>) -> Res<Box<dyn ComponentSerialization>>> {
+			vec![|x| Ok(Box::new(SimplePhysicsSerialization::deserialize(x)?))]
+		}
+	}
+
+	#[test]
+	fn complete() {
+		let data = [0xe8, 0x03, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00];
+		let trace = trace::<LevelLoadComplete>(&data);
+		assert!(trace.is_complete(), "{}", trace);
+		// the `Deserialize` derive isn't traced, each read is an entry
+		assert_eq!(trace.entries[0].path, "LevelLoadComplete");
+		assert_eq!(trace.entries[0].value, "e8 03");
+		assert_eq!(trace.entries.last().unwrap().end, 8);
+	}
+
+	#[test]
+	fn fields() {
+		let data = include_bytes!("world/gm/client/tests/PlayFxEffect.bin");
+		let trace = trace::<GameMessage>(data);
+		assert!(trace.is_complete(), "{}", trace);
+		// the message ID, read by the `Deserialize` derive
+		assert_eq!(trace.entries[0].value, "9a 00");
+		let effect_type = trace.entries.iter().find(|e| e.path == "GameMessage.PlayFxEffect.effect_type").unwrap();
+		assert_eq!(effect_type.value, "\"equip-head\"");
+	}
+
+	#[test]
+	fn components() {
+		let comp = SimplePhysicsSerialization { velocity_info: None, motion_type: Some(MotionType::Fixed), position_rotation_info: None };
+		let msg = ReplicaSerialization { network_id: 1, parent_child_info: None, components: vec![Box::new(comp)] };
+		let mut data = vec![];
+		LEWrite::write(&mut data, &msg).unwrap();
+		let trace = trace_with(&data, |reader| LERead::read::<ReplicaSerialization>(&mut SimplePhysicsContext(reader)));
+		assert!(trace.is_complete(), "{}", trace);
+		let motion_type = trace.entries.iter().find(|e| e.path == "ReplicaSerialization.components[0].SimplePhysicsSerialization.motion_type").unwrap();
+		assert_eq!(motion_type.value, "Some(Fixed)");
+		// after the network ID and the bits of `parent_child_info` and `velocity_info`
+		assert_eq!(motion_type.bit_start, 18);
+		assert_eq!(motion_type.bit_end, 18 + 1 + 32);
+		assert_eq!(motion_type.start, 3);
+		assert_eq!(motion_type.end, 7);
+	}
+
+	#[test]
+	fn read_past_end() {
+		let data = include_bytes!("world/gm/client/tests/PlayFxEffect.bin");
+		let trace = trace::<GameMessage>(&data[..12]);
+		let err = trace.error.unwrap();
+		assert_eq!(err.path, "GameMessage.PlayFxEffect.effect_type");
+		assert_eq!(err.offset, 12);
+		// the fields after the flag of `effect_id` are one bit off the byte boundaries
+		assert_eq!(err.bit_offset, 11 * 8 + 1);
+	}
+}
@@ -1,37 +1,50 @@
+use std::io::Result as Res;
+
 use crate::auth::client::LoginResponse;
 use crate::auth::server::AuthMessage;
 use crate::chat::client::AchievementNotify;
-use crate::chat::server::{AddFriendRequest as ChatAddFriendRequest, AddFriendResponse as ChatAddFriendResponse, AddIgnore, RequestMinimumChatMode, RequestMinimumChatModePrivate, TeamInvite as ChatTeamInvite, TeamInviteResponse, TeamLeave};
-use crate::chat::{GeneralChatMessage, PrivateChatMessage};
-use crate::common::ServiceId;
+use crate::chat::server::{AddFriendRequest as ChatAddFriendRequest, AddFriendResponse as ChatAddFriendResponse, AddIgnore, RequestMinimumChatMode, RequestMinimumChatModePrivate, TeamInvite as ChatTeamInvite, TeamInviteResponse, TeamKick, TeamLeave, TeamSetLeader, TeamSetLoot};
+use crate::chat::{GeneralChatMessage, GeneralChatMessageRef, PrivateChatMessage, PrivateChatMessageRef};
+use crate::common::{take, ServiceId, NETWORK_VERSION_LIVE};
 use crate::general::client::GeneralMessage;
+use crate::raknet::{AdvertiseSystem, Timestamp};
 use crate::raknet::client::{
 	replica::{ReplicaConstruction, ReplicaSerialization},
-	ConnectedPong, ConnectionRequestAccepted,
+	ConnectedPong, ConnectionRequestAccepted, OpenConnectionReply,
 };
-use crate::raknet::server::{ConnectionRequest, InternalPing, NewIncomingConnection};
-use crate::world::client::{AddFriendRequest, AddFriendResponse, BlueprintLoadItemResponse, BlueprintSaveResponse, CharacterCreateResponse, CharacterDeleteResponse, CharacterListResponse, ChatModerationString, CreateCharacter, FriendUpdateNotify, GetFriendsListResponse, GetIgnoreListResponse, LoadStaticZone, MinimumChatModeResponse, MinimumChatModeResponsePrivate, TeamInvite, TransferToWorld, UpdateFreeTrialStatus};
+use crate::raknet::server::{ConnectionRequest, InternalPing, NewIncomingConnection, OpenConnectionRequest};
+use crate::world::client::{AddFriendRequest, AddFriendResponse, BlueprintLoadItemResponse, BlueprintSaveResponse, CharacterCreateResponse, CharacterDeleteResponse, CharacterListResponse, ChatModerationString, CreateCharacter, DebugOutput, FriendUpdateNotify, GetFriendsListResponse, GetIgnoreListResponse, HttpMonitorInfoResponse, LoadStaticZone, MakeGmResponse, MinimumChatModeResponse, MinimumChatModeResponsePrivate, SlashLockMapResponse, SlashPullMapResponse, SlashPushMapResponse, TeamInvite, TransferToWorld, UpdateFreeTrialStatus};
+use crate::world::gm::GameMessageRef;
 use crate::world::gm::client::SubjectGameMessage;
-use crate::world::server::WorldMessage;
-use endio::{Deserialize, Serialize};
-use lu_packets_derive::MessageFromVariants;
+use crate::world::server::{GeneralChatMessageRef as WorldGeneralChatMessageRef, WorldMessage};
+use endio::{Deserialize, LE, LERead, Serialize};
+use lu_packets_derive::{Arbitrary, MessageDeserialize, MessageFromVariants, Reflect, Schema};
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, MessageFromVariants)]
+#[derive(Arbitrary, Debug, MessageDeserialize, Reflect, Schema, Serialize, PartialEq, MessageFromVariants)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum Message {
 	InternalPing(InternalPing) = 0,
 	ConnectedPong(ConnectedPong) = 3,
 	ConnectionRequest(ConnectionRequest) = 4,
+	OpenConnectionRequest(OpenConnectionRequest) = 9,
+	OpenConnectionReply(OpenConnectionReply) = 10,
 	ConnectionRequestAccepted(ConnectionRequestAccepted) = 14,
+	ConnectionAttemptFailed = 15,
 	NewIncomingConnection(NewIncomingConnection) = 17,
+	NoFreeIncomingConnections = 18,
 	DisconnectionNotification = 19,
+	ConnectionLost = 20,
+	ConnectionBanned = 22,
+	InvalidPassword = 23,
+	Timestamp(Timestamp<UserMessage>) = 25,
+	AdvertiseSystem(AdvertiseSystem) = 27,
 	ReplicaConstruction(ReplicaConstruction) = 36,
 	ReplicaSerialization(ReplicaSerialization) = 39,
 	UserMessage(UserMessage) = 83,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, MessageFromVariants)]
+#[derive(Arbitrary, Debug, MessageDeserialize, Reflect, Schema, Serialize, PartialEq, MessageFromVariants)]
 #[repr(u16)]
 pub enum UserMessage {
 	General(GeneralMessage) = ServiceId::General as u16,
@@ -41,7 +54,7 @@ pub enum UserMessage {
 	Auth(AuthMessage) = ServiceId::Auth as u16,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, MessageFromVariants)]
+#[derive(Arbitrary, Debug, MessageDeserialize, Reflect, Schema, Serialize, PartialEq, MessageFromVariants)]
 #[non_exhaustive]
 #[post_disc_padding = 1]
 #[repr(u32)]
@@ -54,8 +67,15 @@ pub enum AnyClientMessage {
 	CharacterDeleteResponse(CharacterDeleteResponse) = 11,
 	SubjectGameMessage(SubjectGameMessage) = 12,
 	TransferToWorld(TransferToWorld) = 14,
+	ImpendingReloadNotify = 15,
+	MakeGmResponse(MakeGmResponse) = 16,
+	HttpMonitorInfoResponse(HttpMonitorInfoResponse) = 17,
+	SlashPushMapResponse(SlashPushMapResponse) = 18,
+	SlashPullMapResponse(SlashPullMapResponse) = 19,
+	SlashLockMapResponse(SlashLockMapResponse) = 20,
 	BlueprintSaveResponse(BlueprintSaveResponse) = 21,
 	BlueprintLoadItemResponse(BlueprintLoadItemResponse) = 23,
+	DebugOutput(DebugOutput) = 26,
 	AddFriendRequest(AddFriendRequest) = 27,
 	AddFriendResponse(AddFriendResponse) = 28,
 	GetFriendsListResponse(GetFriendsListResponse) = 30,
@@ -68,7 +88,7 @@ pub enum AnyClientMessage {
 	UpdateFreeTrialStatus(UpdateFreeTrialStatus) = 62,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, MessageFromVariants)]
+#[derive(Arbitrary, Debug, MessageDeserialize, Reflect, Schema, Serialize, PartialEq, MessageFromVariants)]
 #[non_exhaustive]
 #[post_disc_padding = 9]
 #[repr(u32)]
@@ -82,9 +102,194 @@ pub enum AnyChatMessage {
 	GetIgnoreList = 13,
 	TeamInvite(ChatTeamInvite) = 15,
 	TeamInviteResponse(TeamInviteResponse) = 16,
+	TeamKick(TeamKick) = 17,
 	TeamLeave(TeamLeave) = 18,
+	TeamSetLoot(TeamSetLoot) = 19,
+	TeamSetLeader(TeamSetLeader) = 20,
 	TeamGetStatus = 21,
 	RequestMinimumChatMode(RequestMinimumChatMode) = 50,
 	RequestMinimumChatModePrivate(RequestMinimumChatModePrivate) = 51,
 	AchievementNotify(AchievementNotify) = 59,
 }
+
+/**
+	Borrowed decoding of a [`Message`], for relaying messages without copying them.
+
+	Parses the message headers from a byte slice, and gives borrowed views of the messages that are usually forwarded untouched: chat messages and game messages. Strings borrow from the input, and fixed-size fields are decoded when accessed. Other messages are only identified.
+
+	The views convert to their owned types on demand, and [`into_owned`](Self::into_owned) decodes the whole message.
+
+	Game messages are bit-packed, so their fields can't be borrowed, except for blobs that start on a byte boundary. Blobs after bit-level fields, like the skill bitstreams of [`StartSkill`](crate::world::gm::server::StartSkill::bitstream) and [`SyncSkill`](crate::world::gm::server::SyncSkill::bitstream), have to be decoded with [`GameMessageRef::into_owned`]. See [`GameMessageRef`] for details.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MessageRef<'a> {
+	/// The whole message.
+	pub data: &'a [u8],
+	pub body: BodyRef<'a>,
+}
+
+/// Borrowed view of the body of a [`MessageRef`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum BodyRef<'a> {
+	GeneralChatMessage(GeneralChatMessageRef<'a>),
+	PrivateChatMessage(PrivateChatMessageRef<'a>),
+	/// A chat message sent to a world server.
+	WorldGeneralChatMessage(WorldGeneralChatMessageRef<'a>),
+	/// A game message sent to a world server.
+	ServerGameMessage(GameMessageRef<'a>),
+	/// A game message sent to a client.
+	ClientGameMessage(GameMessageRef<'a>),
+	/// Any other message.
+	Other,
+}
+
+impl<'a> MessageRef<'a> {
+	pub fn parse(data: &'a [u8]) -> Res<Self> {
+		Self::parse_versioned(data, NETWORK_VERSION_LIVE)
+	}
+
+	/// Like [`parse`](Self::parse), for a message from a client with the given network version, which game message IDs are translated for.
+	pub fn parse_versioned(data: &'a [u8], version: u32) -> Res<Self> {
+		let body = parse_body(&mut &data[..], version)?;
+		Ok(Self { data, body })
+	}
+
+	/**
+		Decodes the whole message into an owned message type, like [`world::server::Message`](crate::world::server::Message).
+
+		Messages containing replicas need a [`ReplicaContext`](crate::raknet::client::replica::ReplicaContext) to be decoded, use [`from_bytes_exact_with`](crate::common::from_bytes_exact_with) for those.
+	*/
+	pub fn into_owned<T>(self) -> Res<T>
+	where
+		for<'b> T: Deserialize<LE, &'b [u8]>,
+	{
+		LERead::read(&mut &self.data[..])
+	}
+}
+
+fn parse_body<'a>(data: &mut &'a [u8], version: u32) -> Res<BodyRef<'a>> {
+	const CHAT: u16 = ServiceId::Chat as u16;
+	const WORLD: u16 = ServiceId::World as u16;
+	const CLIENT: u16 = ServiceId::Client as u16;
+
+	let message_id: u8 = LERead::read(data)?;
+	if message_id != 83 {
+		return Ok(BodyRef::Other);
+	}
+	let service_id: u16 = LERead::read(data)?;
+	let message_id: u32 = LERead::read(data)?;
+	Ok(match (service_id, message_id) {
+		(CHAT, 1) => {
+			take(data, 9)?;
+			BodyRef::GeneralChatMessage(GeneralChatMessageRef::parse(data)?)
+		}
+		(CHAT, 2) => {
+			take(data, 9)?;
+			BodyRef::PrivateChatMessage(PrivateChatMessageRef::parse(data)?)
+		}
+		(WORLD, 5) => {
+			take(data, 1)?;
+			BodyRef::ServerGameMessage(GameMessageRef::parse_versioned(data, version)?)
+		}
+		(WORLD, 14) => {
+			take(data, 1)?;
+			BodyRef::WorldGeneralChatMessage(WorldGeneralChatMessageRef::parse(data)?)
+		}
+		(CLIENT, 12) => {
+			take(data, 1)?;
+			BodyRef::ClientGameMessage(GameMessageRef::parse_versioned(data, version)?)
+		}
+		_ => BodyRef::Other,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use endio::LEWrite;
+
+	use super::*;
+	use crate::chat::ChatChannel;
+
+	#[test]
+	fn message_ref() {
+		let chat = GeneralChatMessage { chat_channel: ChatChannel::Team, sender_name: lu!("sender"), sender: 1152921510794154770, source_id: 0, sender_gm_level: 0, message: lu!("hello") };
+		let mut data = vec![];
+		data.write(&Message::UserMessage(UserMessage::Chat(AnyChatMessage::GeneralChatMessage(chat)))).unwrap();
+		let msg = MessageRef::parse(&data).unwrap();
+		let chat = match msg.body {
+			BodyRef::GeneralChatMessage(x) => x,
+			x => panic!("{:?}", x),
+		};
+		assert_eq!(chat.chat_channel(), ChatChannel::Team);
+		assert_eq!(chat.sender(), 1152921510794154770);
+		assert_eq!(chat.message().to_string(), "hello");
+		assert_eq!(chat.into_owned().message, lu!("hello"));
+		assert_eq!(MessageRef::parse(&[19, 0]).unwrap().body, BodyRef::Other);
+	}
+
+	#[test]
+	fn guild() {
+		use crate::world::server::GuildCreate;
+
+		let create = WorldMessage::GuildCreate(GuildCreate { guild_name: lu!("Nexus Knights") });
+		let mut data = vec![];
+		data.write(&Message::UserMessage(UserMessage::World(create))).unwrap();
+		assert_eq!(&data[..8], &[83, ServiceId::World as u8, 0, 20, 0, 0, 0, 0]);
+		assert_eq!(LERead::read::<WorldMessage>(&mut &data[3..]).unwrap(), WorldMessage::GuildCreate(GuildCreate { guild_name: lu!("Nexus Knights") }));
+		assert_eq!(MessageRef::parse(&data).unwrap().body, BodyRef::Other);
+
+		for (chat_channel, wire) in [(ChatChannel::Guild, 10u8), (ChatChannel::GuildNotify, 11)] {
+			let chat = GeneralChatMessage { chat_channel, sender_name: lu!("sender"), sender: 1152921510794154770, source_id: 0, sender_gm_level: 0, message: lu!("hello guild") };
+			let mut body = vec![];
+			body.write(&chat).unwrap();
+			assert_eq!(body[0], wire);
+			let mut data = vec![];
+			data.write(&Message::UserMessage(UserMessage::Chat(AnyChatMessage::GeneralChatMessage(chat)))).unwrap();
+			let chat = match MessageRef::parse(&data).unwrap().body {
+				BodyRef::GeneralChatMessage(x) => x,
+				x => panic!("{:?}", x),
+			};
+			assert_eq!(chat.chat_channel(), chat_channel);
+		}
+	}
+
+	#[cfg(feature = "arbitrary")]
+	mod round_trip {
+		use arbitrary::{Arbitrary, Unstructured};
+		use endio::LEWrite;
+
+		use crate::unified::Message;
+		use crate::common::from_bytes_exact_with;
+		use crate::raknet::client::replica::ArbitraryComponents;
+
+		/// Serializes arbitrary messages, decodes them and checks that they serialize to the same bytes. Bytes are compared instead of values because of NaN.
+		#[test]
+		fn round_trip() {
+			let mut seed = 0x2545f4914f6cdd1du64;
+			let mut buf = vec![0; 4096];
+			for _ in 0..2000 {
+				for b in buf.iter_mut() {
+					// xorshift
+					seed ^= seed << 13;
+					seed ^= seed >> 7;
+					seed ^= seed << 17;
+					*b = seed as u8;
+				}
+				let msg = match Message::arbitrary(&mut Unstructured::new(&buf)) {
+					Ok(x) => x,
+					Err(_) => continue,
+				};
+				let mut first = vec![];
+				first.write(&msg).unwrap();
+				let decoded: Message = match from_bytes_exact_with(&first, ArbitraryComponents) {
+					Ok(x) => x,
+					Err(e) => panic!("{:?} failed to decode: {}", msg, e),
+				};
+				let mut second = vec![];
+				second.write(&decoded).unwrap();
+				assert_eq!(first, second, "{:?} doesn't round trip", msg);
+			}
+		}
+	}
+}
@@ -324,6 +324,42 @@ impl Debug for Amf3 {
 	}
 }
 
+/// Arrays are nested at most four levels deep.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Amf3 {
+	fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+		arbitrary_amf3(u, 0)
+	}
+}
+
+#[cfg(feature = "arbitrary")]
+fn arbitrary_amf3(u: &mut arbitrary::Unstructured, depth: u8) -> arbitrary::Result<Amf3> {
+	use arbitrary::Arbitrary;
+
+	let max = if depth < 4 { 4 } else { 3 };
+	Ok(match u.int_in_range(0..=max)? {
+		0 => Amf3::False,
+		1 => Amf3::True,
+		2 => Amf3::Double(f64::arbitrary(u)?),
+		3 => Amf3::String(Amf3String(String::arbitrary(u)?)),
+		_ => {
+			let mut array = Amf3Array::new();
+			for _ in 0..u.arbitrary_len::<(String, f64)>()? {
+				let mut key = String::arbitrary(u)?;
+				// an empty key marks the end of the map
+				if key.is_empty() {
+					key.push('_');
+				}
+				array.map.insert(Amf3String(key), arbitrary_amf3(u, depth + 1)?);
+			}
+			for _ in 0..u.arbitrary_len::<f64>()? {
+				array.vec.push(arbitrary_amf3(u, depth + 1)?);
+			}
+			Amf3::Array(array)
+		}
+	})
+}
+
 impl<R: Read> Deserialize<LE, R> for Amf3 {
 	fn deserialize(reader: &mut R) -> Res<Self> {
 		let mut reader = Amf3Reader { inner: reader, string_ref_table: vec![] };
@@ -4,20 +4,20 @@ use std::io::Result as Res;
 
 use endio::{Deserialize, LERead, LEWrite, Serialize};
 use endio::LittleEndian as LE;
-use lu_packets_derive::{MessageFromVariants, VariantTests};
+use lu_packets_derive::{Arbitrary, MessageDeserialize, MessageFromVariants, Reflect, Schema, VariantTests};
 
 use crate::chat::ChatChannel;
 use crate::chat::client::ChatMessage;
-use crate::common::{ObjId, LuString33, LuWString33, LuWString42, LVec, ServiceId};
+use crate::common::{ObjId, LuString33, LuVarWString, LuWString33, LuWString42, LVec, ServiceId};
 use crate::general::client::{DisconnectNotify, Handshake, GeneralMessage};
-use super::{Lot, lnv::LuNameValue, Vector3, ZoneId};
+use super::{Lot, lnv::LuNameValue, MapId, Vector3, ZoneId};
 use super::gm::client::SubjectGameMessage;
 
 /// All messages that can be received by a client from a world server.
 pub type Message = crate::raknet::client::Message<LuMessage>;
 
 /// All client-received LU messages from a world server.
-#[derive(Debug, Deserialize, MessageFromVariants, PartialEq, Serialize, VariantTests)]
+#[derive(Arbitrary, Debug, MessageDeserialize, MessageFromVariants, PartialEq, Reflect, Schema, Serialize, VariantTests)]
 #[repr(u16)]
 pub enum LuMessage {
 	General(GeneralMessage) = ServiceId::General as u16,
@@ -44,7 +44,7 @@ impl From<DisconnectNotify> for Message {
 }
 
 /// All client-received world messages.
-#[derive(Debug, Deserialize, PartialEq, Serialize, MessageFromVariants, VariantTests)]
+#[derive(Arbitrary, Debug, MessageDeserialize, PartialEq, Reflect, Schema, Serialize, MessageFromVariants, VariantTests)]
 #[non_exhaustive]
 #[post_disc_padding = 1]
 #[repr(u32)]
@@ -56,8 +56,15 @@ pub enum ClientMessage {
 	CharacterDeleteResponse(CharacterDeleteResponse) = 11,
 	SubjectGameMessage(SubjectGameMessage) = 12,
 	TransferToWorld(TransferToWorld) = 14,
+	ImpendingReloadNotify = 15,
+	MakeGmResponse(MakeGmResponse) = 16,
+	HttpMonitorInfoResponse(HttpMonitorInfoResponse) = 17,
+	SlashPushMapResponse(SlashPushMapResponse) = 18,
+	SlashPullMapResponse(SlashPullMapResponse) = 19,
+	SlashLockMapResponse(SlashLockMapResponse) = 20,
 	BlueprintSaveResponse(BlueprintSaveResponse) = 21,
 	BlueprintLoadItemResponse(BlueprintLoadItemResponse) = 23,
+	DebugOutput(DebugOutput) = 26,
 	AddFriendRequest(AddFriendRequest) = 27,
 	AddFriendResponse(AddFriendResponse) = 28,
 	GetFriendsListResponse(GetFriendsListResponse) = 30,
@@ -70,7 +77,7 @@ pub enum ClientMessage {
 	UpdateFreeTrialStatus(UpdateFreeTrialStatus) = 62,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 #[repr(u32)]
 pub enum InstanceType {
 	Public,
@@ -101,7 +108,7 @@ pub enum InstanceType {
 
 	However, these are quite advanced architectures, and for now it is unlikely that any server project will actually pull these off.
 */
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct LoadStaticZone {
 	/// ID of the zone to be loaded.
 	pub zone_id: ZoneId,
@@ -115,7 +122,7 @@ pub struct LoadStaticZone {
 	pub instance_type: InstanceType,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct CreateCharacter {
 	pub data: LuNameValue,
 }
@@ -143,6 +150,17 @@ pub struct CharacterListResponse {
 	pub chars: Vec<CharListChar>,
 }
 
+// the length is written as u8
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CharacterListResponse {
+	fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+		let selected_char = u.arbitrary()?;
+		let mut chars: Vec<CharListChar> = u.arbitrary()?;
+		chars.truncate(u8::MAX as usize);
+		Ok(Self { selected_char, chars })
+	}
+}
+
 impl<R: LERead> Deserialize<LE, R> for CharacterListResponse
 where
 	u8: Deserialize<LE, R>,
@@ -175,7 +193,7 @@ where
 }
 
 /// A character from the [`CharacterListResponse`] message.
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct CharListChar {
 	pub obj_id: ObjId,
 	#[padding = 4]
@@ -211,7 +229,7 @@ pub struct CharListChar {
 	### Response
 	None.
 */
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 #[repr(u8)]
 pub enum CharacterCreateResponse {
 	/// The character has been successfully created.
@@ -238,7 +256,7 @@ pub enum CharacterCreateResponse {
 	### Response
 	None.
 */
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct CharacterDeleteResponse {
 	/// Whether the deletion was successful.
 	pub success: bool,
@@ -253,7 +271,7 @@ pub struct CharacterDeleteResponse {
 	### Response
 	Close the connection after the connection to the other instance has been established.
 */
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct TransferToWorld {
 	/// The host to connect to.
 	pub redirect_ip: LuString33,
@@ -263,7 +281,87 @@ pub struct TransferToWorld {
 	pub is_maintenance_transfer: bool,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+/**
+	Reports the result of a GM level change.
+
+	### Trigger
+	A GM slash command changing the player's GM level.
+
+	### Handling
+	Update any UI depending on the GM level, like the availability of slash commands.
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct MakeGmResponse {
+	/// Whether the GM level was changed.
+	pub success: bool,
+	/// The highest GM level the account is allowed to use.
+	pub highest_level: u16,
+	/// The GM level before the change.
+	pub prev_level: u16,
+	/// The GM level after the change.
+	pub new_level: u16,
+}
+
+/**
+	Provides info about the server's HTTP monitoring interface.
+
+	### Trigger
+	Receipt of [`HttpMonitorInfoRequest`](super::server::WorldMessage::HttpMonitorInfoRequest).
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct HttpMonitorInfoResponse {
+	/// Port of the monitoring web server.
+	pub port: u16,
+	/// Whether the monitoring web server is running.
+	pub is_open: bool,
+	pub supports_sum: bool,
+	pub supports_detail: bool,
+	pub supports_who: bool,
+	pub supports_objects: bool,
+}
+
+/**
+	Responds to a [`SlashPushMapRequest`](super::server::SlashPushMapRequest).
+
+	### Handling
+	If [`success`](Self::success) is `true`, send the map with [`SlashPushMap`](super::server::SlashPushMap). Otherwise, report the failure to the user.
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct SlashPushMapResponse {
+	pub map_id: MapId,
+	/// Whether the push is allowed.
+	pub success: bool,
+}
+
+/**
+	Provides the server's version of a map.
+
+	### Trigger
+	Receipt of [`SlashPullMap`](super::server::SlashPullMap).
+
+	### Handling
+	If [`success`](Self::success) is `true`, replace the local copy of the map with [`map_data`](Self::map_data).
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct SlashPullMapResponse {
+	pub map_id: MapId,
+	/// Whether the map could be read.
+	pub success: bool,
+	/// Contents of the map file, empty if unsuccessful.
+	pub map_data: LVec<u32, u8>,
+}
+
+/// Responds to a [`LockMapRequest`](super::server::LockMapRequest).
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct SlashLockMapResponse {
+	pub map_id: MapId,
+	/// Whether the map is now locked.
+	pub locked: bool,
+	/// Name of the player holding the lock, if locked by someone else.
+	pub locked_by: LuWString33,
+}
+
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 #[repr(u32)]
 pub enum BlueprintSaveResponseType {
 	EverythingWorked,
@@ -283,26 +381,32 @@ pub enum BlueprintSaveResponseType {
 	FindMatchesFailed,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct BlueprintSaveResponseModel {
 	pub blueprint_id: ObjId,
 	pub lxfml_compressed: LVec<u32, u8>,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct BlueprintSaveResponse {
 	pub local_id: ObjId,
 	pub reason_code: BlueprintSaveResponseType,
 	pub models: LVec<u32, BlueprintSaveResponseModel>,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct BlueprintLoadItemResponse {
 	pub success: bool,
 	pub item_id: ObjId,
 	pub dest_item_id: ObjId,
 }
 
+/// Debug text to be displayed in the client's console, sent by servers in development mode.
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct DebugOutput {
+	pub text: LuVarWString<u32>,
+}
+
 /**
 	Informs the client that another player has asked them to be their friend.
 
@@ -315,7 +419,7 @@ pub struct BlueprintLoadItemResponse {
 	### Response
 	Respond with [`AddFriendResponse`](crate::chat::server::AddFriendResponse) once the user has made their choice.
 */
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct AddFriendRequest {
 	/// Name of the requesting character.
 	pub sender_name: LuWString33,
@@ -323,7 +427,7 @@ pub struct AddFriendRequest {
 	pub is_best_friend_request: bool,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Arbitrary, Debug, PartialEq)]
 #[repr(u8)]
 pub enum AddFriendResponseType {
 	Accepted { is_online: bool, sender_id: ObjId, zone_id: ZoneId, is_best_friend: bool, is_free_trial: bool },
@@ -341,7 +445,7 @@ pub enum AddFriendResponseType {
 	FriendIsFreeTrial,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Arbitrary, Debug, PartialEq)]
 pub struct AddFriendResponse {
 	pub char_name: LuWString33,
 	pub response_type: AddFriendResponseType,
@@ -413,7 +517,7 @@ impl<'a, W: Write> Serialize<LE, W> for &'a AddFriendResponse {
 	}
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 #[trailing_padding = 6]
 pub struct FriendState {
 	pub is_online: bool,
@@ -425,7 +529,7 @@ pub struct FriendState {
 	pub char_name: LuWString33,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 #[repr(u8)]
 #[post_disc_padding = 2]
 pub enum GetFriendsListResponse {
@@ -433,7 +537,7 @@ pub enum GetFriendsListResponse {
 	GeneralError,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 #[repr(u8)]
 pub enum FriendUpdateType {
 	Logout,
@@ -442,7 +546,7 @@ pub enum FriendUpdateType {
 	FreeTrialChange,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct FriendUpdateNotify {
 	pub update_type: FriendUpdateType,
 	pub char_name: LuWString33,
@@ -451,14 +555,14 @@ pub struct FriendUpdateNotify {
 	pub is_free_trial: bool,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 #[trailing_padding = 6]
 pub struct IgnoreState {
 	pub object_id: ObjId,
 	pub char_name: LuWString33,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 #[repr(u8)]
 #[post_disc_padding = 2]
 pub enum GetIgnoreListResponse {
@@ -478,7 +582,7 @@ pub enum GetIgnoreListResponse {
 	### Response
 	Respond with [`TeamInviteResponse`](crate::chat::server::TeamInviteResponse) once the user has made their choice.
 */
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct TeamInvite {
 	/// Name of the requesting character.
 	pub sender_name: LuWString33,
@@ -486,13 +590,13 @@ pub struct TeamInvite {
 	pub sender_id: ObjId,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct MinimumChatModeResponse {
 	pub chat_mode: u8, // todo: type?
 	pub chat_channel: ChatChannel,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct MinimumChatModeResponsePrivate {
 	pub chat_mode: u8, // todo: type?
 	pub chat_channel: ChatChannel,
@@ -500,7 +604,7 @@ pub struct MinimumChatModeResponsePrivate {
 	pub recipient_gm_level: u8,
 }
 
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Clone, Copy, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct ModerationSpan {
 	pub start_index: u8,
 	pub length: u8,
@@ -515,6 +619,20 @@ pub struct ChatModerationString {
 	pub spans: Vec<ModerationSpan>,
 }
 
+// there are at most 64 spans, and spans of length 0 are skipped when reading
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ChatModerationString {
+	fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+		let request_id = u.arbitrary()?;
+		let chat_mode = u.arbitrary()?;
+		let whisper_name = u.arbitrary()?;
+		let mut spans: Vec<ModerationSpan> = u.arbitrary()?;
+		spans.retain(|x| x.length != 0);
+		spans.truncate(64);
+		Ok(Self { request_id, chat_mode, whisper_name, spans })
+	}
+}
+
 impl<R: Read + LERead> Deserialize<LE, R> for ChatModerationString {
 	fn deserialize(reader: &mut R) -> Res<Self> {
 		let _string_okay: bool = LERead::read(reader)?;
@@ -572,7 +690,7 @@ impl<'a, W: Write + LEWrite> Serialize<LE, W> for &'a ChatModerationString {
 	### Response
 	None.
 */
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct UpdateFreeTrialStatus {
 	/// Whether the player is on free trial.
 	pub is_free_trial: bool,
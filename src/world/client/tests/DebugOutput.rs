@@ -0,0 +1,5 @@
+ClientMessage::DebugOutput(
+	DebugOutput {
+		text: lu!("Loaded 1337 objects"),
+	},
+)
@@ -0,0 +1,10 @@
+ClientMessage::HttpMonitorInfoResponse(
+	HttpMonitorInfoResponse {
+		port: 8080,
+		is_open: true,
+		supports_sum: true,
+		supports_detail: false,
+		supports_who: true,
+		supports_objects: false,
+	},
+)
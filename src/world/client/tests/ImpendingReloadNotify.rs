@@ -0,0 +1 @@
+ClientMessage::ImpendingReloadNotify
@@ -0,0 +1,8 @@
+ClientMessage::MakeGmResponse(
+	MakeGmResponse {
+		success: true,
+		highest_level: 9,
+		prev_level: 0,
+		new_level: 9,
+	},
+)
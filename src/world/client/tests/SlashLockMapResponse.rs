@@ -0,0 +1,7 @@
+ClientMessage::SlashLockMapResponse(
+	SlashLockMapResponse {
+		map_id: 1100,
+		locked: true,
+		locked_by: lu!("Dev Bob"),
+	},
+)
@@ -0,0 +1,7 @@
+ClientMessage::SlashPullMapResponse(
+	SlashPullMapResponse {
+		map_id: 1100,
+		success: true,
+		map_data: vec![108, 117, 122, 0, 38, 0, 0, 0, 1, 2, 3, 4].into(),
+	},
+)
@@ -0,0 +1,6 @@
+ClientMessage::SlashPushMapResponse(
+	SlashPushMapResponse {
+		map_id: 1100,
+		success: true,
+	},
+)
@@ -1,22 +1,43 @@
 use std::cmp::PartialEq;
+use std::io::{Read, Result as Res, Write};
 
-use endio::{Deserialize, Serialize};
-use lu_packets_derive::{FromVariants, GameMessage, GmParam, VariantTests};
+use endio::{Deserialize, LE, LERead, LEWrite, Serialize};
+use lu_packets_derive::{Arbitrary, FromVariants, GameMessage, GmParam, MessageDeserialize, MessageHandler, Reflect, Schema, VariantTests};
 
-use crate::common::{ObjId, OBJID_EMPTY};
+use crate::common::{bit_reader, bit_writer, GuildId, LuWString33, LVec, ObjId, OBJID_EMPTY, read_bit};
 
 use crate::world::{CloneId, CLONE_ID_INVALID, Lot, LOT_NULL, LuNameValue, MapId, MAP_ID_INVALID, Quaternion, Vector3, ZoneId};
 use crate::world::amf3::Amf3;
-pub use super::{EquipInventory, InventoryType, KillType, UnEquipInventory, LootType, MissionState, PetNotificationType, MoveItemInInventory, MoveInventoryBatch, RemoveSkill, RemoveItemFromInventory, SetIgnoreProjectileCollision, ModifyPlayerZoneStatistic};
+pub use super::{Die, DropClientLoot, EquipInventory, InventoryType, FlagId, GuildRank, TeamLootOption, KillType, UnEquipInventory, LootType, MissionId, MissionState, PetNotificationType, MoveItemInInventory, MoveItemResponseCode, MoveInventoryBatch, RemoveSkill, RemoveItemFromInventory, SetIgnoreProjectileCollision, ModifyPlayerZoneStatistic, RemoveBuff, ArrangeItemType, BehaviorSlot, BuildModeValue, CastType, PetCommandType, PropertyModerationStatus, ActivityId, SkillId, SoundEventName, SoundGuid, TradeItem};
 use super::{GmString, GmWString};
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// The message ID is translated for the network version of the reader or writer, see [`Versioned`](crate::common::Versioned).
+#[derive(Arbitrary, Debug, PartialEq, Reflect, Schema)]
 pub struct SubjectGameMessage {
 	pub subject_id: ObjId,
 	pub message: GameMessage,
 }
 
-#[derive(Debug, Deserialize, FromVariants, PartialEq, Serialize, VariantTests)]
+impl<R: Read> Deserialize<LE, R> for SubjectGameMessage {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let (subject_id, message) = super::read_subject_message::<_, GameMessage>(reader)?;
+		Ok(Self { subject_id, message })
+	}
+}
+
+impl<'a, W: Write> Serialize<LE, W> for &'a SubjectGameMessage {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		super::write_subject_message::<_, GameMessage>(writer, self.subject_id, &self.message)
+	}
+}
+
+/// Calls the [`GameMessageHandler`] method corresponding to the game message, with the message's subject.
+pub fn dispatch(msg: &SubjectGameMessage, handler: &mut impl GameMessageHandler) {
+	msg.message.dispatch(msg.subject_id, handler)
+}
+
+#[derive(Arbitrary, Debug, MessageDeserialize, FromVariants, MessageHandler, PartialEq, Reflect, Schema, Serialize, VariantTests)]
+#[handler_args(subject: ObjId)]
 #[repr(u16)]
 pub enum GameMessage {
 	Teleport(Teleport) = 19,
@@ -44,6 +65,7 @@ pub enum GameMessage {
 	UnEquipInventory(UnEquipInventory) = 233,
 	OfferMission(OfferMission) = 248,
 	NotifyMission(NotifyMission) = 254,
+	NotifyMissionTask(NotifyMissionTask) = 255,
 	RebuildNotifyState(RebuildNotifyState) = 336,
 	ToggleInteractionUpdates(ToggleInteractionUpdates) = 356,
 	TerminateInteraction(TerminateInteraction) = 357,
@@ -79,8 +101,17 @@ pub enum GameMessage {
 	ShowActivityCountdown(ShowActivityCountdown) = 568,
 	DisplayTooltip(DisplayTooltip) = 569,
 	StartActivityTime(StartActivityTime) = 576,
+	SetCharGuildInfo(SetCharGuildInfo) = 581,
+	ReRenderNameBillboard = 584,
+	GuildAddPlayer(GuildAddPlayer) = 587,
+	GuildRemovePlayer(GuildRemovePlayer) = 588,
+	GuildUpdatePlayerName(GuildUpdatePlayerName) = 589,
+	GuildSetPlayerRank(GuildSetPlayerRank) = 590,
+	GuildSetOnlineStatus(GuildSetOnlineStatus) = 591,
+	GuildRenderName(GuildRenderName) = 596,
 	ActivityPause(ActivityPause) = 602,
 	UseItemResult(UseItemResult) = 607,
+	DisplayGuildCreateBox = 626,
 	PetResponse(PetResponse) = 641,
 	SendActivitySummaryLeaderboardData(SendActivitySummaryLeaderboardData) = 649,
 	ClientNotifyPet(ClientNotifyPet) = 659,
@@ -100,12 +131,26 @@ pub enum GameMessage {
 	PlatformResync(PlatformResync) = 761,
 	PlayCinematic(PlayCinematic) = 762,
 	EndCinematic(EndCinematic) = 763,
+	AttachCameraToRail(AttachCameraToRail) = 765,
+	DetachCameraFromRail = 766,
+	AddCameraEffect(AddCameraEffect) = 773,
+	RemoveCameraEffect(RemoveCameraEffect) = 774,
+	RemoveAllCameraEffects = 775,
 	ScriptNetworkVarUpdate(ScriptNetworkVarUpdate) = 781,
+	PlayNdAudioEmitter(PlayNdAudioEmitter) = 821,
+	StopNdAudioEmitter(StopNdAudioEmitter) = 822,
+	ActivateNdAudioMusicCue(ActivateNdAudioMusicCue) = 827,
+	DeactivateNdAudioMusicCue(DeactivateNdAudioMusicCue) = 828,
+	Play2dAmbientSound(Play2dAmbientSound) = 831,
+	Play3dAmbientSound(Play3dAmbientSound) = 834,
+	Stop3dAmbientSound(Stop3dAmbientSound) = 835,
 	BroadcastTextToChatbox(BroadcastTextToChatbox) = 858,
 	OpenPropertyVendor = 861,
 	ServerTradeInvite(ServerTradeInvite) = 870,
 	ServerTradeInitialReply(ServerTradeInitialReply) = 873,
 	ServerTradeFinalReply(ServerTradeFinalReply) = 874,
+	ServerTradeUpdate(ServerTradeUpdate) = 877,
+	ServerTradeCancel = 883,
 	ServerTradeAccept(ServerTradeAccept) = 884,
 	GetLastCustomBuild(GetLastCustomBuild) = 891,
 	SetIgnoreProjectileCollision(SetIgnoreProjectileCollision) = 903,
@@ -127,11 +172,14 @@ pub enum GameMessage {
 	SetModelToBuild(SetModelToBuild) = 1077,
 	SpawnModelBricks(SpawnModelBricks) = 1078,
 	NotifyClientFailedPrecondition(NotifyClientFailedPrecondition) = 1081,
+	StartModularBuild(StartModularBuild) = 1092,
+	ModularBuildEnd = 1095,
 	ModuleAssemblyDbDataForClient(ModuleAssemblyDbDataForClient) = 1131,
 	EchoSyncSkill(EchoSyncSkill) = 1144,
 	DoClientProjectileImpact(DoClientProjectileImpact) = 1151,
 	SetPlayerAllowedRespawn(SetPlayerAllowedRespawn) = 1165,
 	UiMessageServerToSingleClient(UiMessageServerToSingleClient) = 1184,
+	UiMessageServerToAllClients(UiMessageServerToAllClients) = 1185,
 	UncastSkill(UncastSkill) = 1206,
 	FireEventClientSide(FireEventClientSide) = 1213,
 	ChangeObjectWorldState(ChangeObjectWorldState) = 1223,
@@ -177,8 +225,12 @@ pub enum GameMessage {
 	StartCelebrationEffect(StartCelebrationEffect) = 1618,
 	SetLocalTeam(SetLocalTeam) = 1636,
 	ServerDoneLoadingAllObjects = 1642,
+	AddBuff(AddBuff) = 1647,
+	RemoveBuff(RemoveBuff) = 1648,
 	ResponseMoveItemBetweenInventoryTypes(ResponseMoveItemBetweenInventoryTypes) = 1667,
 	PlayerSetCameraCyclingMode(PlayerSetCameraCyclingMode) = 1676,
+	ForceCameraTargetCycle(ForceCameraTargetCycle) = 1682,
+	SetCameraZoom(SetCameraZoom) = 1683,
 	SetMountInventoryId(SetMountInventoryId) = 1726,
 	NotifyLevelRewards(NotifyLevelRewards) = 1735,
 	ClientCancelMoveSkill = 1747,
@@ -203,38 +255,6 @@ pub struct Teleport {
 	pub z: f32,
 }
 
-#[derive(Debug, GameMessage, PartialEq)]
-pub struct DropClientLoot {
-	#[default(false)]
-	pub use_position: bool,
-	#[default(Vector3::ZERO)]
-	pub final_position: Vector3,
-	pub currency: i32, // todo: unsigned?
-	pub item_template: Lot,
-	pub loot_id: ObjId,
-	pub owner: ObjId,
-	pub source_obj: ObjId,
-	#[default(Vector3::ZERO)]
-	pub spawn_position: Vector3,
-}
-
-#[derive(Debug, GameMessage, PartialEq)]
-pub struct Die {
-	#[default(false)]
-	pub client_death: bool,
-	#[default(true)]
-	pub spawn_loot: bool,
-	pub death_type: GmWString,
-	pub direction_relative_angle_xz: f32,
-	pub direction_relative_angle_y: f32,
-	pub direction_relative_force: f32,
-	#[default(KillType::Violent)]
-	pub kill_type: KillType,
-	pub killer_id: ObjId,
-	#[default(OBJID_EMPTY)]
-	pub loot_owner_id: ObjId,
-}
-
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct PreloadAnimation {
 	pub animation_id: GmWString,
@@ -271,8 +291,8 @@ pub struct EchoStartSkill {
 	pub used_mouse: bool,
 	#[default(0.0)]
 	pub caster_latency: f32,
-	#[default(0)]
-	pub cast_type: i32, // todo: type
+	#[default(CastType::None)]
+	pub cast_type: CastType,
 	#[default(Vector3::ZERO)]
 	pub last_clicked_posit: Vector3,
 	pub optional_originator_id: ObjId,
@@ -280,8 +300,8 @@ pub struct EchoStartSkill {
 	pub optional_target_id: ObjId,
 	#[default(Quaternion::IDENTITY)]
 	pub originator_rot: Quaternion,
-	pub bitstream: Vec<u8>,
-	pub skill_id: u32, // todo: type
+	pub bitstream: LVec<u32, u8>,
+	pub skill_id: SkillId,
 	#[default(0)]
 	pub skill_handle: u32,
 }
@@ -292,21 +312,22 @@ pub struct AddSkill {
 	pub ai_combat_weight: i32,
 	#[default(false)]
 	pub from_skill_set: bool,
-	#[default(0)]
-	pub cast_type: i32, // todo: type
+	#[default(CastType::None)]
+	pub cast_type: CastType,
 	#[default(-1.0)]
 	pub time_secs: f32,
 	#[default(-1)]
 	pub times_can_cast: i32,
-	pub skill_id: u32, // todo: type
-	#[default(-1)]
-	pub slot_id: i32, // todo: type
+	pub skill_id: SkillId,
+	#[default(BehaviorSlot::Invalid)]
+	pub slot_id: BehaviorSlot,
 	#[default(true)]
 	pub temporary: bool,
 }
 
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct SetCurrency {
+	/// The new total of the player's coins.
 	pub currency: i64,
 	#[default(LootType::None)]
 	pub loot_type: LootType,
@@ -321,6 +342,13 @@ pub struct SetCurrency {
 	pub source_type: LootType,
 }
 
+impl SetCurrency {
+	/// Sets the player's coins after coins were looted, with where they came from. The client shows the change with the source's loot effects.
+	pub fn looted(currency: i64, source_type: LootType, source_object: ObjId, position: Vector3) -> Self {
+		Self { currency, loot_type: LootType::None, position, source_lot: LOT_NULL, source_object, source_trade_id: OBJID_EMPTY, source_type }
+	}
+}
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct TeamPickupItem {
 	pub loot_id: ObjId,
@@ -388,7 +416,7 @@ pub struct SetStunned {
 	pub ignore_immunity: bool,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum StunState {
 	Push,
@@ -409,7 +437,7 @@ pub struct SetStunImmunity {
 	pub immune_to_stun_use_item: bool,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum ImmunityState {
 	Push,
@@ -438,7 +466,7 @@ pub struct EnableRebuild {
 	pub user: ObjId,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum FailReason {
 	NotGiven,
@@ -473,18 +501,46 @@ pub struct AddItemToInventoryClientSync {
 
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct OfferMission {
-	pub mission_id: i32,
+	pub mission_id: MissionId,
 	pub offerer: ObjId,
 }
 
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct NotifyMission {
-	pub mission_id: i32,
+	pub mission_id: MissionId,
 	pub mission_state: MissionState,
 	#[default(false)]
 	pub sending_rewards: bool,
 }
 
+/// Updates the progress of a mission's tasks.
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct NotifyMissionTask {
+	pub mission_id: MissionId,
+	pub task_mask: TaskMask,
+	/// New progress of each updated task, in task order.
+	pub updates: LVec<u8, f32>,
+}
+
+/// Tasks updated by a [`NotifyMissionTask`]. Bit `index + 1` is set for each updated task, with `index` being the task's position in the mission.
+#[derive(Arbitrary, Clone, Copy, Debug, Deserialize, Eq, GmParam, PartialEq, Reflect, Schema, Serialize)]
+pub struct TaskMask(pub u32);
+
+impl TaskMask {
+	pub fn new(indexes: impl IntoIterator<Item=u32>) -> Self {
+		Self(indexes.into_iter().fold(0, |mask, index| mask | 1 << (index + 1)))
+	}
+
+	pub fn contains(&self, index: u32) -> bool {
+		self.0 & 1 << (index + 1) != 0
+	}
+
+	/// Positions of the updated tasks, in the order of [`NotifyMissionTask::updates`].
+	pub fn indexes(&self) -> impl Iterator<Item=u32> + '_ {
+		(0..31).filter(move |&index| self.contains(index))
+	}
+}
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct RebuildNotifyState {
 	pub prev_state: RebuildChallengeState,
@@ -492,7 +548,7 @@ pub struct RebuildNotifyState {
 	pub player: ObjId,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum RebuildChallengeState {
 	Open = 0,
@@ -514,7 +570,7 @@ pub struct TerminateInteraction {
 	pub terminate_type: TerminateType,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum TerminateType {
 	Range,
@@ -548,14 +604,14 @@ pub struct ActivityStop {
 
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct CancelMission {
-	pub mission_id: i32,
+	pub mission_id: MissionId,
 	pub reset_completed: bool,
 }
 
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct ResetMissions {
-	#[default(-1)]
-	pub mission_id: i32,
+	#[default(MissionId(-1))]
+	pub mission_id: MissionId,
 }
 
 #[derive(Debug, GameMessage, PartialEq)]
@@ -574,17 +630,61 @@ pub struct SetUserCtrlCompPause {
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct NotifyClientFlagChange {
 	pub flag: bool,
-	pub flag_id: i32,
+	pub flag_id: FlagId,
+}
+
+impl NotifyClientFlagChange {
+	/// Confirms that the player joined a faction, after they set its flag with [`SetFlag`](super::server::SetFlag), see [`FlagId::is_faction`].
+	pub fn faction_joined(faction: FlagId) -> Self {
+		Self { flag: true, flag_id: faction }
+	}
+}
+
+gm_enum! {
+	/// ID of a help message shown by [`Help`].
+	pub enum HelpId: i32 {
+		None = 0,
+		UnlockMinimap = 2,
+		ToggleTooltipOutOfTimeRebuild = 3,
+		ToggleTooltipLeaveLoseRebuild = 4,
+		ToggleControlsTutorialWalking = 6,
+		DisplayTutorialPassportFirstSmash = 7,
+		TooltipFirstImaginationPickup = 8,
+		PetTamingMinigameTutorial = 15,
+		ToggleControlsTutorialJumping = 26,
+		ToggleControlsTutorialDoubleJumping = 27,
+		ToggleControlsTutorialCamera = 28,
+		ToggleControlsTutorialSmash = 30,
+		UiModBuildPutOnHat = 40,
+		UiModBuildEquipFirstModule = 41,
+		UiModBuildGoLaunchRocket = 44,
+		UiModBuildTalkToSkylane = 45,
+		PetDespawnByOwnerHibernate = 69,
+		PetDespawnTamingNewPet = 70,
+		UiInventoryFullCannotPickupItem = 86,
+	}
 }
 
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct Help {
-	pub help_id: i32, // todo: type
+	pub help_id: HelpId,
 }
 
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct VendorTransactionResult {
-	pub result: i32, // todo: type
+	pub result: VendorTransactionResultCode,
+}
+
+gm_enum! {
+	/// Result of buying from or selling to a vendor, see [`VendorTransactionResult`].
+	pub enum VendorTransactionResultCode: i32 {
+		SellSuccess = 0,
+		SellFail = 1,
+		PurchaseSuccess = 2,
+		PurchaseFail = 3,
+		DonationFail = 4,
+		DonationFull = 5,
+	}
 }
 
 #[derive(Debug, GameMessage, PartialEq)]
@@ -613,11 +713,18 @@ pub struct TransferToZone {
 	#[default(0.0)]
 	pub rot_z: f32,
 	pub spawn_point: GmWString,
-	pub instance_type: u8, // todo: type
+	pub instance_type: InstanceType,
 	#[default(MAP_ID_INVALID)]
 	pub zone_id: MapId,
 }
 
+gm_enum! {
+	/// Kind of instance a player is transferred to, see [`TransferToZone`].
+	pub enum InstanceType: u8 {
+		Public = 0,
+	}
+}
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct TransferToZoneCheckedIm {
 	#[default(false)]
@@ -711,12 +818,20 @@ pub struct PlaceModelResponse {
 	pub position: Vector3,
 	#[default(OBJID_EMPTY)]
 	pub property_plaque_id: ObjId,
-	#[default(0)]
-	pub response: i32, // todo: type
+	#[default(PlaceModelResponseCode::None)]
+	pub response: PlaceModelResponseCode,
 	#[default(Quaternion::IDENTITY)]
 	pub rotation: Quaternion,
 }
 
+gm_enum! {
+	/// Result of placing a model on a property, see [`PlaceModelResponse`].
+	pub enum PlaceModelResponseCode: i32 {
+		None = 0,
+		Success = 14,
+	}
+}
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct SetJetPackMode {
 	#[default(false)]
@@ -751,7 +866,8 @@ pub struct ShowActivityCountdown {
 	pub play_additional_sound: bool,
 	pub play_countdown_sound: bool,
 	pub sound_name: GmWString,
-	pub state_to_play_sound_on: i32, // todo: type
+	/// The countdown number at which to play the sound.
+	pub state_to_play_sound_on: i32,
 }
 
 #[derive(Debug, GameMessage, PartialEq)]
@@ -779,6 +895,57 @@ pub struct StartActivityTime {
 	pub start_time: f32,
 }
 
+/**
+	Sets the guild shown for a character, sent from the character. Also update [`SocialInfo`](crate::raknet::client::replica::character::SocialInfo) in the character's replica so that other players see it.
+
+	Send [`GameMessage::ReRenderNameBillboard`] afterwards to update the name shown above the character.
+*/
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct SetCharGuildInfo {
+	pub guild_id: GuildId,
+	pub guild_name: GmWString,
+}
+
+/// Adds a member to the guild roster of the receiving player, sent from the player. Used to populate the roster after [`RequestGuildData`](super::server::GameMessage::RequestGuildData) and when someone joins.
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct GuildAddPlayer {
+	pub player_id: ObjId,
+	pub player_name: GmWString,
+	pub rank: GuildRank,
+	#[default(false)]
+	pub is_online: bool,
+}
+
+/// Removes a member from the guild roster of the receiving player, sent from the player.
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct GuildRemovePlayer {
+	pub player_id: ObjId,
+}
+
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct GuildUpdatePlayerName {
+	pub player_id: ObjId,
+	pub player_name: GmWString,
+}
+
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct GuildSetPlayerRank {
+	pub player_id: ObjId,
+	pub rank: GuildRank,
+}
+
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct GuildSetOnlineStatus {
+	pub player_id: ObjId,
+	pub is_online: bool,
+}
+
+/// Shows or hides the guild name above the subject's name.
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct GuildRenderName {
+	pub render_name: bool,
+}
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct ActivityPause {
 	pub pause: bool,
@@ -794,15 +961,26 @@ pub struct UseItemResult {
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct PetResponse {
 	pub obj_id_pet: ObjId,
-	pub pet_command_type: i32, // todo: type
-	pub response: i32,         // todo: type
-	pub type_id: i32,          // todo: type
+	pub pet_command_type: PetCommandType,
+	/// Response code of the pet, depending on the command.
+	pub response: i32,
+	/// Argument of the command, like the emote ID for [`PetCommandType::Emote`].
+	pub type_id: i32,
+}
+
+gm_enum! {
+	/// Kind of leaderboard sent with [`SendActivitySummaryLeaderboardData`], the [`QueryType`](super::server::QueryType) it was requested with.
+	pub enum LeaderboardInfoType: i32 {
+		TopAll = 0,
+		TopCharacter = 1,
+		TopSocial = 2,
+	}
 }
 
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct SendActivitySummaryLeaderboardData {
-	pub game_id: i32,   // todo: type
-	pub info_type: i32, // todo: type
+	pub game_id: ActivityId,
+	pub info_type: LeaderboardInfoType,
 	pub leaderboard_data: LuNameValue,
 	pub throttled: bool,
 	pub weekly: bool,
@@ -826,7 +1004,7 @@ pub struct NotifyPetTamingMinigame {
 	pub tele_rot: Quaternion,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum PetTamingNotifyType {
 	Success,
@@ -867,7 +1045,7 @@ pub struct PetNameChanged {
 	pub owner_name: GmWString,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum PetModerationStatus {
 	Unnamed,
@@ -884,7 +1062,7 @@ pub struct ShowPetActionButton {
 	pub show: bool,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum PetAbilityType {
 	Invalid, // todo: option
@@ -904,7 +1082,7 @@ pub struct UseItemRequirementsResponse {
 	pub use_response: UseItemResponse,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum UseItemResponse {
 	NoImaginationForPet = 1,
@@ -938,10 +1116,11 @@ pub struct PropertyRentalResponse {
 	pub clone_id: CloneId,
 	pub code: PropertyRentalResponseCode,
 	pub property_id: ObjId,
-	pub rentdue: i64, // todo: type
+	/// Unix time at which the rent is due, or 0 if there's no rent.
+	pub rentdue: i64,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum PropertyRentalResponseCode {
 	Ok = 0,
@@ -958,7 +1137,7 @@ pub struct PlatformResync {
 	pub reverse: bool,
 	pub stop_at_desired_waypoint: bool,
 	pub command: i32,
-	pub state: i32, // todo: type
+	pub state: PlatformState,
 	pub unexpected_command: i32,
 	pub idle_time_elapsed: f32,
 	pub move_time_elapsed: f32,
@@ -971,6 +1150,23 @@ pub struct PlatformResync {
 	pub unexpected_rotation: Quaternion,
 }
 
+/// State of a moving platform, as bit flags, see [`PlatformResync`].
+#[derive(Arbitrary, Clone, Copy, Debug, Deserialize, Eq, GmParam, Hash, PartialEq, Reflect, Schema, Serialize)]
+pub struct PlatformState(pub u32);
+
+impl PlatformState {
+	pub const WAITING: Self = Self(1 << 0);
+	pub const TRAVELLING: Self = Self(1 << 1);
+	pub const STOPPED: Self = Self(1 << 2);
+	pub const REACHED_DESIRED_WAYPOINT: Self = Self(1 << 3);
+	pub const REACHED_FINAL_WAYPOINT: Self = Self(1 << 4);
+
+	/// Whether all flags of `other` are set.
+	pub fn contains(self, other: Self) -> bool {
+		self.0 & other.0 == other.0
+	}
+}
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct PlayCinematic {
 	#[default(true)]
@@ -997,7 +1193,7 @@ pub struct PlayCinematic {
 	pub start_time_advance: f32,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum EndBehavior {
 	Return,
@@ -1013,11 +1209,120 @@ pub struct EndCinematic {
 	pub path_name: GmWString,
 }
 
+/// Moves the player's camera along a camera path from the zone file, until it's detached with [`GameMessage::DetachCameraFromRail`].
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct AttachCameraToRail {
+	pub path_name: GmWString,
+	#[default(0)]
+	pub start_waypoint: i32,
+	#[default(false)]
+	pub reverse: bool,
+}
+
+/**
+	Adds an effect to the player's camera, like a shake or a field of view change. The effect is removed after `duration`, or with [`RemoveCameraEffect`].
+
+	The effect's settings are in `config`, see [`shake`](Self::shake) and [`fov`](Self::fov).
+*/
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct AddCameraEffect {
+	pub effect_id: GmWString,
+	pub effect_type: CameraEffectType,
+	/// In seconds, or -1 to keep the effect until it's removed.
+	#[default(-1.0)]
+	pub duration: f32,
+	pub config: Amf3,
+}
+
+impl AddCameraEffect {
+	/// Shakes the camera with the given amplitude.
+	pub fn shake(effect_id: GmWString, duration: f32, amplitude: f32) -> Self {
+		Self { effect_id, effect_type: CameraEffectType::Shake, duration, config: amf3! { "amplitude": amplitude } }
+	}
+
+	/// Changes the camera's field of view, in degrees.
+	pub fn fov(effect_id: GmWString, duration: f32, fov: f32) -> Self {
+		Self { effect_id, effect_type: CameraEffectType::Fov, duration, config: amf3! { "fov": fov } }
+	}
+}
+
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
+#[repr(u32)]
+pub enum CameraEffectType {
+	Shake,
+	Fov,
+}
+
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct RemoveCameraEffect {
+	pub effect_id: GmWString,
+}
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct ScriptNetworkVarUpdate {
 	pub table_of_vars: LuNameValue,
 }
 
+/// Plays a sound event on the subject's ND audio emitter.
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct PlayNdAudioEmitter {
+	#[default(0)]
+	pub callback_message_data: i64,
+	#[default(OBJID_EMPTY)]
+	pub emitter_id: ObjId,
+	pub event_guid: SoundGuid,
+	/// Name of a meta event to play instead of `event_guid`, empty if unused.
+	pub meta_event_name: SoundEventName,
+	pub result: bool,
+	/// Object to notify when the event sends a callback.
+	#[default(OBJID_EMPTY)]
+	pub target_object_id_for_nd_audio_callback_messages: ObjId,
+}
+
+/// Stops a sound event started with [`PlayNdAudioEmitter`].
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct StopNdAudioEmitter {
+	#[default(true)]
+	pub allow_fadeout: bool,
+	#[default(OBJID_EMPTY)]
+	pub emitter_id: ObjId,
+	pub event_guid: SoundGuid,
+	pub meta_event_name: SoundEventName,
+}
+
+/// Starts a music cue, which replaces the zone music until it's deactivated.
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct ActivateNdAudioMusicCue {
+	pub music_cue_name: SoundEventName,
+}
+
+/// Stops a music cue started with [`ActivateNdAudioMusicCue`], returning to the zone music.
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct DeactivateNdAudioMusicCue {
+	pub music_cue_name: SoundEventName,
+}
+
+/// Plays a non-positional sound, used for ambient and UI sounds.
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct Play2dAmbientSound {
+	pub sound_guid: SoundGuid,
+	pub result: bool,
+}
+
+/// Sets a positional ambient sound on the subject, which plays from the subject's position until it's reset.
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct Play3dAmbientSound {
+	pub sound_guid: SoundGuid,
+	pub result: bool,
+}
+
+/// Resets a sound set with [`Play3dAmbientSound`].
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct Stop3dAmbientSound {
+	pub sound_guid: SoundGuid,
+	pub result: bool,
+}
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct BroadcastTextToChatbox {
 	pub attrs: LuNameValue,
@@ -1039,7 +1344,7 @@ pub struct ServerTradeInitialReply {
 	pub name: GmWString,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum ResultType {
 	NotFound,
@@ -1056,6 +1361,45 @@ pub struct ServerTradeFinalReply {
 	pub name: GmWString,
 }
 
+/// Forwards the offer of the other player in a trade, see [`ClientTradeUpdate`](crate::world::gm::server::ClientTradeUpdate).
+#[derive(Arbitrary, Debug, PartialEq, Reflect)]
+pub struct ServerTradeUpdate {
+	pub about_to_perform: bool,
+	pub currency: u64,
+	pub items: LVec<u32, TradeItem>,
+}
+
+impl<R: Read> Deserialize<LE, R> for ServerTradeUpdate {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let mut reader = bit_reader(reader);
+		let about_to_perform = read_bit(&mut reader)?;
+		let currency = LERead::read(&mut reader)?;
+		let items = LERead::read(&mut reader)?;
+		Ok(Self { about_to_perform, currency, items })
+	}
+}
+
+#[cfg(feature = "schema")]
+impl crate::schema::Schema for ServerTradeUpdate {
+	fn schema() -> crate::schema::Type {
+		super::trade_schema(std::any::type_name::<Self>(), "ServerTradeUpdate", vec![
+			("about_to_perform", bool::schema(), false),
+			("currency", u64::schema(), false),
+			("items", <LVec<u32, TradeItem>>::schema(), false),
+		])
+	}
+}
+
+impl<'a, W: Write> Serialize<LE, W> for &'a ServerTradeUpdate {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		let mut writer = bit_writer(writer);
+		writer.write_bit(self.about_to_perform)?;
+		LEWrite::write(&mut writer, self.currency)?;
+		LEWrite::write(&mut writer, &self.items)?;
+		writer.flush()
+	}
+}
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct ServerTradeAccept {
 	#[default(false)]
@@ -1085,8 +1429,8 @@ pub struct OrientToAngle {
 
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct PropertyModerationStatusUpdate {
-	#[default(-1)]
-	pub new_moderation_status: i32, // todo: type
+	#[default(PropertyModerationStatus::Unset)]
+	pub new_moderation_status: PropertyModerationStatus,
 	pub rejection_reason: GmWString,
 }
 
@@ -1140,11 +1484,11 @@ pub struct StartArrangingWithItem {
 	pub source_bag: InventoryType,
 	pub source_id: ObjId,
 	pub source_lot: Lot,
-	pub source_type: i32, // todo: type
+	pub source_type: ArrangeItemType,
 	pub target_id: ObjId,
 	pub target_lot: Lot,
 	pub target_pos: Vector3,
-	pub target_type: i32, // todo: type
+	pub target_type: ArrangeItemType,
 }
 
 #[derive(Debug, GameMessage, PartialEq)]
@@ -1154,15 +1498,15 @@ pub struct FinishArrangingWithItem {
 	pub new_source_bag: InventoryType,
 	pub new_source_id: ObjId,
 	pub new_source_lot: Lot,
-	pub new_source_type: i32, // todo: type
+	pub new_source_type: ArrangeItemType,
 	pub new_target_id: ObjId,
 	pub new_target_lot: Lot,
-	pub new_target_type: i32, // todo: type
+	pub new_target_type: ArrangeItemType,
 	pub new_target_pos: Vector3,
 	pub old_item_bag: InventoryType,
 	pub old_item_id: ObjId,
 	pub old_item_lot: Lot,
-	pub old_item_type: i32, // todo: type
+	pub old_item_type: ArrangeItemType,
 }
 
 #[derive(Debug, GameMessage, PartialEq)]
@@ -1172,8 +1516,8 @@ pub struct SetBuildModeConfirmed {
 	pub warn_visitors: bool,
 	#[default(false)]
 	pub mode_paused: bool,
-	#[default(1)]
-	pub mode_value: i32, // todo: type
+	#[default(BuildModeValue::Normal)]
+	pub mode_value: BuildModeValue,
 	pub player_id: ObjId,
 	#[default(Vector3::ZERO)]
 	pub start_pos: Vector3,
@@ -1205,6 +1549,16 @@ pub struct NotifyClientFailedPrecondition {
 	pub precondition_id: i32,
 }
 
+/// Opens the modular build UI at a build area, like a rocket or race car build station. The client starts building with [`ModularBuildBegin`](super::server::GameMessage::ModularBuildBegin).
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct StartModularBuild {
+	#[default(OBJID_EMPTY)]
+	pub build_area_id: ObjId,
+	pub build_start_pos: Vector3,
+	/// LOT of the assembly being built.
+	pub template_id: Lot,
+}
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct ModuleAssemblyDbDataForClient {
 	pub assembly_id: ObjId,
@@ -1215,7 +1569,7 @@ pub struct ModuleAssemblyDbDataForClient {
 pub struct EchoSyncSkill {
 	#[default(false)]
 	pub done: bool,
-	pub bitstream: Vec<u8>,
+	pub bitstream: LVec<u32, u8>,
 	pub behavior_handle: u32,
 	pub skill_handle: u32,
 }
@@ -1228,7 +1582,7 @@ pub struct DoClientProjectileImpact {
 	pub owner_id: ObjId,
 	#[default(OBJID_EMPTY)]
 	pub target_id: ObjId,
-	pub bitstream: Vec<u8>,
+	pub bitstream: LVec<u32, u8>,
 }
 
 #[derive(Debug, GameMessage, PartialEq)]
@@ -1242,9 +1596,48 @@ pub struct UiMessageServerToSingleClient {
 	pub message_name: GmString,
 }
 
+impl UiMessageServerToSingleClient {
+	/**
+		Pushes a UI state, like `Story` for cutscene text, which replaces the HUD until it's popped with [`pop_game_state`](Self::pop_game_state).
+
+		Panics if `state` is too long for an AMF3 string.
+	*/
+	pub fn push_game_state(state: &str) -> Self {
+		Self { args: amf3! { "state": state }, message_name: lu!(b"pushGameState") }
+	}
+
+	/// Pops a UI state pushed with [`push_game_state`](Self::push_game_state).
+	pub fn pop_game_state(state: &str) -> Self {
+		Self { args: amf3! { "state": state }, message_name: lu!(b"popGameState") }
+	}
+
+	/**
+		Shows or hides a flashing notification text on the HUD, like the ones announcing an activity's start.
+
+		Panics if `text` is too long for an AMF3 string.
+	*/
+	pub fn flashing_text(text: &str, visible: bool) -> Self {
+		Self { args: amf3! { "text": text, "visible": visible }, message_name: lu!(b"ToggleFlashingText") }
+	}
+}
+
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct UiMessageServerToAllClients {
+	pub args: Amf3,
+	pub message_name: GmString,
+}
+
+impl UiMessageServerToAllClients {
+	/// Shows or hides a flashing notification text on the HUD of every player in the zone, see [`UiMessageServerToSingleClient::flashing_text`].
+	pub fn flashing_text(text: &str, visible: bool) -> Self {
+		let UiMessageServerToSingleClient { args, message_name } = UiMessageServerToSingleClient::flashing_text(text, visible);
+		Self { args, message_name }
+	}
+}
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct UncastSkill {
-	pub skill_id: i32, // todo: type
+	pub skill_id: SkillId,
 }
 
 #[derive(Debug, GameMessage, PartialEq)]
@@ -1258,13 +1651,24 @@ pub struct FireEventClientSide {
 	pub sender_id: ObjId,
 }
 
+impl FireEventClientSide {
+	/**
+		Launches the rocket the player equipped at a rocket launchpad, sent from the launchpad. The client plays the launch and then asks to be moved to the destination with [`FireEventServerSide::zone_player`](super::server::FireEventServerSide::zone_player).
+
+		The rocket should also be attached to the player with [`ChangeObjectWorldState`].
+	*/
+	pub fn rocket_equipped(rocket_id: ObjId, clone_id: CloneId, player_id: ObjId) -> Self {
+		Self { args: lu!("RocketEquipped"), object: rocket_id, param1: clone_id as i64, param2: -1, sender_id: player_id }
+	}
+}
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct ChangeObjectWorldState {
 	#[default(ObjectWorldState::InWorld)]
 	pub new_state: ObjectWorldState,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum ObjectWorldState {
 	InWorld,
@@ -1347,7 +1751,7 @@ pub struct MatchResponse {
 	pub response: MatchResponseType,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum MatchResponseType {
 	Ok,
@@ -1365,7 +1769,7 @@ pub struct MatchUpdate {
 	pub match_update_type: MatchUpdateType,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum MatchUpdateType {
 	PlayerAdded,
@@ -1380,12 +1784,16 @@ pub enum MatchUpdateType {
 
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct ChangeIdleFlags {
-	#[default(0)]
-	pub off: i32, // todo: type
-	#[default(0)]
-	pub on: i32, // todo: type
+	#[default(IdleFlags(0))]
+	pub off: IdleFlags,
+	#[default(IdleFlags(0))]
+	pub on: IdleFlags,
 }
 
+/// Bit flags of idle behaviors, like idle animations, turned off or on with [`ChangeIdleFlags`].
+#[derive(Arbitrary, Clone, Copy, Debug, Deserialize, Eq, GmParam, Hash, PartialEq, Reflect, Schema, Serialize)]
+pub struct IdleFlags(pub i32);
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct NotifyRacingClient {
 	#[default(RacingClientNotificationType::Invalid)]
@@ -1396,7 +1804,7 @@ pub struct NotifyRacingClient {
 	pub single_client: ObjId,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum RacingClientNotificationType {
 	Invalid,
@@ -1497,7 +1905,7 @@ pub struct NotifyRewardMailed {
 	pub template_id: Lot,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(i32)]
 pub enum StatisticId {
 	CurrencyCollected = 1,
@@ -1557,12 +1965,61 @@ pub struct TeamSetLeader {
 pub struct TeamGetStatusResponse {
 	pub leader_id: ObjId,
 	pub leader_zone_id: ZoneId,
-	pub team_buffer: Vec<u8>,
-	pub loot_flag: u8, // todo: type
+	/// The team members other than the leader.
+	pub team_buffer: TeamRoster,
+	pub loot_flag: TeamLootOption,
 	pub num_of_other_players: u8,
 	pub leader_name: GmWString,
 }
 
+/// Member of a team, as listed in [`TeamGetStatusResponse::team_buffer`].
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct TeamMember {
+	pub name: LuWString33,
+	pub player_id: ObjId,
+	pub zone_id: ZoneId,
+}
+
+/// List of [`TeamMember`]s, prefixed by its size in bytes rather than the number of members.
+#[derive(Arbitrary, Debug, PartialEq, Reflect)]
+pub struct TeamRoster(pub Vec<TeamMember>);
+
+impl super::GmParam for TeamRoster {
+	fn deserialize<R: Read>(reader: &mut R) -> Res<Self> {
+		let len: u32 = LERead::read(reader)?;
+		let mut reader = reader.take(len as u64);
+		let mut members = vec![];
+		while reader.limit() > 0 {
+			members.push(LERead::read(&mut reader)?);
+		}
+		Ok(Self(members))
+	}
+
+	fn serialize<W: Write>(&self, writer: &mut W) -> Res<()> {
+		LEWrite::write(writer, (super::GmParam::size(self)? - 4) as u32)?;
+		for member in &self.0 {
+			LEWrite::write(writer, member)?;
+		}
+		Ok(())
+	}
+
+	fn size(&self) -> Res<usize> {
+		let mut size = 4;
+		for member in &self.0 {
+			size += crate::common::ByteSize::byte_size(member)?;
+		}
+		Ok(size)
+	}
+}
+
+/// Described as raw bytes, since the schema has no list type prefixed by its size in bytes.
+#[cfg(feature = "schema")]
+impl crate::schema::Schema for TeamRoster {
+	fn schema() -> crate::schema::Type {
+		<Vec<u8> as crate::schema::Schema>::schema()
+	}
+}
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct TeamAddPlayer {
 	#[default(false)]
@@ -1601,8 +2058,8 @@ pub struct SetResurrectRestoreValues {
 
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct SetPropertyModerationStatus {
-	#[default(-1)]
-	pub moderation_status: i32, // todo: type
+	#[default(PropertyModerationStatus::Unset)]
+	pub moderation_status: PropertyModerationStatus,
 }
 
 #[derive(Debug, GameMessage, PartialEq)]
@@ -1640,13 +2097,72 @@ pub struct StartCelebrationEffect {
 	pub sub_text: GmWString,
 }
 
+impl StartCelebrationEffect {
+	/**
+		Celebrates completing an achievement, like reaching a new rank. The ID, animation and texts are those of the achievement's row in the `CelebrationParameters` table, the other parameters are the defaults.
+
+		The client answers with [`CelebrationCompleted`](super::server::GameMessage::CelebrationCompleted) once the celebration ends.
+	*/
+	pub fn achievement(celebration_id: i32, animation: GmWString, duration: f32, icon_id: u32, main_text: GmWString, sub_text: GmWString) -> Self {
+		Self {
+			animation,
+			background_object: 11164,
+			camera_path_lot: 12458,
+			cele_lead_in: 1.0,
+			cele_lead_out: 0.8,
+			celebration_id,
+			duration,
+			icon_id,
+			main_text,
+			mixer_program: lu!(b""),
+			music_cue: lu!(b""),
+			path_node_name: lu!(b""),
+			sound_guid: lu!(b""),
+			sub_text,
+		}
+	}
+}
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct SetLocalTeam {
 	#[default(false)]
 	pub is_local: bool,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+/**
+	Adds a buff to the subject, which is then listed in its [`BuffConstruction`](crate::raknet::client::replica::buff::BuffConstruction).
+
+	The `cancel_on_*` flags correspond to the ones in [`BuffInfo`](crate::raknet::client::replica::buff::BuffInfo).
+*/
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct AddBuff {
+	pub added_by_teammate: bool,
+	pub apply_on_teammates: bool,
+	pub cancel_on_damage_absorb_ran_out: bool,
+	pub cancel_on_damaged: bool,
+	pub cancel_on_death: bool,
+	pub cancel_on_logout: bool,
+	pub cancel_on_move: bool,
+	pub cancel_on_remove_buff: bool,
+	pub cancel_on_ui: bool,
+	pub cancel_on_unequip: bool,
+	pub cancel_on_zone: bool,
+	pub ignore_immunities: bool,
+	/// Whether the buff grants immunity instead, listed in [`BuffConstruction::immunities`](crate::raknet::client::replica::buff::BuffConstruction::immunities).
+	pub is_immunity: bool,
+	/// Whether applying the buff again stacks, increasing its [`ref_count`](crate::raknet::client::replica::buff::BuffInfo::ref_count), instead of restarting it.
+	pub use_ref_count: bool,
+	#[default(OBJID_EMPTY)]
+	pub caster_id: ObjId,
+	#[default(OBJID_EMPTY)]
+	pub added_by_id: ObjId,
+	pub buff_id: u32,
+	/// Duration in milliseconds, 0 lasts until the buff is removed.
+	#[default(0)]
+	pub duration_ms: u32,
+}
+
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum ResponseMoveItemResponseCode {
 	Success,
@@ -1681,13 +2197,35 @@ pub struct PlayerSetCameraCyclingMode {
 	pub cycling_mode: CyclingMode,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum CyclingMode {
 	AllowCycleTeammates,
 	DisallowCycling,
 }
 
+/// Points the player's camera at another object, like a teammate while the player is dead, or at the target if one is given.
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct ForceCameraTargetCycle {
+	#[default(false)]
+	pub force_cycling: bool,
+	#[default(CyclingMode::AllowCycleTeammates)]
+	pub cycling_mode: CyclingMode,
+	#[default(OBJID_EMPTY)]
+	pub optional_target_id: ObjId,
+}
+
+/// Zooms the player's camera to a distance from the player, over `transition_time` seconds.
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct SetCameraZoom {
+	pub distance: f32,
+	#[default(0.0)]
+	pub transition_time: f32,
+	/// Whether the player can zoom the camera themselves afterwards.
+	#[default(true)]
+	pub allow_player_zoom: bool,
+}
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct SetMountInventoryId {
 	#[default(OBJID_EMPTY)]
@@ -1711,7 +2249,7 @@ pub struct MarkInventoryItemAsActive {
 	pub item_id: ObjId,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum UnequippableActiveType {
 	Pet,
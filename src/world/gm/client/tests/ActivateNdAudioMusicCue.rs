@@ -0,0 +1,5 @@
+GameMessage::ActivateNdAudioMusicCue(
+	ActivateNdAudioMusicCue {
+		music_cue_name: SoundEventName(lu!(b"Ninjago_Monastery_Boss")),
+	},
+)
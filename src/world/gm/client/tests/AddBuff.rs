@@ -0,0 +1,22 @@
+GameMessage::AddBuff(
+	AddBuff {
+		added_by_teammate: false,
+		apply_on_teammates: false,
+		cancel_on_damage_absorb_ran_out: false,
+		cancel_on_damaged: true,
+		cancel_on_death: true,
+		cancel_on_logout: false,
+		cancel_on_move: false,
+		cancel_on_remove_buff: true,
+		cancel_on_ui: true,
+		cancel_on_unequip: false,
+		cancel_on_zone: true,
+		ignore_immunities: false,
+		is_immunity: false,
+		use_ref_count: false,
+		caster_id: 1152921507004579166,
+		added_by_id: 0,
+		buff_id: 60,
+		duration_ms: 10000,
+	},
+)
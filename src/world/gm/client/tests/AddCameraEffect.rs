@@ -0,0 +1,10 @@
+GameMessage::AddCameraEffect(
+	AddCameraEffect {
+		effect_id: lu!("explosion_shake"),
+		effect_type: CameraEffectType::Shake,
+		duration: 1.5,
+		config: amf3! {
+			"amplitude": 0.75,
+		},
+	},
+)
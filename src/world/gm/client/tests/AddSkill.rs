@@ -2,11 +2,11 @@ GameMessage::AddSkill(
 	AddSkill {
 		ai_combat_weight: 0,
 		from_skill_set: false,
-		cast_type: 0,
+		cast_type: CastType::None,
 		time_secs: -1.0,
 		times_can_cast: -1,
-		skill_id: 1629,
-		slot_id: 0,
+		skill_id: SkillId(1629),
+		slot_id: BehaviorSlot::Primary,
 		temporary: true,
 	},
 )
@@ -0,0 +1,7 @@
+GameMessage::AttachCameraToRail(
+	AttachCameraToRail {
+		path_name: lu!("CameraPath_Intro"),
+		start_waypoint: 0,
+		reverse: false,
+	},
+)
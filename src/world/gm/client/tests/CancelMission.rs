@@ -1,6 +1,6 @@
 GameMessage::CancelMission(
 	CancelMission {
-		mission_id: 30,
+		mission_id: MissionId(30),
 		reset_completed: false,
 	},
 )
@@ -1,6 +1,6 @@
 GameMessage::ChangeIdleFlags(
 	ChangeIdleFlags {
-		off: 9,
-		on: 0,
+		off: IdleFlags(9),
+		on: IdleFlags(0),
 	},
 )
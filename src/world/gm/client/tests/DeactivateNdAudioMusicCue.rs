@@ -0,0 +1,5 @@
+GameMessage::DeactivateNdAudioMusicCue(
+	DeactivateNdAudioMusicCue {
+		music_cue_name: SoundEventName(lu!(b"Ninjago_Monastery_Boss")),
+	},
+)
@@ -5,6 +5,6 @@ GameMessage::DoClientProjectileImpact(
 		target_id: 1152921510757277251,
 		bitstream: vec![
 			0, 76, 0, 32, 32, 0, 0, 0, 0, 0, 0, 0, 48,
-		],
+		].into(),
 	},
 )
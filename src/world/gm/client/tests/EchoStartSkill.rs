@@ -2,7 +2,7 @@ GameMessage::EchoStartSkill(
     EchoStartSkill{
         used_mouse: false,
         caster_latency: 0.0,
-        cast_type: 0,
+        cast_type: CastType::None,
         last_clicked_posit: Vector3::ZERO,
         optional_originator_id: 288300744895980623,
         optional_target_id: 1152921510123607030,
@@ -30,8 +30,8 @@ GameMessage::EchoStartSkill(
             0,
             0,
             0,
-        ],
-        skill_id: 239,
+        ].into(),
+        skill_id: SkillId(239),
         skill_handle: 2,
     },
 )
\ No newline at end of file
@@ -3,7 +3,7 @@ GameMessage::EchoSyncSkill(
 		done: false,
 		bitstream: vec![
 			0,
-		],
+		].into(),
 		behavior_handle: 2,
 		skill_handle: 1,
 	},
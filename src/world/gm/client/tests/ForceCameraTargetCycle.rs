@@ -0,0 +1,7 @@
+GameMessage::ForceCameraTargetCycle(
+	ForceCameraTargetCycle {
+		force_cycling: true,
+		cycling_mode: CyclingMode::DisallowCycling,
+		optional_target_id: 1152921510650291330,
+	},
+)
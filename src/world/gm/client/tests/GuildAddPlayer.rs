@@ -0,0 +1,8 @@
+GameMessage::GuildAddPlayer(
+	GuildAddPlayer {
+		player_id: 1152921510109196992,
+		player_name: lu!("Captain Gloves"),
+		rank: GuildRank::Officer,
+		is_online: true,
+	},
+)
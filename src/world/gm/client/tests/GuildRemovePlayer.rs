@@ -0,0 +1,5 @@
+GameMessage::GuildRemovePlayer(
+	GuildRemovePlayer {
+		player_id: 1152921510109196992,
+	},
+)
@@ -0,0 +1,5 @@
+GameMessage::GuildRenderName(
+	GuildRenderName {
+		render_name: true,
+	},
+)
@@ -0,0 +1,6 @@
+GameMessage::GuildSetOnlineStatus(
+	GuildSetOnlineStatus {
+		player_id: 1152921510109196992,
+		is_online: false,
+	},
+)
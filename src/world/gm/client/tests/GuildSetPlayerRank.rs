@@ -0,0 +1,6 @@
+GameMessage::GuildSetPlayerRank(
+	GuildSetPlayerRank {
+		player_id: 1152921510109196992,
+		rank: GuildRank::Leader,
+	},
+)
@@ -0,0 +1,6 @@
+GameMessage::GuildUpdatePlayerName(
+	GuildUpdatePlayerName {
+		player_id: 1152921510109196992,
+		player_name: lu!("Admiral Gloves"),
+	},
+)
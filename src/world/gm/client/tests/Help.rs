@@ -1,5 +1,5 @@
 GameMessage::Help(
 	Help {
-		help_id: 7,
+		help_id: HelpId::DisplayTutorialPassportFirstSmash,
 	},
 )
@@ -1,9 +1,9 @@
 GameMessage::MoveItemInInventory(
 	MoveItemInInventory {
-		dest_inv_type: -1,
+		dest_inv_type: InventoryType::Invalid,
 		obj_id: 1152921510152901383,
 		inventory_type: InventoryType::Buyback,
-		response_code: 0,
+		response_code: MoveItemResponseCode::Success,
 		slot: 0,
 	},
 )
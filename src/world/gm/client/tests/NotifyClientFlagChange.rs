@@ -1,6 +1,6 @@
 GameMessage::NotifyClientFlagChange(
 	NotifyClientFlagChange {
 		flag: true,
-		flag_id: 121,
+		flag_id: FlagId(121),
 	},
 )
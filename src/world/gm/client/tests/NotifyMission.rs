@@ -1,6 +1,6 @@
 GameMessage::NotifyMission(
 	NotifyMission {
-		mission_id: 1846,
+		mission_id: MissionId(1846),
 		mission_state: MissionState::Unavailable,
 		sending_rewards: true,
 	},
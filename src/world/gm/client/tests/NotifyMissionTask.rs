@@ -0,0 +1,7 @@
+GameMessage::NotifyMissionTask(
+	NotifyMissionTask {
+		mission_id: MissionId(173),
+		task_mask: TaskMask(2),
+		updates: vec![1.0].into(),
+	},
+)
@@ -1,6 +1,6 @@
 GameMessage::OfferMission(
 	OfferMission {
-		mission_id: 1837,
+		mission_id: MissionId(1837),
 		offerer: 288300744895889639,
 	},
 )
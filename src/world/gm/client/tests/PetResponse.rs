@@ -1,7 +1,7 @@
 GameMessage::PetResponse(
 	PetResponse {
 		obj_id_pet: 288300744895892635,
-		pet_command_type: 0,
+		pet_command_type: PetCommandType::Unknown(0),
 		response: 10,
 		type_id: 0,
 	},
@@ -6,7 +6,7 @@ GameMessage::PlaceModelResponse(
 			z: -45.600002,
 		},
 		property_plaque_id: 288300744895895428,
-		response: 14,
+		response: PlaceModelResponseCode::Success,
 		rotation: Quaternion {
 			x: 0.70710677,
 			y: 0.0,
@@ -3,7 +3,7 @@ GameMessage::PlatformResync(
 		reverse: false,
 		stop_at_desired_waypoint: false,
 		command: 0,
-		state: 28,
+		state: PlatformState(28),
 		unexpected_command: 0,
 		idle_time_elapsed: 0.0,
 		move_time_elapsed: 0.0,
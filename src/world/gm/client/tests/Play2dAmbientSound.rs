@@ -0,0 +1,6 @@
+GameMessage::Play2dAmbientSound(
+	Play2dAmbientSound {
+		sound_guid: SoundGuid(lu!(b"{e2a1d4e5-6f8a-4c3b-9d2e-1f0a7b6c5d4e}")),
+		result: false,
+	},
+)
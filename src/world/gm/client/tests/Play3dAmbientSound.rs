@@ -0,0 +1,6 @@
+GameMessage::Play3dAmbientSound(
+	Play3dAmbientSound {
+		sound_guid: SoundGuid(lu!(b"{5e1f0b7c-2d4a-4f6e-8a3b-9c0d1e2f3a4b}")),
+		result: true,
+	},
+)
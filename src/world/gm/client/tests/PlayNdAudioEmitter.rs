@@ -0,0 +1,10 @@
+GameMessage::PlayNdAudioEmitter(
+	PlayNdAudioEmitter {
+		callback_message_data: 0,
+		emitter_id: 0,
+		event_guid: SoundGuid(lu!(b"{9d5a4b3b-7b33-4ca9-8b5d-2b77e6b9e1c2}")),
+		meta_event_name: SoundEventName(lu!(b"")),
+		result: false,
+		target_object_id_for_nd_audio_callback_messages: 0,
+	},
+)
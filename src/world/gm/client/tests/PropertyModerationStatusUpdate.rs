@@ -1,6 +1,6 @@
 GameMessage::PropertyModerationStatusUpdate(
 	PropertyModerationStatusUpdate {
-		new_moderation_status: 0,
+		new_moderation_status: PropertyModerationStatus::Pending,
 		rejection_reason: lu!(""),
 	},
 )
@@ -0,0 +1,8 @@
+GameMessage::RemoveBuff(
+	RemoveBuff {
+		from_remove_behavior: false,
+		from_unequip: false,
+		remove_immunity: false,
+		buff_id: 60,
+	},
+)
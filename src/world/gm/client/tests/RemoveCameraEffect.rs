@@ -0,0 +1,5 @@
+GameMessage::RemoveCameraEffect(
+	RemoveCameraEffect {
+		effect_id: lu!("explosion_shake"),
+	},
+)
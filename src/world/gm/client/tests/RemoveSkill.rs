@@ -1,6 +1,6 @@
 GameMessage::RemoveSkill(
 	RemoveSkill {
 		from_skill_set: false,
-		skill_id: 1140,
+		skill_id: SkillId(1140),
 	},
 )
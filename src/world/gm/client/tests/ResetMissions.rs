@@ -1,5 +1,5 @@
 GameMessage::ResetMissions(
 	ResetMissions {
-		mission_id: 1348,
+		mission_id: MissionId(1348),
 	},
 )
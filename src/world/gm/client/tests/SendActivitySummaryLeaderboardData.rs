@@ -1,7 +1,7 @@
 GameMessage::SendActivitySummaryLeaderboardData(
 	SendActivitySummaryLeaderboardData {
-		game_id: 5,
-		info_type: 2,
+		game_id: ActivityId(5),
+		info_type: LeaderboardInfoType::TopSocial,
 		leaderboard_data: lnv! {
 			"ADO.Result": true,
 			"Result.Count": 1i32,
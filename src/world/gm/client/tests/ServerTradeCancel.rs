@@ -0,0 +1 @@
+GameMessage::ServerTradeCancel
@@ -0,0 +1,19 @@
+GameMessage::ServerTradeUpdate(
+	ServerTradeUpdate {
+		about_to_perform: false,
+		currency: 100,
+		items: vec![
+			TradeItem {
+				item_id: 1152921507969871364,
+				subkey: 1152921507969871364,
+				lot: 1727,
+				bound: false,
+				count: Some(1),
+				slot: None,
+				inv_type: None,
+				extra_info: None,
+				force_deletion: false,
+			},
+		].into(),
+	},
+)
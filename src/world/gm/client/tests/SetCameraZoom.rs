@@ -0,0 +1,7 @@
+GameMessage::SetCameraZoom(
+	SetCameraZoom {
+		distance: 12.0,
+		transition_time: 0.5,
+		allow_player_zoom: false,
+	},
+)
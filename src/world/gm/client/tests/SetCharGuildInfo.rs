@@ -0,0 +1,6 @@
+GameMessage::SetCharGuildInfo(
+	SetCharGuildInfo {
+		guild_id: GuildId(1152921510794154800),
+		guild_name: lu!("Nexus Knights"),
+	},
+)
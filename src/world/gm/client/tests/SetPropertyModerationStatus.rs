@@ -1,5 +1,5 @@
 GameMessage::SetPropertyModerationStatus(
 	SetPropertyModerationStatus {
-		moderation_status: 0,
+		moderation_status: PropertyModerationStatus::Pending,
 	},
 )
@@ -10,7 +10,7 @@ GameMessage::StartArrangingWithItem(
 		source_bag: InventoryType::Default,
 		source_id: 0,
 		source_lot: 4294967295,
-		source_type: 8,
+		source_type: ArrangeItemType::Unknown(8),
 		target_id: 0,
 		target_lot: 4294967295,
 		target_pos: Vector3 {
@@ -18,6 +18,6 @@ GameMessage::StartArrangingWithItem(
 			y: 0.0,
 			z: 0.0,
 		},
-		target_type: 0,
+		target_type: ArrangeItemType::None,
 	},
 )
@@ -0,0 +1,11 @@
+GameMessage::StartModularBuild(
+	StartModularBuild {
+		build_area_id: 70368744178052,
+		build_start_pos: Vector3 {
+			x: -3.4758658,
+			y: 291.0479,
+			z: 218.20381,
+		},
+		template_id: 6416,
+	},
+)
@@ -0,0 +1,6 @@
+GameMessage::Stop3dAmbientSound(
+	Stop3dAmbientSound {
+		sound_guid: SoundGuid(lu!(b"{5e1f0b7c-2d4a-4f6e-8a3b-9c0d1e2f3a4b}")),
+		result: false,
+	},
+)
@@ -0,0 +1,8 @@
+GameMessage::StopNdAudioEmitter(
+	StopNdAudioEmitter {
+		allow_fadeout: true,
+		emitter_id: 0,
+		event_guid: SoundGuid(lu!(b"{9d5a4b3b-7b33-4ca9-8b5d-2b77e6b9e1c2}")),
+		meta_event_name: SoundEventName(lu!(b"")),
+	},
+)
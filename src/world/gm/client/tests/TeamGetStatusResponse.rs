@@ -6,10 +6,18 @@ GameMessage::TeamGetStatusResponse(
 			instance_id: 10886,
 			clone_id: 0,
 		},
-		team_buffer: vec![
-			83, 0, 104, 0, 97, 0, 115, 0, 116, 0, 97, 0, 70, 0, 97, 0, 110, 0, 116, 0, 97, 0, 115, 0, 116, 0, 105, 0, 99, 0, 0, 0, 114, 0, 83, 0, 112, 0, 104, 0, 105, 0, 110, 0, 120, 0, 0, 0, 110, 116, 77, 97, 120, 77, 83, 61, 34, 48, 46, 48, 48, 34, 32, 82, 101, 99, 219, 183, 219, 86, 0, 0, 0, 16, 108, 7, 17, 45, 0, 0, 0, 0,
-		],
-		loot_flag: 0,
+		team_buffer: TeamRoster(vec![
+			TeamMember {
+				name: lu!("ShastaFantastic"),
+				player_id: 1152921506064087003,
+				zone_id: ZoneId {
+					map_id: 1900,
+					instance_id: 11537,
+					clone_id: 0,
+				},
+			},
+		]),
+		loot_flag: TeamLootOption::FreeForAll,
 		num_of_other_players: 1,
 		leader_name: lu!("RockVonViper"),
 	},
@@ -10,7 +10,7 @@ GameMessage::TransferToZone(
 		rot_y: 0.0,
 		rot_z: 0.0,
 		spawn_point: lu!(""),
-		instance_type: 0,
+		instance_type: InstanceType::Public,
 		zone_id: 1250,
 	},
 )
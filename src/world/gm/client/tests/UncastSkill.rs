@@ -1,5 +1,5 @@
 GameMessage::UncastSkill(
 	UncastSkill {
-		skill_id: 362,
+		skill_id: SkillId(362),
 	},
 )
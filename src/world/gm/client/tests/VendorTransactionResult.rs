@@ -1,5 +1,5 @@
 GameMessage::VendorTransactionResult(
 	VendorTransactionResult {
-		result: 0,
+		result: VendorTransactionResultCode::SellSuccess,
 	},
 )
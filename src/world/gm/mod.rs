@@ -1,68 +1,316 @@
+/// Implements `GmParam` by forwarding to [`Deserialize`] and [`Serialize`]. Types marked `fixed` always have their in-memory size.
+macro_rules! gm_param {
+	($typ:ty, fixed) => {
+		gm_param!($typ, Some(::std::mem::size_of::<$typ>()));
+	};
+	($typ:ty) => {
+		gm_param!($typ, None);
+	};
+	($typ:ty, $fixed_size:expr) => {
+		impl crate::world::gm::GmParam for $typ {
+			const FIXED_SIZE: ::std::option::Option<usize> = $fixed_size;
+
+			fn deserialize<R: ::std::io::Read>(reader: &mut R) -> ::std::io::Result<Self> {
+				::endio::LERead::read(reader)
+			}
+
+			fn serialize<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+				::endio::LEWrite::write(writer, self)
+			}
+
+			fn size(&self) -> ::std::io::Result<usize> {
+				crate::common::ByteSize::byte_size(self)
+			}
+		}
+	};
+}
+
+/**
+	Defines an enum over the known values of an integer parameter, with an `Unknown` variant holding any other value.
+
+	Unlike a plain `#[repr]` enum, messages with values not listed here can still be read and written back unchanged. Reading always picks the named variant if there is one, so `Unknown` only holds values without one.
+*/
+macro_rules! gm_enum {
+	($(#[$meta:meta])* pub enum $name:ident: $repr:ty { $($(#[$var_meta:meta])* $var:ident = $val:literal,)* }) => {
+		$(#[$meta])*
+		#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+		pub enum $name {
+			$($(#[$var_meta])* $var,)*
+			/// A value without a named variant.
+			Unknown($repr),
+		}
+
+		impl From<$repr> for $name {
+			fn from(raw: $repr) -> Self {
+				match raw {
+					$($val => Self::$var,)*
+					x => Self::Unknown(x),
+				}
+			}
+		}
+
+		impl From<$name> for $repr {
+			fn from(x: $name) -> Self {
+				match x {
+					$($name::$var => $val,)*
+					$name::Unknown(x) => x,
+				}
+			}
+		}
+
+		impl<R: ::std::io::Read> ::endio::Deserialize<::endio::LE, R> for $name {
+			fn deserialize(reader: &mut R) -> ::std::io::Result<Self> {
+				::endio::LERead::read::<$repr>(reader).map(Self::from)
+			}
+		}
+
+		impl<'a, W: ::std::io::Write> ::endio::Serialize<::endio::LE, W> for &'a $name {
+			fn serialize(self, writer: &mut W) -> ::std::io::Result<()> {
+				::endio::LEWrite::write(writer, <$repr>::from(*self))
+			}
+		}
+
+		gm_param!($name, Some(::std::mem::size_of::<$repr>()));
+
+		#[cfg(feature = "arbitrary")]
+		impl<'a> arbitrary::Arbitrary<'a> for $name {
+			fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+				Ok(<$repr as arbitrary::Arbitrary>::arbitrary(u)?.into())
+			}
+		}
+
+		/// Reflected as the raw value.
+		#[cfg(feature = "reflect")]
+		impl crate::reflect::ReflectValue for $name {
+			fn to_value(&self) -> crate::reflect::Value {
+				crate::reflect::ReflectValue::to_value(&<$repr>::from(*self))
+			}
+
+			fn from_value(value: crate::reflect::Value) -> Result<Self, crate::reflect::Value> {
+				<$repr as crate::reflect::ReflectValue>::from_value(value).map(Self::from)
+			}
+		}
+
+		#[cfg(feature = "schema")]
+		impl crate::schema::Schema for $name {
+			fn schema() -> crate::schema::Type {
+				<$repr as crate::schema::Schema>::schema()
+			}
+		}
+	};
+}
+
 pub mod client;
 pub mod server;
 
-use std::io::{Read, Write};
+use std::io::{Chain, Error, ErrorKind::UnexpectedEof, Read, Write};
 use std::io::Result as Res;
 
-use endio::{Deserialize, LERead, LEWrite, Serialize};
-use lu_packets_derive::{GameMessage, GmParam};
+use endio::{Deserialize, LE, LERead, LEWrite, Serialize};
+use endio_bit::{BEBitReader, BEBitWriter};
+use lu_packets_derive::{Arbitrary, GameMessage, GmParam, Reflect, Schema};
 
-use crate::common::{LuVarString, LuVarWString, ObjId, OBJID_EMPTY};
-use crate::world::{LuNameValue, MapId, MAP_ID_INVALID};
+use crate::common::{GuildId, LuVarString, LuVarWString, LVec, NetworkVersion, ObjId, OBJID_EMPTY, read_bit, Versioned};
+use crate::world::{LuNameValue, MapId, MAP_ID_INVALID, Vector3};
 use super::{Lot, LOT_NULL};
 
 type GmString = LuVarString<u32>;
 type GmWString = LuVarWString<u32>;
 
 pub(super) trait GmParam: Sized {
+	/// Number of bytes written by `serialize` if it's the same for all values.
+	const FIXED_SIZE: Option<usize> = None;
+
 	fn deserialize<R: Read>(reader: &mut R) -> Res<Self>;
 	fn serialize<W: Write>(&self, writer: &mut W) -> Res<()>;
+	/// Number of bytes written by `serialize`.
+	fn size(&self) -> Res<usize>;
 }
 
-/// Implements `GmParam` by forwarding to [`Deserialize`] and [`Serialize`].
-macro_rules! gm_param {
-	($typ:ty) => {
-		impl crate::world::gm::GmParam for $typ {
-			fn deserialize<R: ::std::io::Read>(reader: &mut R) -> ::std::io::Result<Self> {
-				::endio::LERead::read(reader)
-			}
+gm_param!(u8, fixed);
+gm_param!(u16, fixed);
+gm_param!(u32, fixed);
+gm_param!(u64, fixed);
+gm_param!(i32, fixed);
+gm_param!(i64, fixed);
+gm_param!(f32, fixed);
+gm_param!(GmString);
+gm_param!(GmWString);
+gm_param!(LVec<u8, f32>);
+gm_param!(LVec<u8, Lot>);
+gm_param!(LVec<u32, u8>);
+gm_param!(GuildId, fixed);
 
-			fn serialize<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
-				::endio::LEWrite::write(writer, self)
-			}
+/// GUID of a sound event, like `{a1b2c3d4-0000-4000-8000-000000000000}`, which the client looks up in its audio banks.
+#[derive(Arbitrary, Debug, Deserialize, GmParam, PartialEq, Reflect, Schema, Serialize)]
+pub struct SoundGuid(pub GmString);
+
+/// Name of a sound event or music cue, like `Ninjago_Monastery_Boss`, for sounds that are looked up by name instead of by [`SoundGuid`].
+#[derive(Arbitrary, Debug, Deserialize, GmParam, PartialEq, Reflect, Schema, Serialize)]
+pub struct SoundEventName(pub GmString);
+
+/**
+	Borrowed view of a subject game message, used by [`MessageRef`](crate::unified::MessageRef).
+
+	Game message fields are packed at the bit level, so they can't be borrowed one by one. Instead the message is kept undecoded, to be forwarded as is or decoded on demand.
+
+	Only blobs that start on a byte boundary can be borrowed, see [`lxfml_data_compressed`](Self::lxfml_data_compressed). Blobs after a bool or a field with a default, like [`server::StartSkill::bitstream`] and [`server::SyncSkill::bitstream`], are shifted by the flag bits before them, so they're only available by decoding the message.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GameMessageRef<'a> {
+	data: &'a [u8],
+	version: u32,
+}
+
+impl<'a> GameMessageRef<'a> {
+	/// Takes the rest of `data`, which has to contain at least the subject and message ID.
+	pub fn parse(data: &mut &'a [u8]) -> Res<Self> {
+		Self::parse_versioned(data, crate::common::NETWORK_VERSION_LIVE)
+	}
+
+	/// Like [`parse`](Self::parse), for a message from a client with the given network version.
+	pub fn parse_versioned(data: &mut &'a [u8], version: u32) -> Res<Self> {
+		if data.len() < 10 {
+			return Err(Error::new(UnexpectedEof, "game message too short"));
 		}
-	};
+		Ok(Self { data: std::mem::take(data), version })
+	}
+
+	/// The encoded message, for forwarding it without decoding.
+	pub fn as_bytes(&self) -> &'a [u8] {
+		self.data
+	}
+
+	pub fn subject_id(&self) -> ObjId {
+		LERead::read(&mut &self.data[..]).unwrap()
+	}
+
+	/// The live ID of the message, translated from the ID used by the network version the message was parsed with.
+	pub fn message_id(&self) -> u16 {
+		live_id(GM_ID_CHANGES, u16::from_le_bytes([self.data[8], self.data[9]]), self.version)
+	}
+
+	/**
+		The compressed LXFML of a [`server::SetBbbAutosave`] or [`server::BbbSaveRequest`], borrowed from the message.
+
+		Returns `None` for other messages.
+	*/
+	pub fn lxfml_data_compressed(&self) -> Res<Option<&'a [u8]>> {
+		let id = self.message_id();
+		let offset = if id == server::GameMessage::SetBbbAutosave(server::SetBbbAutosave { lxfml_data_compressed: LVec::new() }).message_id() {
+			server::SetBbbAutosave::byte_offset("lxfml_data_compressed")
+		} else if id == server::GameMessage::BbbSaveRequest(server::BbbSaveRequest { local_id: OBJID_EMPTY, lxfml_data_compressed: LVec::new(), time_taken_in_ms: 0 }).message_id() {
+			server::BbbSaveRequest::byte_offset("lxfml_data_compressed")
+		} else {
+			return Ok(None);
+		};
+		// subject and message ID
+		let offset = 10 + offset.expect("LXFML fields are byte-aligned");
+		let mut data = self.data.get(offset..).unwrap_or(&[]);
+		let len: u32 = LERead::read(&mut data)?;
+		crate::common::take(&mut data, len as usize).map(Some)
+	}
+
+	/// Decodes the message, into [`server::SubjectGameMessage`] or [`client::SubjectGameMessage`] depending on the direction.
+	pub fn into_owned<T>(self) -> Res<T>
+	where
+		for<'b> T: Deserialize<LE, Versioned<&'b [u8]>>,
+	{
+		LERead::read(&mut Versioned::new(self.data, self.version))
+	}
 }
 
-gm_param!(u8);
-gm_param!(u16);
-gm_param!(u32);
-gm_param!(u64);
-gm_param!(i32);
-gm_param!(i64);
-gm_param!(f32);
-gm_param!(GmString);
-gm_param!(GmWString);
+/**
+	Game message IDs that changed before the live client, as `(version, old_id, live_id)`: clients with a network version below `version` use `old_id` for the message that has `live_id` in the live client.
+
+	The message enums use the live IDs. [`client::SubjectGameMessage`] and [`server::SubjectGameMessage`] translate IDs with this table when reading from or writing to a [`Versioned`] reader or writer. Changes are only listed here once they're confirmed from captures of the earlier client.
+*/
+const GM_ID_CHANGES: &[(u32, u16, u16)] = &[];
+
+/// Translates a message ID as used by `version` to the live ID.
+fn live_id(changes: &[(u32, u16, u16)], id: u16, version: u32) -> u16 {
+	changes.iter().find(|(since, old, _)| version < *since && *old == id).map_or(id, |(_, _, live)| *live)
+}
+
+/// Translates a live message ID to the one used by `version`.
+fn versioned_id(changes: &[(u32, u16, u16)], id: u16, version: u32) -> u16 {
+	changes.iter().find(|(since, _, live)| version < *since && *live == id).map_or(id, |(_, old, _)| *old)
+}
+
+/// Reads a subject and message, translating the message ID for the reader's network version.
+fn read_subject_message<R: Read, M>(reader: &mut R) -> Res<(ObjId, M)>
+where
+	for<'a> M: Deserialize<LE, Versioned<Chain<&'a [u8], &'a mut R>>>,
+{
+	let version = NetworkVersion::network_version(&*reader);
+	let subject_id = LERead::read(reader)?;
+	let id: u16 = LERead::read(reader)?;
+	let id = live_id(GM_ID_CHANGES, id, version).to_le_bytes();
+	let message = LERead::read(&mut Versioned::new(Read::chain(&id[..], reader), version))?;
+	Ok((subject_id, message))
+}
+
+/**
+	Message enums whose discriminant is the message ID.
+
+	# Safety
 
-impl GmParam for Vec<u8> {
-	fn deserialize<R: Read>(reader: &mut R) -> Res<Self> {
-		let str_len: u32 = LERead::read(reader)?;
-		let str_len = str_len as usize;
-		let mut vec = Vec::with_capacity(str_len);
-		unsafe {
-			vec.set_len(str_len);
+	Only implement this for `#[repr(u16)]` enums. Enums with a primitive representation start with their discriminant, which is what [`message_id`](Self::message_id) reads.
+*/
+unsafe trait MessageId: Sized {
+	fn message_id(&self) -> u16 {
+		unsafe { *(self as *const Self as *const u16) }
+	}
+}
+
+unsafe impl MessageId for client::GameMessage {}
+unsafe impl MessageId for server::GameMessage {}
+
+/// Writes a subject and message, translating the message ID for the writer's network version.
+fn write_subject_message<W: Write, M: MessageId>(writer: &mut W, subject_id: ObjId, message: &M) -> Res<()>
+where
+	for<'a, 'b> &'a M: Serialize<LE, W> + Serialize<LE, Versioned<SkipPrefix<&'b mut W>>>,
+{
+	write_subject_message_with::<W, M>(GM_ID_CHANGES, writer, subject_id, message)
+}
+
+fn write_subject_message_with<W: Write, M: MessageId>(changes: &[(u32, u16, u16)], writer: &mut W, subject_id: ObjId, message: &M) -> Res<()>
+where
+	for<'a, 'b> &'a M: Serialize<LE, W> + Serialize<LE, Versioned<SkipPrefix<&'b mut W>>>,
+{
+	let version = NetworkVersion::network_version(&*writer);
+	let live = message.message_id();
+	let id = versioned_id(changes, live, version);
+	LEWrite::write(writer, subject_id)?;
+	if id == live {
+		return LEWrite::write(writer, message);
+	}
+	LEWrite::write(writer, id)?;
+	LEWrite::write(&mut Versioned::new(SkipPrefix { inner: writer, skip: 2 }, version), message)
+}
+
+/// Drops the first `skip` bytes written to it, used to replace the message ID with a translated one.
+struct SkipPrefix<W> {
+	inner: W,
+	skip: usize,
+}
+
+impl<W: Write> Write for SkipPrefix<W> {
+	fn write(&mut self, buf: &[u8]) -> Res<usize> {
+		let skipped = self.skip.min(buf.len());
+		self.skip -= skipped;
+		if skipped == buf.len() {
+			return Ok(skipped);
 		}
-		Read::read(reader, &mut vec)?;
-		Ok(vec)
+		Ok(skipped + self.inner.write(&buf[skipped..])?)
 	}
 
-	fn serialize<W: Write>(&self, writer: &mut W) -> Res<()> {
-		LEWrite::write(writer, self.len() as u32)?;
-		Write::write_all(writer, self)
+	fn flush(&mut self) -> Res<()> {
+		self.inner.flush()
 	}
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum InventoryType {
 	Default,
@@ -82,16 +330,120 @@ pub enum InventoryType {
 	BankModel,
 	BankBehavior,
 	All,
+	/// `-1` on the wire, used where no inventory is given.
+	Invalid = 0xffff_ffff,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+/// Rank of a guild member, see [`client::GuildSetPlayerRank`].
+#[derive(Arbitrary, Clone, Copy, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
+#[repr(u32)]
+pub enum GuildRank {
+	Member,
+	Officer,
+	Leader,
+}
+
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum KillType {
 	Violent,
 	Silent,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+/// ID of a mission or achievement, from the `Missions` table.
+#[derive(Arbitrary, Clone, Copy, Debug, Deserialize, Eq, GmParam, Hash, PartialEq, Reflect, Schema, Serialize)]
+pub struct MissionId(pub i32);
+
+/**
+	ID of a player flag, as set with [`server::SetFlag`] and reported with [`client::NotifyClientFlagChange`].
+
+	Flags track one-off progress like tutorials and collectibles. Joining a faction sets one of the faction flags.
+*/
+#[derive(Arbitrary, Clone, Copy, Debug, Deserialize, Eq, GmParam, Hash, PartialEq, Reflect, Schema, Serialize)]
+pub struct FlagId(pub i32);
+
+impl FlagId {
+	pub const VENTURE_FACTION: Self = Self(46);
+	pub const ASSEMBLY_FACTION: Self = Self(47);
+	pub const PARADOX_FACTION: Self = Self(48);
+	pub const SENTINEL_FACTION: Self = Self(49);
+
+	/// Whether this is one of the faction flags, which a player sets to join that faction.
+	pub fn is_faction(self) -> bool {
+		(Self::VENTURE_FACTION.0..=Self::SENTINEL_FACTION.0).contains(&self.0)
+	}
+}
+
+/// ID of an activity, like a minigame or race, from the `Activities` table.
+#[derive(Arbitrary, Clone, Copy, Debug, Deserialize, Eq, GmParam, Hash, PartialEq, Reflect, Schema, Serialize)]
+pub struct ActivityId(pub i32);
+
+/// ID of a skill, from the `SkillBehavior` table.
+#[derive(Arbitrary, Clone, Copy, Debug, Deserialize, Eq, GmParam, Hash, PartialEq, Reflect, Schema, Serialize)]
+pub struct SkillId(pub u32);
+
+/// ID of a behavior, from the `BehaviorTemplate` table. A skill starts at its root behavior, which runs further behaviors.
+#[derive(Arbitrary, Clone, Copy, Debug, Deserialize, Eq, GmParam, Hash, PartialEq, Reflect, Schema, Serialize)]
+pub struct BehaviorId(pub u32);
+
+gm_enum! {
+	/// How a skill was cast, see [`server::StartSkill`].
+	pub enum CastType: u32 {
+		None = 0,
+		Consumable = 1,
+		EverlastingConsumable = 2,
+	}
+}
+
+gm_enum! {
+	/// Command sent to a pet, see [`client::PetResponse`].
+	pub enum PetCommandType: i32 {
+		/// Plays the emote in the command's `type_id`.
+		Emote = 1,
+		FollowMe = 3,
+		GoHere = 6,
+	}
+}
+
+gm_enum! {
+	/// Moderation status of a property.
+	pub enum PropertyModerationStatus: i32 {
+		Unset = -1,
+		Pending = 0,
+		Approved = 1,
+		Rejected = 2,
+	}
+}
+
+gm_enum! {
+	/// Type of an item being arranged in build mode, see [`client::StartArrangingWithItem`].
+	pub enum ArrangeItemType: i32 {
+		/// No item, like the target of a new arrangement.
+		None = 0,
+		Model = 1,
+	}
+}
+
+gm_enum! {
+	/// Build mode to switch to, see [`client::SetBuildModeConfirmed`].
+	pub enum BuildModeValue: i32 {
+		Normal = 1,
+	}
+}
+
+gm_enum! {
+	/// Slot a skill is added to, see [`client::AddSkill`].
+	pub enum BehaviorSlot: i32 {
+		Invalid = -1,
+		Primary = 0,
+		Offhand = 1,
+		Neck = 2,
+		Head = 3,
+		Consumable = 4,
+	}
+}
+
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum MissionState {
 	Unavailable = 0,
@@ -106,7 +458,7 @@ pub enum MissionState {
 	ReadyToCompleteReported = 32,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum PetNotificationType {
 	OwnerDied = 1,
@@ -126,14 +478,66 @@ pub enum PetNotificationType {
 	OwnerOffDig,
 }
 
+/**
+	Kills or smashes an object.
+
+	The server sends this for objects it kills. The client sends it with [`client_death`](Self::client_death) set for objects that only exist client-side, like client-side smashables, to ask the server to spawn their loot if [`spawn_loot`](Self::spawn_loot) is set. The server drops the loot with [`DropClientLoot`] to the [`loot_owner_id`](Self::loot_owner_id).
+*/
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct Die {
+	#[default(false)]
+	pub client_death: bool,
+	#[default(true)]
+	pub spawn_loot: bool,
+	pub death_type: GmWString,
+	pub direction_relative_angle_xz: f32,
+	pub direction_relative_angle_y: f32,
+	pub direction_relative_force: f32,
+	#[default(KillType::Violent)]
+	pub kill_type: KillType,
+	pub killer_id: ObjId,
+	#[default(OBJID_EMPTY)]
+	pub loot_owner_id: ObjId,
+}
+
+/**
+	Spawns a loot drop, like the coins and items dropped when something is smashed.
+
+	The server sends this to the client that may pick up the loot, which then sends [`PickupItem`](server::PickupItem) or [`PickupCurrency`](server::PickupCurrency). The client sends it to the server for loot dropped by objects that only exist client-side, like client-side smashables, so that the server can track the drop.
+*/
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct DropClientLoot {
+	#[default(false)]
+	pub use_position: bool,
+	#[default(Vector3::ZERO)]
+	pub final_position: Vector3,
+	pub currency: i32, // todo: unsigned?
+	pub item_template: Lot,
+	pub loot_id: ObjId,
+	pub owner: ObjId,
+	pub source_obj: ObjId,
+	#[default(Vector3::ZERO)]
+	pub spawn_position: Vector3,
+}
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct RemoveSkill {
 	#[default(false)]
 	pub from_skill_set: bool,
-	pub skill_id: u32,
+	pub skill_id: SkillId,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+/// How loot is shared in a team, see [`client::TeamGetStatusResponse::loot_flag`] and [`TeamSetLoot`](crate::chat::server::TeamSetLoot).
+#[derive(Arbitrary, Clone, Copy, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
+#[repr(u8)]
+pub enum TeamLootOption {
+	/// Loot goes to whoever picks it up.
+	FreeForAll,
+	/// Loot is given to the team members in turn.
+	Shared,
+}
+
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum LootType {
 	None,
@@ -219,16 +623,25 @@ pub struct UnEquipInventory {
 	pub replacement_object_id: ObjId,
 }
 
-const INVENTORY_INVALID: i32 = -1;
+gm_enum! {
+	/// Response code of [`MoveItemInInventory`], numbered like the first codes of [`client::ResponseMoveItemResponseCode`].
+	pub enum MoveItemResponseCode: i32 {
+		Success = 0,
+		FailGeneric = 1,
+		FailInvFull = 2,
+		FailItemNotFound = 3,
+		FailCantMoveToThatInvType = 4,
+	}
+}
 
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct MoveItemInInventory {
-	#[default(INVENTORY_INVALID)]
-	pub dest_inv_type: i32, // todo: type
+	#[default(InventoryType::Invalid)]
+	pub dest_inv_type: InventoryType,
 	pub obj_id: ObjId,
 	pub inventory_type: InventoryType,
-	pub response_code: i32, // todo: type
-	pub slot: i32,          // todo: unsigned?
+	pub response_code: MoveItemResponseCode,
+	pub slot: i32, // todo: unsigned?
 }
 
 #[derive(Debug, GameMessage, PartialEq)]
@@ -263,3 +676,389 @@ pub struct ModifyPlayerZoneStatistic {
 	#[default(MAP_ID_INVALID)]
 	pub zone_id: MapId,
 }
+
+/**
+	Removes a buff, see [`BuffInfo`](crate::raknet::client::replica::buff::BuffInfo).
+
+	Sent by the server when a buff ends, and by the client when the player cancels a buff that has [`cancel_on_ui`](crate::raknet::client::replica::buff::BuffInfo::cancel_on_ui) set.
+*/
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct RemoveBuff {
+	pub from_remove_behavior: bool,
+	pub from_unequip: bool,
+	pub remove_immunity: bool,
+	pub buff_id: u32,
+}
+
+/**
+	Item offered in a trade, see [`client::ServerTradeUpdate`] and [`server::ClientTradeUpdate`].
+
+	The optional fields are preceded by a flag bit, like defaulted game message fields.
+*/
+#[derive(Arbitrary, Debug, PartialEq, Reflect)]
+pub struct TradeItem {
+	pub item_id: ObjId,
+	pub subkey: ObjId,
+	pub lot: Lot,
+	pub bound: bool,
+	pub count: Option<u32>,
+	pub slot: Option<u16>,
+	pub inv_type: Option<InventoryType>,
+	pub extra_info: Option<LuNameValue>,
+	pub force_deletion: bool,
+}
+
+impl<R: Read> Deserialize<LE, BEBitReader<R>> for TradeItem {
+	fn deserialize(reader: &mut BEBitReader<R>) -> Res<Self> {
+		let item_id = LERead::read(reader)?;
+		let subkey = LERead::read(reader)?;
+		let lot = LERead::read(reader)?;
+		let bound = read_bit(reader)?;
+		let count = if read_bit(reader)? { Some(LERead::read(reader)?) } else { None };
+		let slot = if read_bit(reader)? { Some(LERead::read(reader)?) } else { None };
+		let inv_type = if read_bit(reader)? { Some(LERead::read(reader)?) } else { None };
+		let extra_info = if read_bit(reader)? { Some(GmParam::deserialize(reader)?) } else { None };
+		let force_deletion = read_bit(reader)?;
+		Ok(Self { item_id, subkey, lot, bound, count, slot, inv_type, extra_info, force_deletion })
+	}
+}
+
+impl<'a, W: Write> Serialize<LE, BEBitWriter<W>> for &'a TradeItem {
+	fn serialize(self, writer: &mut BEBitWriter<W>) -> Res<()> {
+		LEWrite::write(writer, self.item_id)?;
+		LEWrite::write(writer, self.subkey)?;
+		LEWrite::write(writer, self.lot)?;
+		writer.write_bit(self.bound)?;
+		writer.write_bit(self.count.is_some())?;
+		if let Some(x) = self.count {
+			LEWrite::write(writer, x)?;
+		}
+		writer.write_bit(self.slot.is_some())?;
+		if let Some(x) = self.slot {
+			LEWrite::write(writer, x)?;
+		}
+		writer.write_bit(self.inv_type.is_some())?;
+		if let Some(x) = &self.inv_type {
+			LEWrite::write(writer, x)?;
+		}
+		writer.write_bit(self.extra_info.is_some())?;
+		if let Some(x) = &self.extra_info {
+			GmParam::serialize(x, writer)?;
+		}
+		writer.write_bit(self.force_deletion)?;
+		Ok(())
+	}
+}
+
+/// Schema of [`TradeItem`] and the messages containing it. The items don't start at a byte boundary, so the messages are read at bit level throughout.
+#[cfg(feature = "schema")]
+pub(crate) fn trade_schema(id: &'static str, name: &'static str, fields: Vec<(&'static str, crate::schema::Type, bool)>) -> crate::schema::Type {
+	use crate::schema::{Field, Struct, Type};
+
+	Type::Struct(Struct {
+		id,
+		name,
+		fields: fields.into_iter().map(|(name, ty, default)| Field { name, ty, padding: 0, default }).collect(),
+		trailing_padding: 0,
+		game_message: true,
+		replica: true,
+	})
+}
+
+#[cfg(feature = "schema")]
+impl crate::schema::Schema for TradeItem {
+	fn schema() -> crate::schema::Type {
+		trade_schema(std::any::type_name::<Self>(), "TradeItem", vec![
+			("item_id", ObjId::schema(), false),
+			("subkey", ObjId::schema(), false),
+			("lot", Lot::schema(), false),
+			("bound", bool::schema(), false),
+			("count", u32::schema(), true),
+			("slot", u16::schema(), true),
+			("inv_type", InventoryType::schema(), true),
+			("extra_info", LuNameValue::schema(), true),
+			("force_deletion", bool::schema(), false),
+		])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn builder() {
+		let built = RemoveItemFromInventory::builder().extra_info(LuNameValue::new()).obj_id(42).stack_count(3).build();
+		let explicit = RemoveItemFromInventory {
+			confirmed: false,
+			delete_item: true,
+			out_success: false,
+			inv_type: InventoryType::All,
+			loot_type_source: LootType::None,
+			extra_info: LuNameValue::new(),
+			force_deletion: true,
+			loot_type_source_id: OBJID_EMPTY,
+			obj_id: 42,
+			obj_template: LOT_NULL,
+			requesting_obj_id: OBJID_EMPTY,
+			stack_count: 3,
+			stack_remaining: 0,
+			subkey: OBJID_EMPTY,
+			trade_id: OBJID_EMPTY,
+		};
+		assert_eq!(built, explicit);
+	}
+
+	#[test]
+	fn default() {
+		assert_eq!(SetIgnoreProjectileCollision::default(), SetIgnoreProjectileCollision { should_ignore: false });
+	}
+
+	#[test]
+	fn shapes() {
+		#[derive(Debug, GameMessage, PartialEq)]
+		struct Unit;
+
+		#[derive(Debug, GameMessage, PartialEq)]
+		struct Tuple(u32, bool);
+
+		#[derive(Debug, GameMessage, PartialEq)]
+		#[repr(u8)]
+		enum Shaped {
+			Unit,
+			Tuple(bool, #[default(7)] u32),
+			Named { a: u32 },
+		}
+
+		macro_rules! round_trip {
+			($val:expr, $expected:expr) => {
+				let val = $val;
+				let mut data = vec![];
+				LEWrite::write(&mut data, &val).unwrap();
+				assert_eq!(data, $expected);
+				assert_eq!(crate::common::Encode::serialized_size(&val).unwrap(), data.len());
+				let mut reader = &data[..];
+				assert_eq!(LERead::read(&mut reader).ok(), Some(val));
+				assert!(reader.is_empty());
+			};
+		}
+
+		round_trip!(Unit, [0u8; 0]);
+		round_trip!(Tuple(3, true), [3, 0, 0, 0, 0x80]);
+		round_trip!(Shaped::Unit, [0]);
+		round_trip!(Shaped::Tuple(true, 7), [1, 0x80]);
+		round_trip!(Shaped::Named { a: 2 }, [2, 2, 0, 0, 0]);
+	}
+
+	#[test]
+	fn since() {
+		use crate::common::{Versioned, NETWORK_VERSION_LIVE};
+
+		#[derive(Debug, GameMessage, PartialEq)]
+		struct Gated {
+			a: u8,
+			#[since(171022)]
+			b: u8,
+			c: bool,
+			#[since(171022)]
+			d: bool,
+		}
+
+		fn write(gated: &Gated, version: u32) -> Vec<u8> {
+			let mut data = vec![];
+			LEWrite::write(&mut Versioned::new(&mut data, version), gated).unwrap();
+			data
+		}
+
+		fn read(data: &[u8], version: u32) -> Gated {
+			LERead::read(&mut Versioned::new(data, version)).unwrap()
+		}
+
+		let gated = Gated { a: 1, b: 2, c: true, d: true };
+		assert_eq!(write(&gated, NETWORK_VERSION_LIVE), [1, 2, 0xc0]);
+		assert_eq!(crate::common::Encode::serialized_size(&gated).unwrap(), 3);
+		assert_eq!(read(&[1, 2, 0xc0], NETWORK_VERSION_LIVE), gated);
+		assert_eq!(write(&gated, 171021), [1, 0x80]);
+		assert_eq!(read(&[1, 0x80], 171021), Gated { a: 1, b: 0, c: true, d: false });
+
+		#[derive(Debug, GameMessage, PartialEq)]
+		#[repr(u16)]
+		enum GatedMessage {
+			Old(u8) = 1,
+			#[since(171022)]
+			New(u8) = 2,
+		}
+
+		let mut data = vec![];
+		assert!(LEWrite::write(&mut Versioned::new(&mut data, 171021), &GatedMessage::New(3)).is_err());
+		assert!(data.is_empty());
+		LEWrite::write(&mut Versioned::new(&mut data, 171021), &GatedMessage::Old(3)).unwrap();
+		assert_eq!(data, [1, 0, 3]);
+		assert!(LERead::read::<GatedMessage>(&mut Versioned::new(&[2, 0, 3][..], 171021)).is_err());
+		assert_eq!(LERead::read::<GatedMessage>(&mut Versioned::new(&[2, 0, 3][..], NETWORK_VERSION_LIVE)).unwrap(), GatedMessage::New(3));
+	}
+
+	#[test]
+	fn id_changes() {
+		let changes = [(171022, 1000, 1001)];
+		assert_eq!(live_id(&changes, 1000, 171021), 1001);
+		assert_eq!(live_id(&changes, 1000, 171022), 1000);
+		assert_eq!(versioned_id(&changes, 1001, 171021), 1000);
+		assert_eq!(versioned_id(&changes, 1001, 171022), 1001);
+		assert_eq!(versioned_id(&changes, 1002, 171021), 1002);
+
+		let message = server::GameMessage::BbbSaveRequest(server::BbbSaveRequest { local_id: 7, lxfml_data_compressed: vec![1, 2, 3].into(), time_taken_in_ms: 1500 });
+		let mut live = vec![];
+		write_subject_message_with::<_, server::GameMessage>(&changes, &mut Versioned::new(&mut live, 171022), 42, &message).unwrap();
+		let mut old = vec![];
+		write_subject_message_with::<_, server::GameMessage>(&changes, &mut Versioned::new(&mut old, 171021), 42, &message).unwrap();
+		assert_eq!(&live[8..10], &1001u16.to_le_bytes());
+		assert_eq!(&old[8..10], &1000u16.to_le_bytes());
+		assert_eq!(old[..8], live[..8]);
+		assert_eq!(old[10..], live[10..]);
+	}
+
+	#[test]
+	fn task_mask() {
+		use super::client::TaskMask;
+
+		let mask = TaskMask::new([0, 2]);
+		assert_eq!(mask, TaskMask(0b1010));
+		assert!(mask.contains(2));
+		assert!(!mask.contains(1));
+		assert_eq!(mask.indexes().collect::<Vec<_>>(), [0, 2]);
+	}
+
+	#[test]
+	fn behavior_slot() {
+		for (slot, raw) in [(BehaviorSlot::Invalid, -1), (BehaviorSlot::Head, 3), (BehaviorSlot::Unknown(9), 9)] {
+			let mut data = vec![];
+			GmParam::serialize(&slot, &mut data).unwrap();
+			assert_eq!(data, i32::to_le_bytes(raw));
+			assert_eq!(<BehaviorSlot as GmParam>::deserialize(&mut &data[..]).unwrap(), slot);
+		}
+	}
+
+	#[test]
+	fn invalid_values() {
+		let mut data = vec![];
+		GmParam::serialize(&InventoryType::Invalid, &mut data).unwrap();
+		GmParam::serialize(&PropertyModerationStatus::Unset, &mut data).unwrap();
+		assert_eq!(data, [i32::to_le_bytes(-1), i32::to_le_bytes(-1)].concat());
+		assert_eq!(<PetCommandType as GmParam>::deserialize(&mut &i32::to_le_bytes(4)[..]).unwrap(), PetCommandType::Unknown(4));
+
+		use super::client::PlatformState;
+		let state = PlatformState(28);
+		assert!(state.contains(PlatformState::STOPPED));
+		assert!(state.contains(PlatformState::REACHED_FINAL_WAYPOINT));
+		assert!(!state.contains(PlatformState::WAITING));
+	}
+
+	#[test]
+	fn game_state() {
+		use super::client::UiMessageServerToSingleClient;
+
+		let pushed = UiMessageServerToSingleClient::push_game_state("Story");
+		assert_eq!(pushed, UiMessageServerToSingleClient { args: amf3! { "state": "Story" }, message_name: lu!(b"pushGameState") });
+		assert_eq!(UiMessageServerToSingleClient::pop_game_state("Story").message_name, lu!(b"popGameState"));
+	}
+
+	#[test]
+	fn camera() {
+		use super::client::{AddCameraEffect, CameraEffectType, UiMessageServerToAllClients, UiMessageServerToSingleClient};
+
+		let shake = AddCameraEffect::shake(lu!("explosion_shake"), 1.5, 0.75);
+		assert_eq!(shake, AddCameraEffect { effect_id: lu!("explosion_shake"), effect_type: CameraEffectType::Shake, duration: 1.5, config: amf3! { "amplitude": 0.75 } });
+		assert_eq!(AddCameraEffect::fov(lu!("zoom_in"), -1.0, 60.0).config, amf3! { "fov": 60.0 });
+
+		let single = UiMessageServerToSingleClient::flashing_text("Go!", true);
+		assert_eq!(single.args, amf3! { "text": "Go!", "visible": true });
+		let all = UiMessageServerToAllClients::flashing_text("Go!", true);
+		assert_eq!((all.args, all.message_name), (single.args, single.message_name));
+	}
+
+	#[test]
+	fn progression() {
+		use super::client::{NotifyClientFlagChange, StartCelebrationEffect};
+
+		assert!(FlagId::SENTINEL_FACTION.is_faction());
+		assert!(!FlagId(45).is_faction());
+		assert_eq!(NotifyClientFlagChange::faction_joined(FlagId::PARADOX_FACTION), NotifyClientFlagChange { flag: true, flag_id: FlagId(48) });
+		let celebration = StartCelebrationEffect::achievement(22, lu!("rank-up"), 7.5, 4181, lu!("Rank 2"), lu!("Venture League"));
+		assert_eq!(celebration.celebration_id, 22);
+		assert_eq!(celebration.background_object, 11164);
+		assert_eq!(celebration.music_cue, lu!(b""));
+	}
+
+	#[test]
+	fn rocket_launch() {
+		use super::client::FireEventClientSide;
+		use super::server::FireEventServerSide;
+
+		let equipped = FireEventClientSide::rocket_equipped(1152921510650291321, 338862, 1152921510109196992);
+		assert_eq!(equipped, FireEventClientSide { args: lu!("RocketEquipped"), object: 1152921510650291321, param1: 338862, param2: -1, sender_id: 1152921510109196992 });
+		assert_eq!(FireEventServerSide::zone_player(1152921510109196992).args, lu!("ZonePlayer"));
+	}
+
+	#[test]
+	fn loot() {
+		use super::client::{self, SetCurrency};
+		use super::server;
+
+		let coins = SetCurrency::looted(250, LootType::Pickup, 1152921510650291340, Vector3 { x: 1.0, y: 2.0, z: 3.0 });
+		assert_eq!(coins.source_type, LootType::Pickup);
+		assert_eq!(coins.loot_type, LootType::None);
+		assert_eq!(coins.source_lot, LOT_NULL);
+
+		// a client-side smash asks the server to spawn the loot with the same message the server kills with
+		let die = Die { client_death: true, spawn_loot: true, death_type: lu!(""), direction_relative_angle_xz: 0.0, direction_relative_angle_y: 0.0, direction_relative_force: 0.0, kill_type: KillType::Silent, killer_id: 1, loot_owner_id: 1 };
+		let mut data = vec![];
+		LEWrite::write(&mut data, &client::SubjectGameMessage { subject_id: 2, message: client::GameMessage::Die(die) }).unwrap();
+		let server::SubjectGameMessage { subject_id, message } = LERead::read(&mut &data[..]).unwrap();
+		assert_eq!(subject_id, 2);
+		assert!(matches!(message, server::GameMessage::Die(Die { client_death: true, spawn_loot: true, kill_type: KillType::Silent, .. })));
+	}
+
+	#[test]
+	fn borrowed_lxfml() {
+		use super::server::{BbbSaveRequest, GameMessage, SetBbbAutosave, SubjectGameMessage};
+
+		let lxfml: LVec<u32, u8> = vec![0x78, 0x9c, 1, 2, 3].into();
+		for message in [GameMessage::SetBbbAutosave(SetBbbAutosave { lxfml_data_compressed: lxfml.clone() }), GameMessage::BbbSaveRequest(BbbSaveRequest { local_id: 7, lxfml_data_compressed: lxfml.clone(), time_taken_in_ms: 1500 })] {
+			let mut data = vec![];
+			LEWrite::write(&mut data, &SubjectGameMessage { subject_id: 1, message }).unwrap();
+			let msg = GameMessageRef::parse(&mut &data[..]).unwrap();
+			assert_eq!(msg.lxfml_data_compressed().unwrap(), Some(&lxfml[..]));
+		}
+		let mut data = vec![];
+		LEWrite::write(&mut data, &SubjectGameMessage { subject_id: 1, message: GameMessage::RequestResurrect }).unwrap();
+		assert_eq!(GameMessageRef::parse(&mut &data[..]).unwrap().lxfml_data_compressed().unwrap(), None);
+
+		assert_eq!(BbbSaveRequest::byte_offset("lxfml_data_compressed"), Some(8));
+		// after the variable-size LXFML
+		assert_eq!(BbbSaveRequest::byte_offset("time_taken_in_ms"), None);
+		// after the `used_mouse` flag
+		assert_eq!(super::server::StartSkill::byte_offset("bitstream"), None);
+	}
+
+	#[test]
+	fn dispatch() {
+		use super::server::{self, GameMessage, GameMessageHandler, SubjectGameMessage};
+
+		#[derive(Default)]
+		struct Handler {
+			resurrected: Vec<ObjId>,
+		}
+
+		impl GameMessageHandler for Handler {
+			fn on_request_resurrect(&mut self, subject: ObjId) {
+				self.resurrected.push(subject);
+			}
+		}
+
+		let mut handler = Handler::default();
+		server::dispatch(&SubjectGameMessage { subject_id: 42, message: GameMessage::RequestResurrect }, &mut handler);
+		server::dispatch(&SubjectGameMessage { subject_id: 43, message: GameMessage::SetIgnoreProjectileCollision(SetIgnoreProjectileCollision::default()) }, &mut handler);
+		assert_eq!(handler.resurrected, vec![42]);
+	}
+}
@@ -1,24 +1,47 @@
 use std::cmp::PartialEq;
+use std::io::{Read, Result as Res, Write};
 
-use endio::{Deserialize, Serialize};
-use lu_packets_derive::{GameMessage, GmParam, VariantTests};
+use endio::{Deserialize, LE, LERead, LEWrite, Serialize};
+use lu_packets_derive::{Arbitrary, GameMessage, GmParam, MessageDeserialize, MessageHandler, Reflect, Schema, VariantTests};
 
-use crate::common::{ObjId, OBJID_EMPTY};
+use crate::common::{bit_reader, bit_writer, LVec, ObjId, OBJID_EMPTY};
 
 use crate::world::{Lot, LOT_NULL, Quaternion, Vector3};
 use crate::world::amf3::Amf3;
-pub use super::{EquipInventory, InventoryType, KillType, UnEquipInventory, MissionState, PetNotificationType, MoveItemInInventory, MoveInventoryBatch, RemoveItemFromInventory, SetIgnoreProjectileCollision, ModifyPlayerZoneStatistic};
+pub use super::{Die, DropClientLoot, EquipInventory, InventoryType, FlagId, GuildRank, TeamLootOption, KillType, UnEquipInventory, MissionId, MissionState, PetNotificationType, MoveItemInInventory, MoveItemResponseCode, MoveInventoryBatch, RemoveItemFromInventory, SetIgnoreProjectileCollision, ModifyPlayerZoneStatistic, RemoveBuff, ArrangeItemType, BuildModeValue, CastType, PetCommandType, PropertyModerationStatus, ActivityId, SkillId, TradeItem};
 use super::{GmString, GmWString};
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// The message ID is translated for the network version of the reader or writer, see [`Versioned`](crate::common::Versioned).
+#[derive(Arbitrary, Debug, PartialEq, Reflect, Schema)]
 pub struct SubjectGameMessage {
 	pub subject_id: ObjId,
 	pub message: GameMessage,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize, VariantTests)]
+impl<R: Read> Deserialize<LE, R> for SubjectGameMessage {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let (subject_id, message) = super::read_subject_message::<_, GameMessage>(reader)?;
+		Ok(Self { subject_id, message })
+	}
+}
+
+impl<'a, W: Write> Serialize<LE, W> for &'a SubjectGameMessage {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		super::write_subject_message::<_, GameMessage>(writer, self.subject_id, &self.message)
+	}
+}
+
+/// Calls the [`GameMessageHandler`] method corresponding to the game message, with the message's subject.
+pub fn dispatch(msg: &SubjectGameMessage, handler: &mut impl GameMessageHandler) {
+	msg.message.dispatch(msg.subject_id, handler)
+}
+
+#[derive(Arbitrary, Debug, MessageDeserialize, MessageHandler, PartialEq, Reflect, Schema, Serialize, VariantTests)]
+#[handler_args(subject: ObjId)]
 #[repr(u16)]
 pub enum GameMessage {
+	DropClientLoot(DropClientLoot) = 30,
+	Die(Die) = 37,
 	RequestDie(RequestDie) = 38,
 	PlayEmote(PlayEmote) = 41,
 	ControlBehaviors(ControlBehaviors) = 48,
@@ -36,6 +59,7 @@ pub enum GameMessage {
 	EquipInventory(EquipInventory) = 231,
 	UnEquipInventory(UnEquipInventory) = 233,
 	RespondToMission(RespondToMission) = 249,
+	GetMissionState(GetMissionState) = 250,
 	ServerTerminateInteraction(ServerTerminateInteraction) = 358,
 	RequestUse(RequestUse) = 364,
 	BuyFromVendor(BuyFromVendor) = 373,
@@ -56,7 +80,15 @@ pub enum GameMessage {
 	MissionDialogueOk(MissionDialogueOk) = 520,
 	MessageBoxRespond(MessageBoxRespond) = 530,
 	ChoiceBoxRespond(ChoiceBoxRespond) = 531,
+	GuildRemovePlayer(GuildRemovePlayer) = 588,
+	GuildSetPlayerRank(GuildSetPlayerRank) = 590,
+	GuildInvite(GuildInvite) = 592,
+	RequestGuildData = 593,
 	UseNonEquipmentItem(UseNonEquipmentItem) = 603,
+	/// Takes all loot out of a chest the player opened with [`RequestUse`], sent to the chest.
+	LootTakeAll = 624,
+	/// Closes the loot window of a chest, sent to the chest.
+	LootCloseWindow = 635,
 	FetchModelMetadataRequest(FetchModelMetadataRequest) = 638,
 	CommandPet(CommandPet) = 640,
 	RequestActivitySummaryLeaderboardData(RequestActivitySummaryLeaderboardData) = 648,
@@ -65,9 +97,15 @@ pub enum GameMessage {
 	ClientExitTamingMinigame(ClientExitTamingMinigame) = 663,
 	PetTamingMinigameResult(PetTamingMinigameResult) = 667,
 	NotifyTamingBuildSuccess(NotifyTamingBuildSuccess) = 673,
+	ActivateBubbleBuff = 678,
+	DeactivateBubbleBuff = 679,
 	RequestSetPetName(RequestSetPetName) = 683,
+	LeaveTeam = 695,
+	TeamKickPlayer(TeamKickPlayer) = 697,
+	TeamSetLootOption(TeamSetLootOption) = 699,
 	CinematicUpdate(CinematicUpdate) = 764,
 	FireEventServerSide(FireEventServerSide) = 770,
+	TeamInviteByProximity(TeamInviteByProximity) = 772,
 	QueryPropertyData = 717,
 	PropertyEditorBegin(PropertyEditorBegin) = 724,
 	PropertyEditorEnd = 725,
@@ -82,6 +120,7 @@ pub enum GameMessage {
 	SetMissionTypeState(SetMissionTypeState) = 851,
 	UpdatePropertyOrModelForFilterCheck(UpdatePropertyOrModelForFilterCheck) = 863,
 	ClientTradeRequest(ClientTradeRequest) = 868,
+	ClientTradeUpdate(ClientTradeUpdate) = 875,
 	ClientTradeCancel = 878,
 	ClientTradeAccept(ClientTradeAccept) = 880,
 	ReadyForUpdates(ReadyForUpdates) = 888,
@@ -103,10 +142,16 @@ pub enum GameMessage {
 	BuildModeSet(BuildModeSet) = 1069,
 	BuildExitConfirmation(BuildExitConfirmation) = 1072,
 	MoveItemBetweenInventoryTypes(MoveItemBetweenInventoryTypes) = 1093,
+	ModularBuildBegin = 1094,
+	ModularBuildEnd = 1095,
+	ModularBuildMoveAndEquip(ModularBuildMoveAndEquip) = 1096,
+	ModularBuildFinish(ModularBuildFinish) = 1097,
+	PickedUpModule(PickedUpModule) = 1098,
 	MissionDialogueCancelled(MissionDialogueCancelled) = 1129,
 	ModuleAssemblyQueryData = 1132,
 	SyncSkill(SyncSkill) = 1145,
 	RequestServerProjectileImpact(RequestServerProjectileImpact) = 1148,
+	ModularBuildConvertModel(ModularBuildConvertModel) = 1155,
 	ToggleSendingPositionUpdates(ToggleSendingPositionUpdates) = 1166,
 	PlacePropertyModel(PlacePropertyModel) = 1170,
 	ReportBug(ReportBug) = 1198,
@@ -136,6 +181,7 @@ pub enum GameMessage {
 	SetEmotesEnabled(SetEmotesEnabled) = 1577,
 	VehicleNotifyHitImaginationServer(VehicleNotifyHitImaginationServer) = 1606,
 	CelebrationCompleted = 1632,
+	RemoveBuff(RemoveBuff) = 1648,
 	RequestMoveItemBetweenInventoryTypes(RequestMoveItemBetweenInventoryTypes) = 1666,
 	NotifyServerLevelProcessingComplete = 1734,
 	ServerCancelMoveSkill = 1746,
@@ -175,8 +221,8 @@ pub struct StartSkill {
 	pub consumable_item_id: ObjId,
 	#[default(0.0)]
 	pub caster_latency: f32,
-	#[default(0)]
-	pub cast_type: i32, // todo: type
+	#[default(CastType::None)]
+	pub cast_type: CastType,
 	#[default(Vector3::ZERO)]
 	pub last_clicked_posit: Vector3,
 	pub optional_originator_id: ObjId,
@@ -184,8 +230,8 @@ pub struct StartSkill {
 	pub optional_target_id: ObjId,
 	#[default(Quaternion::IDENTITY)]
 	pub originator_rot: Quaternion,
-	pub bitstream: Vec<u8>,
-	pub skill_id: u32, // todo: type
+	pub bitstream: LVec<u32, u8>,
+	pub skill_id: SkillId,
 	#[default(0)]
 	pub skill_handle: u32,
 }
@@ -202,7 +248,7 @@ pub struct CasterDead {
 pub struct VerifyAck {
 	#[default(false)]
 	pub different: bool,
-	pub bitstream: Vec<u8>,
+	pub bitstream: LVec<u32, u8>,
 	#[default(0)]
 	pub handle: u32,
 }
@@ -211,7 +257,7 @@ pub struct VerifyAck {
 pub struct SelectSkill {
 	#[default(false)]
 	pub from_skill_set: bool,
-	pub skill_id: i32,
+	pub skill_id: SkillId,
 }
 
 #[derive(Debug, GameMessage, PartialEq)]
@@ -232,22 +278,30 @@ pub struct RebuildCancel {
 	pub user_id: ObjId,
 }
 
+/// Accepts or turns in a mission.
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct RespondToMission {
-	pub mission_id: i32,
+	pub mission_id: MissionId,
 	pub player_id: ObjId,
 	pub receiver: ObjId,
+	/// The chosen reward, for missions that offer a choice. Joining a faction is such a choice, with the faction's kit as the reward.
 	#[default(LOT_NULL)]
 	pub reward_item: Lot,
 }
 
+/// Queries the state of a mission or achievement. The server answers with [`NotifyMission`](super::client::NotifyMission).
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct GetMissionState {
+	pub mission_id: MissionId,
+}
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct ServerTerminateInteraction {
 	pub obj_id_terminator: ObjId,
 	pub terminate_type: TerminateType,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum TerminateType {
 	Range,
@@ -265,7 +319,7 @@ pub struct RequestUse {
 	pub secondary: bool,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum InteractionType {
 	MissionOfferer,
@@ -333,7 +387,7 @@ pub struct SetTooltipFlag {
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct SetFlag {
 	pub flag: bool,
-	pub flag_id: i32,
+	pub flag_id: FlagId,
 }
 
 #[derive(Debug, GameMessage, PartialEq)]
@@ -354,7 +408,7 @@ pub struct PlayerLoaded {
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct RequestLinkedMission {
 	pub player_id: ObjId,
-	pub mission_id: i32,
+	pub mission_id: MissionId,
 	pub mission_offered: bool,
 }
 
@@ -362,7 +416,7 @@ pub struct RequestLinkedMission {
 pub struct MissionDialogueOk {
 	pub is_complete: bool,
 	pub mission_state: MissionState,
-	pub mission_id: i32,
+	pub mission_id: MissionId,
 	pub responder: ObjId,
 }
 
@@ -380,6 +434,25 @@ pub struct ChoiceBoxRespond {
 	pub identifier: GmWString,
 }
 
+/// Kicks a member from the player's guild, or leaves it if the member is the player. Sent from the player.
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct GuildRemovePlayer {
+	pub player_id: ObjId,
+}
+
+/// Promotes or demotes a member of the player's guild. Sent from the player.
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct GuildSetPlayerRank {
+	pub player_id: ObjId,
+	pub rank: GuildRank,
+}
+
+/// Invites a player to the sender's guild by name. Sent from the player.
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct GuildInvite {
+	pub invitee_name: GmWString,
+}
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct UseNonEquipmentItem {
 	pub item_to_use: ObjId,
@@ -397,7 +470,7 @@ pub struct FetchModelMetadataRequest {
 pub struct CommandPet {
 	pub generic_pos_info: Vector3,
 	pub obj_id_source: ObjId,
-	pub pet_command_type: i32,
+	pub pet_command_type: PetCommandType,
 	pub type_id: i32,
 	#[default(false)]
 	pub override_obey: bool,
@@ -405,8 +478,8 @@ pub struct CommandPet {
 
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct RequestActivitySummaryLeaderboardData {
-	#[default(0)]
-	pub game_id: i32,
+	#[default(ActivityId(0))]
+	pub game_id: ActivityId,
 	#[default(QueryType::TopCharacter)]
 	pub query_type: QueryType,
 	#[default(10)]
@@ -424,7 +497,7 @@ pub struct NotifyPet {
 	pub pet_notification_type: PetNotificationType,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum QueryType {
 	TopAll,
@@ -453,6 +526,18 @@ pub struct RequestSetPetName {
 	pub name: GmWString,
 }
 
+/// Kicks a member from the team, from the team window. Only the team leader can kick. Players can also kick by name through the chat server, see [`TeamKick`](crate::chat::server::TeamKick).
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct TeamKickPlayer {
+	pub player_id: ObjId,
+}
+
+/// Changes how loot is shared in the team, like [`TeamSetLoot`](crate::chat::server::TeamSetLoot) does through the chat server. Only the team leader can change it.
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct TeamSetLootOption {
+	pub loot_option: TeamLootOption,
+}
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct CinematicUpdate {
 	#[default(CinematicEvent::Started)]
@@ -466,7 +551,7 @@ pub struct CinematicUpdate {
 	pub waypoint: i32,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum CinematicEvent {
 	Started,
@@ -486,6 +571,19 @@ pub struct FireEventServerSide {
 	pub sender_id: ObjId,
 }
 
+impl FireEventServerSide {
+	/// Asks to move the player to the destination of a rocket launchpad, after the launch started with [`FireEventClientSide::rocket_equipped`](super::client::FireEventClientSide::rocket_equipped). Sent to the launchpad.
+	pub fn zone_player(player_id: ObjId) -> Self {
+		Self { args: lu!("ZonePlayer"), param1: -1, param2: -1, param3: -1, sender_id: player_id }
+	}
+}
+
+/// Invites a nearby player to the sender's team, from the player's radial menu. Sent from the inviting player. Respond like to a [`TeamInvite`](crate::chat::server::TeamInvite) by name.
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct TeamInviteByProximity {
+	pub invitee_id: ObjId,
+}
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct PropertyEditorBegin {
 	#[default(0)]
@@ -494,7 +592,7 @@ pub struct PropertyEditorBegin {
 	pub property_object_id: ObjId,
 	#[default(1)]
 	pub start_mode: i32,
-	#[default(0)]
+	#[default(false)]
 	pub start_paused: bool,
 }
 
@@ -525,7 +623,7 @@ pub struct DeleteModelFromClient {
 	pub reason: DeleteReason,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum DeleteReason {
 	PickingModelUp,
@@ -567,7 +665,7 @@ pub struct SetMissionTypeState {
 	pub mission_type: GmString,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum MissionLockState {
 	Locked,
@@ -592,6 +690,42 @@ pub struct ClientTradeRequest {
 	pub invitee: ObjId,
 }
 
+/// Updates the coins and items the player offers in a trade, sent on every change to the offer.
+#[derive(Arbitrary, Debug, PartialEq, Reflect)]
+pub struct ClientTradeUpdate {
+	pub currency: u64,
+	pub items: LVec<u32, TradeItem>,
+}
+
+// the items contain flag bits, so the whole message is read at the bit level
+impl<R: Read> Deserialize<LE, R> for ClientTradeUpdate {
+	fn deserialize(reader: &mut R) -> Res<Self> {
+		let mut reader = bit_reader(reader);
+		let currency = LERead::read(&mut reader)?;
+		let items = LERead::read(&mut reader)?;
+		Ok(Self { currency, items })
+	}
+}
+
+#[cfg(feature = "schema")]
+impl crate::schema::Schema for ClientTradeUpdate {
+	fn schema() -> crate::schema::Type {
+		super::trade_schema(std::any::type_name::<Self>(), "ClientTradeUpdate", vec![
+			("currency", u64::schema(), false),
+			("items", <LVec<u32, TradeItem>>::schema(), false),
+		])
+	}
+}
+
+impl<'a, W: Write> Serialize<LE, W> for &'a ClientTradeUpdate {
+	fn serialize(self, writer: &mut W) -> Res<()> {
+		let mut writer = bit_writer(writer);
+		LEWrite::write(&mut writer, self.currency)?;
+		LEWrite::write(&mut writer, &self.items)?;
+		writer.flush()
+	}
+}
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct ClientTradeAccept {
 	#[default(false)]
@@ -613,8 +747,8 @@ pub struct PropertyModerationAction {
 	#[default(0)]
 	pub character_id: ObjId,
 	pub info: GmWString,
-	#[default(-1)]
-	pub new_moderation_status: i32,
+	#[default(PropertyModerationStatus::Unset)]
+	pub new_moderation_status: PropertyModerationStatus,
 }
 
 #[derive(Debug, GameMessage, PartialEq)]
@@ -626,7 +760,7 @@ pub struct BounceNotification {
 
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct SetBbbAutosave {
-	pub lxfml_data_compressed: Vec<u8>,
+	pub lxfml_data_compressed: LVec<u32, u8>,
 }
 
 #[derive(Debug, GameMessage, PartialEq)]
@@ -637,7 +771,7 @@ pub struct BbbLoadItemRequest {
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct BbbSaveRequest {
 	pub local_id: ObjId,
-	pub lxfml_data_compressed: Vec<u8>,
+	pub lxfml_data_compressed: LVec<u32, u8>,
 	pub time_taken_in_ms: u32,
 }
 
@@ -662,11 +796,11 @@ pub struct StartBuildingWithItem {
 	pub source_bag: InventoryType,
 	pub source_id: ObjId,
 	pub source_lot: Lot,
-	pub source_type: i32,
+	pub source_type: ArrangeItemType,
 	pub target_id: ObjId,
 	pub target_lot: Lot,
 	pub target_pos: Vector3,
-	pub target_type: i32,
+	pub target_type: ArrangeItemType,
 }
 
 #[derive(Debug, GameMessage, PartialEq)]
@@ -674,15 +808,15 @@ pub struct DoneArrangingWithItem {
 	pub new_source_bag: InventoryType,
 	pub new_source_id: ObjId,
 	pub new_source_lot: Lot,
-	pub new_source_type: i32,
+	pub new_source_type: ArrangeItemType,
 	pub new_target_id: ObjId,
 	pub new_target_lot: Lot,
-	pub new_target_type: i32,
+	pub new_target_type: ArrangeItemType,
 	pub new_target_pos: Vector3,
 	pub old_item_bag: InventoryType,
 	pub old_item_id: ObjId,
 	pub old_item_lot: Lot,
-	pub old_item_type: i32,
+	pub old_item_type: ArrangeItemType,
 }
 
 #[derive(Debug, GameMessage, PartialEq)]
@@ -692,8 +826,8 @@ pub struct SetBuildMode {
 	pub distance_type: i32,
 	#[default(false)]
 	pub mode_paused: bool,
-	#[default(1)]
-	pub mode_value: i32,
+	#[default(BuildModeValue::Normal)]
+	pub mode_value: BuildModeValue,
 	pub player_id: ObjId,
 	#[default(Vector3::ZERO)]
 	pub start_pos: Vector3,
@@ -706,8 +840,8 @@ pub struct BuildModeSet {
 	pub distance_type: i32,
 	#[default(false)]
 	pub mode_paused: bool,
-	#[default(1)]
-	pub mode_value: i32,
+	#[default(BuildModeValue::Normal)]
+	pub mode_value: BuildModeValue,
 	pub player_id: ObjId,
 	#[default(Vector3::ZERO)]
 	pub start_pos: Vector3,
@@ -731,11 +865,30 @@ pub struct MoveItemBetweenInventoryTypes {
 	pub template_id: Lot,
 }
 
+/// Moves a module from the build area back into the inventory and equips it.
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct ModularBuildMoveAndEquip {
+	pub template_id: Lot,
+}
+
+/// Completes a modular build (like a rocket or race car), with the modules in the order they're assembled.
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct ModularBuildFinish {
+	pub module_template_ids: LVec<u8, Lot>,
+}
+
+/// Picks up a module from the inventory in the modular build UI, to place it in the build.
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct PickedUpModule {
+	pub module_id: ObjId,
+	pub template_id: Lot,
+}
+
 #[derive(Debug, GameMessage, PartialEq)]
 pub struct MissionDialogueCancelled {
 	pub is_complete: bool,
 	pub mission_state: MissionState,
-	pub mission_id: i32,
+	pub mission_id: MissionId,
 	pub responder: ObjId,
 }
 
@@ -743,7 +896,7 @@ pub struct MissionDialogueCancelled {
 pub struct SyncSkill {
 	#[default(false)]
 	pub done: bool,
-	pub bitstream: Vec<u8>,
+	pub bitstream: LVec<u32, u8>,
 	pub behavior_handle: u32,
 	pub skill_handle: u32,
 }
@@ -754,7 +907,13 @@ pub struct RequestServerProjectileImpact {
 	pub local_id: ObjId,
 	#[default(OBJID_EMPTY)]
 	pub target_id: ObjId,
-	pub bitstream: Vec<u8>,
+	pub bitstream: LVec<u32, u8>,
+}
+
+/// Converts a finished modular build back into its modules.
+#[derive(Debug, GameMessage, PartialEq)]
+pub struct ModularBuildConvertModel {
+	pub model_id: ObjId,
 }
 
 #[derive(Debug, GameMessage, PartialEq)]
@@ -854,7 +1013,7 @@ pub struct ActivateBrickMode {
 	pub enter_flag: bool,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, GmParam)]
+#[derive(Arbitrary, Debug, Deserialize, Reflect, Schema, Serialize, PartialEq, GmParam)]
 #[repr(u32)]
 pub enum BuildType {
 	Nowhere,
@@ -3,7 +3,7 @@ GameMessage::BbbSaveRequest(
 		local_id: 288230376151713599,
 		lxfml_data_compressed: vec![
 			115, 100, 48, 1, 255, 64, 2, 0, 0, 120, 218, 189, 151, 93, 111, 211, 48, 20, 134, 239, 145, 248, 15, 145, 175, 61, 199, 223, 246, 145, 82, 38, 16, 108, 66, 106, 53, 180, 49, 137, 219, 208, 186, 85, 160, 77, 170, 36, 32, 248, 247, 216, 89, 218, 180, 101, 147, 162, 144, 144, 92, 212, 57, 125, 207, 107, 159, 71, 142, 123, 154, 92, 255, 218, 109, 163, 159, 174, 172, 178, 34, 159, 33, 70, 40, 138, 92, 190, 44, 86, 89, 190, 153, 161, 199, 207, 55, 87, 22, 69, 85, 157, 230, 171, 116, 91, 228, 110, 134, 242, 2, 69, 215, 111, 94, 191, 74, 230, 95, 110, 22, 243, 67, 230, 34, 253, 86, 148, 51, 164, 208, 49, 144, 229, 33, 64, 145, 151, 70, 81, 178, 112, 117, 218, 140, 252, 248, 237, 126, 191, 205, 150, 105, 237, 85, 81, 158, 238, 188, 231, 252, 195, 237, 93, 244, 152, 103, 33, 215, 161, 11, 79, 250, 183, 103, 124, 176, 122, 87, 250, 133, 157, 152, 28, 61, 78, 20, 217, 242, 251, 131, 171, 187, 18, 165, 50, 237, 215, 73, 124, 92, 214, 147, 174, 58, 203, 138, 74, 183, 254, 248, 190, 89, 192, 202, 85, 217, 38, 15, 15, 130, 82, 129, 90, 153, 23, 126, 74, 203, 250, 101, 93, 180, 75, 107, 87, 102, 233, 182, 154, 33, 46, 48, 197, 141, 100, 89, 148, 77, 241, 62, 3, 211, 206, 43, 76, 235, 9, 159, 184, 213, 190, 186, 106, 93, 148, 187, 86, 206, 130, 131, 191, 187, 207, 43, 198, 136, 166, 225, 18, 150, 73, 13, 134, 27, 44, 149, 34, 224, 47, 13, 210, 114, 201, 153, 53, 152, 113, 73, 100, 80, 1, 83, 138, 27, 33, 229, 217, 180, 113, 152, 183, 171, 41, 14, 69, 29, 72, 196, 13, 138, 103, 185, 176, 158, 92, 216, 168, 92, 216, 191, 112, 161, 2, 192, 88, 26, 16, 8, 163, 38, 226, 194, 123, 114, 225, 163, 114, 225, 195, 185, 104, 2, 202, 239, 23, 10, 211, 49, 17, 61, 153, 136, 81, 153, 136, 225, 76, 12, 1, 6, 160, 184, 0, 174, 140, 101, 124, 34, 46, 178, 39, 23, 57, 42, 23, 57, 156, 139, 37, 214, 0, 72, 97, 165, 209, 74, 243, 169, 246, 139, 234, 201, 69, 141, 202, 69, 13, 231, 2, 196, 250, 179, 69, 40, 209, 30, 46, 19, 113, 209, 61, 185, 232, 81, 185, 232, 193, 92, 52, 37, 126, 187, 0, 215, 166, 121, 139, 166, 218, 46, 166, 39, 22, 51, 42, 22, 51, 28, 11, 35, 198, 31, 185, 204, 114, 198, 45, 80, 61, 213, 241, 98, 123, 114, 177, 163, 114, 177, 195, 185, 112, 98, 252, 177, 75, 65, 107, 3, 218, 78, 246, 115, 4, 61, 185, 192, 168, 92, 96, 56, 23, 65, 60, 16, 160, 140, 113, 65, 165, 30, 173, 165, 107, 199, 79, 125, 111, 114, 159, 109, 178, 213, 195, 239, 170, 118, 187, 99, 39, 124, 18, 235, 236, 154, 224, 127, 106, 84, 163, 175, 190, 146, 123, 183, 174, 2, 91, 134, 57, 22, 88, 98, 133, 53, 54, 216, 98, 232, 26, 253, 248, 114, 165, 103, 145, 182, 194, 219, 178, 248, 177, 191, 168, 240, 36, 118, 244, 186, 140, 157, 69, 66, 102, 18, 55, 127, 127, 252, 232, 15, 144, 184, 72, 165,
-		],
+		].into(),
 		time_taken_in_ms: 36576,
 	},
 )
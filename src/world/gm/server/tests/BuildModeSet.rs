@@ -3,7 +3,7 @@ GameMessage::BuildModeSet(
 		start: true,
 		distance_type: 1,
 		mode_paused: false,
-		mode_value: 1,
+		mode_value: BuildModeValue::Normal,
 		player_id: 0,
 		start_pos: Vector3 {
 			x: 0.0,
@@ -0,0 +1,18 @@
+GameMessage::ClientTradeUpdate(
+	ClientTradeUpdate {
+		currency: 100,
+		items: vec![
+			TradeItem {
+				item_id: 1152921507969871364,
+				subkey: 1152921507969871364,
+				lot: 1727,
+				bound: false,
+				count: Some(1),
+				slot: None,
+				inv_type: None,
+				extra_info: None,
+				force_deletion: false,
+			},
+		].into(),
+	},
+)
@@ -6,7 +6,7 @@ GameMessage::CommandPet(
 			z: 0.0,
 		},
 		obj_id_source: 1152921510436607007,
-		pet_command_type: 4,
+		pet_command_type: PetCommandType::Unknown(4),
 		type_id: 1,
 		override_obey: true,
 	},
@@ -0,0 +1,13 @@
+GameMessage::Die(
+	Die {
+		client_death: true,
+		spawn_loot: true,
+		death_type: lu!(""),
+		direction_relative_angle_xz: 0.0,
+		direction_relative_angle_y: 0.0,
+		direction_relative_force: 0.0,
+		kill_type: KillType::Violent,
+		killer_id: 1152921510109196992,
+		loot_owner_id: 1152921510109196992,
+	},
+)
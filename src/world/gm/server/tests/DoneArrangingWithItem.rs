@@ -3,10 +3,10 @@ GameMessage::DoneArrangingWithItem(
 		new_source_bag: InventoryType::Default,
 		new_source_id: 0,
 		new_source_lot: 4294967295,
-		new_source_type: 0,
+		new_source_type: ArrangeItemType::None,
 		new_target_id: 0,
 		new_target_lot: 4294967295,
-		new_target_type: 0,
+		new_target_type: ArrangeItemType::None,
 		new_target_pos: Vector3 {
 			x: 0.0,
 			y: 0.0,
@@ -15,6 +15,6 @@ GameMessage::DoneArrangingWithItem(
 		old_item_bag: InventoryType::Default,
 		old_item_id: 1152921507005357158,
 		old_item_lot: 6086,
-		old_item_type: 1,
+		old_item_type: ArrangeItemType::Model,
 	},
 )
@@ -0,0 +1,20 @@
+GameMessage::DropClientLoot(
+	DropClientLoot {
+		use_position: false,
+		final_position: Vector3 {
+			x: 0.0,
+			y: 0.0,
+			z: 0.0,
+		},
+		currency: 12,
+		item_template: 935,
+		loot_id: 1152921510650291340,
+		owner: 1152921510109196992,
+		source_obj: 1152921510650291335,
+		spawn_position: Vector3 {
+			x: -310.5,
+			y: 288.25,
+			z: 42.75,
+		},
+	},
+)
@@ -0,0 +1,5 @@
+GameMessage::GetMissionState(
+	GetMissionState {
+		mission_id: MissionId(1732),
+	},
+)
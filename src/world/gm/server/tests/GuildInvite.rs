@@ -0,0 +1,5 @@
+GameMessage::GuildInvite(
+	GuildInvite {
+		invitee_name: lu!("Captain Gloves"),
+	},
+)
@@ -0,0 +1 @@
+GameMessage::LootTakeAll
@@ -2,7 +2,7 @@ GameMessage::MissionDialogueCancelled(
 	MissionDialogueCancelled {
 		is_complete: false,
 		mission_state: MissionState::ReadyToComplete,
-		mission_id: 320,
+		mission_id: MissionId(320),
 		responder: 1152921510115197038,
 	},
 )
@@ -2,7 +2,7 @@ GameMessage::MissionDialogueOk(
 	MissionDialogueOk {
 		is_complete: true,
 		mission_state: MissionState::ReadyToComplete,
-		mission_id: 1837,
+		mission_id: MissionId(1837),
 		responder: 1152921507004579166,
 	},
 )
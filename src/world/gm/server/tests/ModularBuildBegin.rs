@@ -0,0 +1 @@
+GameMessage::ModularBuildBegin
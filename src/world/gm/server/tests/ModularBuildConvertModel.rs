@@ -0,0 +1,5 @@
+GameMessage::ModularBuildConvertModel(
+	ModularBuildConvertModel {
+		model_id: 1152921507960015642,
+	},
+)
@@ -0,0 +1,5 @@
+GameMessage::ModularBuildFinish(
+	ModularBuildFinish {
+		module_template_ids: vec![4714, 4713, 4712].into(),
+	},
+)
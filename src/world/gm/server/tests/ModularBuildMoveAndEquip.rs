@@ -0,0 +1,5 @@
+GameMessage::ModularBuildMoveAndEquip(
+	ModularBuildMoveAndEquip {
+		template_id: 6416,
+	},
+)
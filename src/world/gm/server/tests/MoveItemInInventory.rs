@@ -1,9 +1,9 @@
 GameMessage::MoveItemInInventory(
 	MoveItemInInventory {
-		dest_inv_type: -1,
+		dest_inv_type: InventoryType::Invalid,
 		obj_id: 1152921510837614509,
 		inventory_type: InventoryType::Default,
-		response_code: 0,
+		response_code: MoveItemResponseCode::Success,
 		slot: 166,
 	},
 )
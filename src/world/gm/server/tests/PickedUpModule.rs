@@ -0,0 +1,6 @@
+GameMessage::PickedUpModule(
+	PickedUpModule {
+		module_id: 1152921510650291330,
+		template_id: 6416,
+	},
+)
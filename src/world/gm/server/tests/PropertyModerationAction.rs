@@ -2,6 +2,6 @@ GameMessage::PropertyModerationAction(
 	PropertyModerationAction {
 		character_id: 1152921510115197038,
 		info: lu!(""),
-		new_moderation_status: 0,
+		new_moderation_status: PropertyModerationStatus::Pending,
 	},
 )
@@ -1,6 +1,6 @@
 GameMessage::RequestActivitySummaryLeaderboardData(
 	RequestActivitySummaryLeaderboardData {
-		game_id: 5,
+		game_id: ActivityId(5),
 		query_type: QueryType::TopSocial,
 		results_end: 10,
 		results_start: 0,
@@ -1,7 +1,7 @@
 GameMessage::RequestLinkedMission(
 	RequestLinkedMission {
 		player_id: 1152921507004579166,
-		mission_id: 1837,
+		mission_id: MissionId(1837),
 		mission_offered: false,
 	},
 )
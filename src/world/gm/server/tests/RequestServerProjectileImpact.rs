@@ -4,6 +4,6 @@ GameMessage::RequestServerProjectileImpact(
 		target_id: 288300744896150797,
 		bitstream: vec![
 			0, 76, 0, 32, 0, 0, 0, 0, 32, 0, 0, 16, 16,
-		],
+		].into(),
 	},
 )
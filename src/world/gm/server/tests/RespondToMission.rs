@@ -1,6 +1,6 @@
 GameMessage::RespondToMission(
 	RespondToMission {
-		mission_id: 1837,
+		mission_id: MissionId(1837),
 		player_id: 1152921507004579166,
 		receiver: 288300744895889639,
 		reward_item: 4294967295,
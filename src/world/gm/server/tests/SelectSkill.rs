@@ -1,6 +1,6 @@
 GameMessage::SelectSkill(
 	SelectSkill {
 		from_skill_set: false,
-		skill_id: 809,
+		skill_id: SkillId(809),
 	},
 )
@@ -2,6 +2,6 @@ GameMessage::SetBbbAutosave(
 	SetBbbAutosave {
 		lxfml_data_compressed: vec![
 			115, 100, 48, 1, 255, 101, 1, 0, 0, 120, 218, 189, 83, 93, 75, 195, 48, 20, 125, 23, 252, 15, 33, 207, 93, 147, 126, 173, 43, 180, 14, 69, 55, 132, 13, 101, 115, 224, 107, 108, 179, 17, 109, 147, 145, 68, 209, 127, 111, 82, 251, 45, 190, 218, 62, 244, 246, 244, 220, 115, 239, 57, 52, 233, 242, 179, 42, 193, 7, 149, 138, 9, 158, 65, 207, 197, 16, 80, 158, 139, 130, 241, 83, 6, 15, 79, 171, 217, 2, 2, 165, 9, 47, 72, 41, 56, 205, 32, 23, 16, 44, 175, 46, 47, 210, 205, 243, 106, 187, 105, 59, 183, 228, 85, 200, 12, 70, 176, 3, 24, 183, 0, 134, 134, 10, 64, 186, 165, 154, 212, 149, 169, 175, 207, 231, 146, 229, 68, 27, 22, 224, 164, 50, 154, 155, 187, 245, 3, 56, 112, 102, 123, 41, 156, 104, 226, 223, 154, 168, 149, 186, 145, 102, 177, 129, 72, 167, 49, 96, 176, 252, 109, 79, 117, 111, 49, 140, 226, 230, 115, 138, 186, 181, 126, 120, 106, 212, 5, 36, 61, 222, 223, 214, 11, 20, 84, 177, 19, 183, 47, 1, 198, 1, 108, 104, 134, 248, 72, 164, 254, 155, 7, 42, 162, 169, 100, 164, 84, 25, 244, 3, 7, 59, 53, 37, 23, 178, 54, 111, 58, 28, 220, 107, 217, 177, 38, 225, 129, 154, 54, 238, 212, 81, 200, 170, 161, 123, 86, 193, 220, 253, 115, 230, 121, 238, 28, 219, 43, 88, 120, 225, 60, 137, 253, 216, 9, 163, 200, 197, 65, 146, 196, 11, 236, 199, 65, 24, 196, 145, 227, 249, 161, 27, 90, 86, 226, 69, 145, 5, 195, 209, 88, 100, 231, 246, 158, 144, 53, 213, 38, 129, 234, 40, 154, 184, 6, 33, 165, 59, 118, 98, 197, 254, 75, 105, 90, 117, 177, 13, 176, 94, 174, 6, 255, 201, 21, 120, 49, 78, 118, 244, 168, 70, 127, 9, 154, 238, 53, 66, 26, 63, 107, 41, 222, 207, 19, 63, 3, 172, 211, 154, 98, 35, 196, 118, 166, 168, 62, 25, 166, 250, 6, 189, 222, 232, 97,
-		],
+		].into(),
 	},
 )
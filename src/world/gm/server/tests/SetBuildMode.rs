@@ -3,7 +3,7 @@ GameMessage::SetBuildMode(
 		start: true,
 		distance_type: -1,
 		mode_paused: false,
-		mode_value: 1,
+		mode_value: BuildModeValue::Normal,
 		player_id: 1152921510115197038,
 		start_pos: Vector3 {
 			x: -161.56265,
@@ -1,6 +1,6 @@
 GameMessage::SetFlag(
 	SetFlag {
 		flag: true,
-		flag_id: 121,
+		flag_id: FlagId(121),
 	},
 )
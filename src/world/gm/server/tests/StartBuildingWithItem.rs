@@ -5,7 +5,7 @@ GameMessage::StartBuildingWithItem(
 		source_bag: InventoryType::Default,
 		source_id: 1152921507005357158,
 		source_lot: 6086,
-		source_type: 1,
+		source_type: ArrangeItemType::Model,
 		target_id: 0,
 		target_lot: 4294967295,
 		target_pos: Vector3 {
@@ -13,6 +13,6 @@ GameMessage::StartBuildingWithItem(
 			y: 0.0,
 			z: 0.0,
 		},
-		target_type: 0,
+		target_type: ArrangeItemType::None,
 	},
 )
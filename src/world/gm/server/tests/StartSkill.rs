@@ -3,15 +3,15 @@ GameMessage::StartSkill(
 		used_mouse: false,
 		consumable_item_id: 0,
 		caster_latency: 0.0,
-		cast_type: 0,
+		cast_type: CastType::None,
 		last_clicked_posit: Vector3::ZERO,
 		optional_originator_id: 0,
 		optional_target_id: 0,
 		originator_rot: Quaternion::IDENTITY,
 		bitstream: vec![
 			1, 0, 0, 0, 1, 0, 0, 0, 128, 128, 0, 0, 50, 138, 129, 0, 0, 32, 0, 2, 61, 0, 0, 0, 0,
-		],
-		skill_id: 1140,
+		].into(),
+		skill_id: SkillId(1140),
 		skill_handle: 121,
 	},
 )
@@ -3,7 +3,7 @@ GameMessage::SyncSkill(
 		done: true,
 		bitstream: vec![
 			128, 64, 0, 0, 20, 8, 0, 128, 0, 16, 0, 1, 0, 81, 0, 32, 0, 0, 0, 0, 96, 0, 0, 0, 16, 0,
-		],
+		].into(),
 		behavior_handle: 2,
 		skill_handle: 1,
 	},
@@ -0,0 +1,5 @@
+GameMessage::TeamInviteByProximity(
+	TeamInviteByProximity {
+		invitee_id: 1152921506064087003,
+	},
+)
@@ -0,0 +1,5 @@
+GameMessage::TeamKickPlayer(
+	TeamKickPlayer {
+		player_id: 1152921506064087003,
+	},
+)
@@ -0,0 +1,5 @@
+GameMessage::TeamSetLootOption(
+	TeamSetLootOption {
+		loot_option: TeamLootOption::Shared,
+	},
+)
@@ -3,7 +3,7 @@ GameMessage::VerifyAck(
 		different: true,
 		bitstream: vec![
 			32,
-		],
+		].into(),
 		handle: 11,
 	},
 )
@@ -7,12 +7,15 @@ use std::io::Result as Res;
 use flate2::{Compression, read::ZlibDecoder, write::ZlibEncoder};
 
 use endio::{Deserialize, LE, LERead, LEWrite, Serialize};
+use lu_packets_derive::{Arbitrary, Reflect, Schema};
 use super::gm::GmParam;
 
 use crate::common::{LuStrExt, LuVarString, LuVarWString, LuWStr};
+#[cfg(feature = "arbitrary")]
+use crate::common::Ucs2Char;
 
 /// A value contained in a [`LuNameValue`].
-#[derive(Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Clone, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 #[repr(u8)]
 pub enum LnvValue {
 	WString(LuVarWString<u32>) = 0,
@@ -131,7 +134,7 @@ impl<const N: usize> From<&[u8; N]> for LnvValue {
 }
 
 /// A hash map with values being one of multiple possible types.
-#[derive(PartialEq)]
+#[derive(Clone, PartialEq)]
 pub struct LuNameValue(HashMap<LuVarWString<u32>, LnvValue>);
 
 impl LuNameValue {
@@ -163,6 +166,29 @@ impl std::fmt::Debug for LuNameValue {
 	}
 }
 
+/**
+	Keys are at most 127 characters long, since the binary format stores their length in bytes as a `u8`.
+
+	They don't contain `=`, since that separates keys from values in the text format used in game messages.
+*/
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for LuNameValue {
+	fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+		let len = u.arbitrary_len::<(LuVarWString<u32>, LnvValue)>()?;
+		let mut map = HashMap::with_capacity(len);
+		for _ in 0..len {
+			let key_len = u.int_in_range(0..=127)?;
+			let mut key = Vec::with_capacity(key_len);
+			for _ in 0..key_len {
+				let c: Ucs2Char = arbitrary::Arbitrary::arbitrary(u)?;
+				key.push(if c == b'='.into() { b'_'.into() } else { c });
+			}
+			map.insert(key.into(), arbitrary::Arbitrary::arbitrary(u)?);
+		}
+		Ok(Self(map))
+	}
+}
+
 impl<R: Read> Deserialize<LE, R> for LuNameValue {
 	fn deserialize(reader: &mut R) -> Res<Self> {
 		let len: u32 = LERead::read(reader)?;
@@ -298,4 +324,10 @@ impl GmParam for LuNameValue {
 		}
 		Ok(())
 	}
+
+	fn size(&self) -> ::std::io::Result<usize> {
+		let lu_var_wstr: LuVarWString<u32> = self.into();
+		let terminator = if lu_var_wstr.is_empty() { 0 } else { 2 };
+		Ok(4 + lu_var_wstr.len() * 2 + terminator)
+	}
 }
@@ -1,9 +1,9 @@
 use endio::{Deserialize, Serialize};
-use lu_packets_derive::VariantTests;
+use lu_packets_derive::{Arbitrary, Reflect, Schema, VariantTests};
 
 use crate::common::{LuWString32, LuWString400, LuWString50, ObjId};
 
-#[derive(Debug, Deserialize, PartialEq, Serialize, VariantTests)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize, VariantTests)]
 #[repr(u32)]
 pub enum Mail {
 	CreateRequest(CreateRequest) = 0,
@@ -14,7 +14,7 @@ pub enum Mail {
 	UnreadCountRequest = 11,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 #[trailing_padding = 4]
 pub struct CreateRequest {
 	pub subject: LuWString50,
@@ -26,21 +26,21 @@ pub struct CreateRequest {
 	pub locale_id: u16,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct ContentCollectRequest {
 	#[padding = 4]
 	pub mail_id: ObjId,
 	pub receiver_id: ObjId,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct DeleteRequest {
 	#[padding = 4]
 	pub mail_id: ObjId,
 	pub receiver_id: ObjId,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct MarkAsReadRequest {
 	#[padding = 4]
 	pub mail_id: ObjId,
@@ -2,18 +2,17 @@
 pub mod mail;
 
 use std::io::{Read, Write};
-use std::io::Result as Res;
+use std::io::{Error, ErrorKind::InvalidData, Result as Res};
 
 use endio::{Deserialize, LERead, LEWrite, Serialize};
 use endio::LittleEndian as LE;
-use endio_bit::{BEBitReader, BEBitWriter};
-use lu_packets_derive::VariantTests;
+use lu_packets_derive::{Arbitrary, MessageDeserialize, MessageHandler, Reflect, Schema, VariantTests};
 
-use crate::common::{ObjId, LuVarWString, LuWString33, LuWString42, ServiceId};
+use crate::common::{bit_reader, bit_writer, take, ObjId, LuVarWString, LuWStrRef, LuWString33, LuWString42, LVec, ServiceId};
 use crate::chat::ChatChannel;
 use crate::chat::server::ChatMessage;
 use crate::raknet::client::replica::controllable_physics::FrameStats;
-use super::ZoneId;
+use super::{MapId, ZoneId};
 use super::gm::server::SubjectGameMessage;
 use self::mail::Mail;
 
@@ -23,7 +22,7 @@ pub use crate::general::server::GeneralMessage;
 pub type Message = crate::raknet::server::Message<LuMessage>;
 
 /// All LU messages that can be received by a world server.
-#[derive(Debug, Deserialize, PartialEq, Serialize, VariantTests)]
+#[derive(Arbitrary, Debug, MessageDeserialize, PartialEq, Reflect, Schema, Serialize, VariantTests)]
 #[repr(u16)]
 pub enum LuMessage {
 	General(GeneralMessage) = ServiceId::General as u16,
@@ -31,7 +30,7 @@ pub enum LuMessage {
 }
 
 /// All server-received world messages.
-#[derive(Debug, Deserialize, PartialEq, Serialize, VariantTests)]
+#[derive(Arbitrary, Debug, MessageDeserialize, MessageHandler, PartialEq, Reflect, Schema, Serialize, VariantTests)]
 #[post_disc_padding = 1]
 #[repr(u32)]
 pub enum WorldMessage {
@@ -41,13 +40,25 @@ pub enum WorldMessage {
 	CharacterLoginRequest(CharacterLoginRequest) = 4,
 	SubjectGameMessage(SubjectGameMessage) = 5,
 	CharacterDeleteRequest(CharacterDeleteRequest) = 6,
+	SlashReloadMap = 9,
+	SlashPushMapRequest(SlashPushMapRequest) = 10,
+	SlashPushMap(SlashPushMap) = 11,
+	SlashPullMap(SlashPullMap) = 12,
+	LockMapRequest(LockMapRequest) = 13,
 	GeneralChatMessage(GeneralChatMessage) = 14,
+	HttpMonitorInfoRequest = 15,
 	LevelLoadComplete(LevelLoadComplete) = 19,
+	GuildCreate(GuildCreate) = 20,
 	RouteMessage(RouteMessage) = 21,
 	PositionUpdate(PositionUpdate) = 22,
 	Mail(Mail) = 23,
 	StringCheck(StringCheck) = 25,
+	GetPlayersInZone = 26,
+	RequestUgcManifestInfo(RequestUgcManifestInfo) = 27,
+	BlueprintGetAllDataRequest(BlueprintGetAllDataRequest) = 28,
+	CancelMapQueue = 29,
 	RequestFreeTrialRefresh = 32,
+	GmSetFreeTrialStatus(GmSetFreeTrialStatus) = 33,
 	Top5IssuesRequest(Top5IssuesRequest) = 91,
 	UgcDownloadFailed(UgcDownloadFailed) = 120,
 }
@@ -71,7 +82,7 @@ pub enum WorldMessage {
 	### Notes
 	**Important**: Do **not** handle any other packets from clients that have not yet been validated. Handling other packets before validation can lead to errors because the connection has not yet been associated with a username, and can lead to security vulnerabilities if session keys are not validated properly.
 */
-#[derive(Debug, PartialEq)]
+#[derive(Arbitrary, Debug, PartialEq, Reflect)]
 pub struct ClientValidation {
 	/// Account username.
 	pub username: LuWString33,
@@ -130,7 +141,7 @@ where
 	### Response
 	Respond with [`CharacterCreateResponse`](super::client::CharacterCreateResponse), using the appropriate variant to indicate the result. If the character creation is successful, additionally send a [`CharacterListResponse`](super::client::CharacterListResponse) afterwards with the new character included.
 */
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 #[trailing_padding = 1]
 pub struct CharacterCreateRequest {
 	/// The custom name, or blank if the predefined name is to be used.
@@ -172,7 +183,7 @@ pub struct CharacterCreateRequest {
 	### Response
 	Respond with [`LoadStaticZone`](super::client::LoadStaticZone) if you're not switching instances, or [`TransferToWorld`](super::client::TransferToWorld) if you do.
 */
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct CharacterLoginRequest {
 	/// The object ID of the chosen character.
 	pub char_id: ObjId,
@@ -190,13 +201,80 @@ pub struct CharacterLoginRequest {
 	### Response
 	Respond with [`CharacterDeleteResponse`](super::client::CharacterDeleteResponse) indicating whether deletion was successful.
 */
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct CharacterDeleteRequest {
 	/// The object ID of the chosen character.
 	pub char_id: ObjId,
 }
 
-#[derive(Debug, PartialEq)]
+/**
+	Asks the server whether the player may upload their local version of a map.
+
+	### Trigger
+	The `/pushmap` slash command, only available in development clients.
+
+	### Response
+	Respond with [`SlashPushMapResponse`](super::client::SlashPushMapResponse). If successful, the client will follow up with [`SlashPushMap`].
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct SlashPushMapRequest {
+	/// The map to be pushed.
+	pub map_id: MapId,
+}
+
+/**
+	Uploads the player's local version of a map.
+
+	### Trigger
+	A successful [`SlashPushMapResponse`](super::client::SlashPushMapResponse).
+
+	### Handling
+	Replace the server's copy of the map, then notify clients in the zone with [`ImpendingReloadNotify`](super::client::ClientMessage::ImpendingReloadNotify) and reload it.
+
+	### Notes
+	This is a development feature. Production servers should not accept map data from clients.
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct SlashPushMap {
+	/// The map being pushed.
+	pub map_id: MapId,
+	/// Contents of the map file.
+	pub map_data: LVec<u32, u8>,
+}
+
+/**
+	Requests the server's version of a map.
+
+	### Trigger
+	The `/pullmap` slash command, only available in development clients.
+
+	### Response
+	Respond with [`SlashPullMapResponse`](super::client::SlashPullMapResponse).
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct SlashPullMap {
+	/// The map to be pulled.
+	pub map_id: MapId,
+}
+
+/**
+	Requests a map to be locked or unlocked for editing, so that multiple developers don't push conflicting changes.
+
+	### Trigger
+	The `/lockmap` and `/unlockmap` slash commands, only available in development clients.
+
+	### Response
+	Respond with [`SlashLockMapResponse`](super::client::SlashLockMapResponse).
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct LockMapRequest {
+	/// The map to be locked or unlocked.
+	pub map_id: MapId,
+	/// Whether to lock or unlock the map.
+	pub lock: bool,
+}
+
+#[derive(Arbitrary, Debug, PartialEq, Reflect)]
 pub struct GeneralChatMessage {
 	pub chat_channel: ChatChannel,
 	pub source_id: u16,
@@ -231,6 +309,48 @@ impl<'a, W: Write + LEWrite> Serialize<LE, W> for &'a GeneralChatMessage {
 	}
 }
 
+/**
+	Borrowed view of a [`GeneralChatMessage`], used by [`MessageRef`](crate::unified::MessageRef).
+
+	The message borrows from the input, and the other fields are decoded when accessed.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GeneralChatMessageRef<'a> {
+	header: &'a [u8],
+	message: LuWStrRef<'a>,
+}
+
+impl<'a> GeneralChatMessageRef<'a> {
+	const HEADER_LEN: usize = 7;
+
+	pub fn parse(data: &mut &'a [u8]) -> Res<Self> {
+		let header = take(data, Self::HEADER_LEN)?;
+		let _: ChatChannel = LERead::read(&mut &header[..])?;
+		let str_len: u32 = LERead::read(&mut &header[3..])?;
+		let str_len = str_len.checked_sub(1).ok_or_else(|| Error::new(InvalidData, "chat message without null terminator"))?;
+		let message = LuWStrRef::read(data, str_len as usize)?;
+		take(data, 2)?;
+		Ok(Self { header, message })
+	}
+
+	pub fn chat_channel(&self) -> ChatChannel {
+		// valid, since it was checked when parsing
+		LERead::read(&mut &self.header[..]).unwrap()
+	}
+
+	pub fn source_id(&self) -> u16 {
+		u16::from_le_bytes([self.header[1], self.header[2]])
+	}
+
+	pub fn message(&self) -> LuWStrRef<'a> {
+		self.message
+	}
+
+	pub fn into_owned(self) -> GeneralChatMessage {
+		GeneralChatMessage { chat_channel: self.chat_channel(), source_id: self.source_id(), message: self.message.to_lu_wstring() }
+	}
+}
+
 /**
 	Reports to the server that client-side loading has finished.
 
@@ -240,27 +360,44 @@ impl<'a, W: Write + LEWrite> Serialize<LE, W> for &'a GeneralChatMessage {
 	### Handling / Response
 	Respond with [`CreateCharacter`](super::client::CreateCharacter) containing details about the player's character. Add the client to your server's replica manager, so that existing objects in range are replicated using [`ReplicaConstruction`](crate::raknet::client::replica::ReplicaConstruction). Create the character's replica object and and let the replica manager broadcast its construction to all clients in range. Finally, send [`ServerDoneLoadingAllObjects`](crate::world::gm::client::GameMessage::ServerDoneLoadingAllObjects) from the character object to the client.
 */
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct LevelLoadComplete {
 	/// The ID of the zone that was loaded. Servers should not trust this, as a player could use it to get into zones they don't belong.
 	pub zone_id: ZoneId,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+/**
+	Requests a new guild with the player as its leader.
+
+	### Trigger
+	Confirming the guild creation box opened with [`DisplayGuildCreateBox`](crate::world::gm::client::GameMessage::DisplayGuildCreateBox).
+
+	### Handling
+	Check that the name is available and passes moderation, and that the player isn't already in a guild.
+
+	### Response
+	Respond with [`SetCharGuildInfo`](crate::world::gm::client::SetCharGuildInfo) from the character, and add the player to their roster with [`GuildAddPlayer`](crate::world::gm::client::GuildAddPlayer).
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct GuildCreate {
+	pub guild_name: LuWString33,
+}
+
+#[derive(Arbitrary, Debug, MessageDeserialize, PartialEq, Reflect, Schema, Serialize)]
 #[pre_disc_padding = 4]
 #[repr(u16)]
 pub enum RouteMessage {
 	Chat(ChatMessage) = ServiceId::Chat as u16,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Arbitrary, Debug, PartialEq, Reflect)]
 pub struct PositionUpdate {
 	pub frame_stats: FrameStats,
 }
 
 impl<R: Read> Deserialize<LE, R> for PositionUpdate {
 	fn deserialize(reader: &mut R) -> Res<Self> {
-		let mut reader = BEBitReader::new(reader);
+		let mut reader = bit_reader(reader);
 		let frame_stats = LERead::read(&mut reader)?;
 		Ok(Self { frame_stats })
 	}
@@ -268,7 +405,7 @@ impl<R: Read> Deserialize<LE, R> for PositionUpdate {
 
 impl<'a, W: Write> Serialize<LE, W> for &'a PositionUpdate {
 	fn serialize(self, writer: &mut W) -> Res<()> {
-		let mut writer = BEBitWriter::new(writer);
+		let mut writer = bit_writer(writer);
 		LEWrite::write(&mut writer, &self.frame_stats)
 	}
 }
@@ -288,7 +425,7 @@ impl<'a, W: Write> Serialize<LE, W> for &'a PositionUpdate {
 	### Notes
 	This message is only for quick player feedback on acceptability. Final string submissions by the player will be sent in different messages (e.g. [`GeneralChatMessage`] or `Mail` (todo)). Those messages will need to be checked for moderation as well. This means that there's no harm in trusting the client to provide accurate context ([`chat_mode`](Self::chat_mode), [`recipient_name`](Self::recipient_name) in this message.
 */
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct StringCheck {
 	pub chat_mode: u8, // todo: type?
 	pub request_id: u8,
@@ -297,7 +434,34 @@ pub struct StringCheck {
 	pub string: LuVarWString<u16>,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// Requests the manifest of a user-generated content item, used to determine which resources have to be downloaded.
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct RequestUgcManifestInfo {
+	pub blueprint_id: ObjId,
+}
+
+/// Requests all data of a saved blueprint, used by the model debugging tools.
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct BlueprintGetAllDataRequest {
+	pub blueprint_id: ObjId,
+}
+
+/**
+	Sets the free trial status of the player's account.
+
+	### Trigger
+	A GM slash command, used for testing free trial restrictions.
+
+	### Handling
+	Check the player's GM level. If sufficient, update the account and respond with [`UpdateFreeTrialStatus`](super::client::UpdateFreeTrialStatus).
+*/
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
+pub struct GmSetFreeTrialStatus {
+	/// Whether the account should be put on free trial.
+	pub is_free_trial: bool,
+}
+
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 #[repr(u32)]
 #[allow(non_camel_case_types)]
 pub enum Language {
@@ -307,12 +471,12 @@ pub enum Language {
 	en_GB,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct Top5IssuesRequest {
 	pub language: Language,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 #[repr(u32)]
 pub enum UgcResType {
 	Lxfml,
@@ -321,7 +485,7 @@ pub enum UgcResType {
 	Dds,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Arbitrary, Debug, Deserialize, PartialEq, Reflect, Schema, Serialize)]
 pub struct UgcDownloadFailed {
 	pub res_type: UgcResType,
 	pub blueprint_id: ObjId,
@@ -0,0 +1,5 @@
+WorldMessage::BlueprintGetAllDataRequest(
+	BlueprintGetAllDataRequest {
+		blueprint_id: 1152921510794154770,
+	},
+)
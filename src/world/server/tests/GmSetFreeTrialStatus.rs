@@ -0,0 +1,5 @@
+WorldMessage::GmSetFreeTrialStatus(
+	GmSetFreeTrialStatus {
+		is_free_trial: true,
+	},
+)
@@ -0,0 +1,5 @@
+WorldMessage::GuildCreate(
+	GuildCreate {
+		guild_name: lu!("Nexus Knights"),
+	},
+)
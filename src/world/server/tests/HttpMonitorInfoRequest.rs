@@ -0,0 +1 @@
+WorldMessage::HttpMonitorInfoRequest
@@ -0,0 +1,6 @@
+WorldMessage::LockMapRequest(
+	LockMapRequest {
+		map_id: 1100,
+		lock: true,
+	},
+)
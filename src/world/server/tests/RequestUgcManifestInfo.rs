@@ -0,0 +1,5 @@
+WorldMessage::RequestUgcManifestInfo(
+	RequestUgcManifestInfo {
+		blueprint_id: 1152921510794154770,
+	},
+)
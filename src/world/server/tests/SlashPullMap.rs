@@ -0,0 +1,5 @@
+WorldMessage::SlashPullMap(
+	SlashPullMap {
+		map_id: 1100,
+	},
+)
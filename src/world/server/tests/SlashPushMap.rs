@@ -0,0 +1,6 @@
+WorldMessage::SlashPushMap(
+	SlashPushMap {
+		map_id: 1100,
+		map_data: vec![108, 117, 122, 0, 38, 0, 0, 0, 1, 2, 3, 4].into(),
+	},
+)
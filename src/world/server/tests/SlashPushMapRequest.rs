@@ -0,0 +1,5 @@
+WorldMessage::SlashPushMapRequest(
+	SlashPushMapRequest {
+		map_id: 1100,
+	},
+)
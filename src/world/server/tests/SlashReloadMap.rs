@@ -0,0 +1 @@
+WorldMessage::SlashReloadMap